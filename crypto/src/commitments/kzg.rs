@@ -3,14 +3,26 @@ use crate::errors::SrsFromFileError;
 use super::traits::IsCommitmentScheme;
 use lambdaworks_math::{
     cyclic_group::IsGroup,
-    elliptic_curve::{short_weierstrass::errors::DeserializationError, traits::IsPairing},
+    elliptic_curve::{
+        short_weierstrass::errors::DeserializationError,
+        traits::{HasCofactor, IsPairing},
+    },
     field::{element::FieldElement, traits::IsPrimeField},
-    msm::pippenger::msm,
+    msm::{
+        pippenger::msm_field,
+        precomputed::PrecomputedMSM,
+        streaming::{msm_from_reader, msm_from_reader_checked},
+    },
     polynomial::Polynomial,
     traits::{Deserializable, Serializable},
     unsigned_integer::element::UnsignedInteger,
 };
-use std::{marker::PhantomData, mem};
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    marker::PhantomData,
+    mem,
+};
 
 #[derive(PartialEq, Clone, Debug)]
 pub struct StructuredReferenceString<G1Point, G2Point> {
@@ -41,6 +53,90 @@ where
         let bytes = std::fs::read(file_path)?;
         Ok(Self::deserialize(&bytes)?)
     }
+
+    /// Computes the multiscalar multiplication of `cs` by the first points of the main group
+    /// of the SRS stored at `file_path`, reading `chunk_size` points at a time instead of
+    /// loading the whole SRS. Gives the same result as `msm` with the loaded points.
+    ///
+    /// Like `from_file`, the points are not checked to be in the prime order subgroup, so the
+    /// file must be trusted. Use `msm_main_group_from_file_checked` otherwise.
+    pub fn msm_main_group_from_file<const NUM_LIMBS: usize, I>(
+        file_path: &str,
+        cs: I,
+        chunk_size: usize,
+    ) -> Result<G1Point, SrsFromFileError>
+    where
+        I: IntoIterator<Item = UnsignedInteger<NUM_LIMBS>>,
+    {
+        let main_group = Self::main_group_reader(file_path)?;
+        Ok(msm_from_reader(
+            cs,
+            main_group,
+            mem::size_of::<G1Point>(),
+            chunk_size,
+        )?)
+    }
+
+    /// Returns a reader of the points of the main group of the SRS stored at `file_path`.
+    fn main_group_reader(file_path: &str) -> Result<impl Read, SrsFromFileError> {
+        const MAIN_GROUP_OFFSET: usize = 12;
+
+        let mut reader = BufReader::new(File::open(file_path)?);
+        let mut header = [0; MAIN_GROUP_OFFSET];
+        reader.read_exact(&mut header)?;
+        // This unwrap can't fail since we are fixing the size of the slice
+        let main_group_len = u64::from_le_bytes(header[4..].try_into().unwrap());
+
+        // The points of the main group are stored as in `deserialize`, and the reader is
+        // limited to them so that the points of the secondary group are never read.
+        let point_size = mem::size_of::<G1Point>() as u64;
+        Ok(reader.take(main_group_len.saturating_mul(point_size)))
+    }
+}
+
+impl<G1Point, G2Point> StructuredReferenceString<G1Point, G2Point>
+where
+    G1Point: IsGroup + Deserializable + HasCofactor,
+    G2Point: IsGroup + Deserializable + HasCofactor,
+{
+    /// Loads the SRS like `from_file`, additionally checking that every point
+    /// is in the prime order subgroup. Use this for files from untrusted sources.
+    pub fn from_file_checked(file_path: &str) -> Result<Self, SrsFromFileError> {
+        let srs = Self::from_file(file_path)?;
+        let main_group_in_subgroup = srs
+            .powers_main_group
+            .iter()
+            .all(|point| point.is_in_subgroup());
+        let secondary_group_in_subgroup = srs
+            .powers_secondary_group
+            .iter()
+            .all(|point| point.is_in_subgroup());
+
+        if main_group_in_subgroup && secondary_group_in_subgroup {
+            Ok(srs)
+        } else {
+            Err(DeserializationError::NotInSubgroup.into())
+        }
+    }
+
+    /// Computes the multiscalar multiplication like `msm_main_group_from_file`, additionally
+    /// checking that every point read is in the prime order subgroup.
+    pub fn msm_main_group_from_file_checked<const NUM_LIMBS: usize, I>(
+        file_path: &str,
+        cs: I,
+        chunk_size: usize,
+    ) -> Result<G1Point, SrsFromFileError>
+    where
+        I: IntoIterator<Item = UnsignedInteger<NUM_LIMBS>>,
+    {
+        let main_group = Self::main_group_reader(file_path)?;
+        Ok(msm_from_reader_checked(
+            cs,
+            main_group,
+            mem::size_of::<G1Point>(),
+            chunk_size,
+        )?)
+    }
 }
 
 impl<G1Point, G2Point> Serializable for StructuredReferenceString<G1Point, G2Point>
@@ -138,17 +234,70 @@ where
 #[derive(Clone)]
 pub struct KateZaveruchaGoldberg<F: IsPrimeField, P: IsPairing> {
     srs: StructuredReferenceString<P::G1Point, P::G2Point>,
+    /// The points of the secondary group of the SRS, prepared for the pairings of `verify`.
+    prepared_secondary_group: [P::G2Prepared; 2],
+    /// The points of the main group of the SRS, preprocessed to speed up `commit`.
+    precomputed_main_group: Option<PrecomputedMSM<P::G1Point>>,
     phantom: PhantomData<F>,
 }
 
 impl<F: IsPrimeField, P: IsPairing> KateZaveruchaGoldberg<F, P> {
     #[allow(unused)]
     pub fn new(srs: StructuredReferenceString<P::G1Point, P::G2Point>) -> Self {
+        let prepared_secondary_group = [
+            P::prepare(&srs.powers_secondary_group[0]),
+            P::prepare(&srs.powers_secondary_group[1]),
+        ];
         Self {
             srs,
+            prepared_secondary_group,
+            precomputed_main_group: None,
             phantom: PhantomData,
         }
     }
+
+    /// Creates the scheme preprocessing the main group of the SRS, so that `commit` uses a
+    /// fixed-base MSM. See `PrecomputedMSM` for the meaning of `window_size` and `stride`.
+    pub fn with_precomputation(
+        srs: StructuredReferenceString<P::G1Point, P::G2Point>,
+        window_size: usize,
+        stride: usize,
+    ) -> Self {
+        let precomputed = PrecomputedMSM::new(
+            &srs.powers_main_group,
+            F::field_bit_size(),
+            window_size,
+            stride,
+        );
+        Self {
+            precomputed_main_group: Some(precomputed),
+            ..Self::new(srs)
+        }
+    }
+
+    /// Creates the scheme with a preprocessing of the main group of the SRS computed before,
+    /// for example loaded from disk. Returns `None` if `precomputed` was not built from the
+    /// points of the main group of `srs`, or for scalars with fewer bits than the field.
+    pub fn with_precomputed_msm(
+        srs: StructuredReferenceString<P::G1Point, P::G2Point>,
+        precomputed: PrecomputedMSM<P::G1Point>,
+    ) -> Option<Self> {
+        if precomputed.len() != srs.powers_main_group.len()
+            || precomputed.scalar_bits() < F::field_bit_size()
+            || !precomputed.bases().eq(srs.powers_main_group.iter())
+        {
+            return None;
+        }
+        Some(Self {
+            precomputed_main_group: Some(precomputed),
+            ..Self::new(srs)
+        })
+    }
+
+    /// Returns the preprocessing of the main group of the SRS, if any, to store it.
+    pub fn precomputed_msm(&self) -> Option<&PrecomputedMSM<P::G1Point>> {
+        self.precomputed_main_group.as_ref()
+    }
 }
 
 impl<const N: usize, F: IsPrimeField<RepresentativeType = UnsignedInteger<N>>, P: IsPairing>
@@ -158,16 +307,23 @@ impl<const N: usize, F: IsPrimeField<RepresentativeType = UnsignedInteger<N>>, P
 
     #[allow(unused)]
     fn commit(&self, p: &Polynomial<FieldElement<F>>) -> Self::Commitment {
-        let coefficients: Vec<_> = p
-            .coefficients
-            .iter()
-            .map(|coefficient| coefficient.representative())
-            .collect();
-        msm(
-            &coefficients,
-            &self.srs.powers_main_group[..coefficients.len()],
-        )
-        .expect("`points` is sliced by `cs`'s length")
+        match &self.precomputed_main_group {
+            Some(precomputed) if p.coefficients.len() <= precomputed.len() => {
+                let coefficients: Vec<_> = p
+                    .coefficients
+                    .iter()
+                    .map(|coefficient| coefficient.representative())
+                    .collect();
+                precomputed
+                    .msm(&coefficients)
+                    .expect("the precomputation covers the coefficients and the bits of the field")
+            }
+            _ => msm_field(
+                &p.coefficients,
+                &self.srs.powers_main_group[..p.coefficients.len()],
+            )
+            .expect("`points` is sliced by `cs`'s length"),
+        }
     }
 
     #[allow(unused)]
@@ -192,18 +348,19 @@ impl<const N: usize, F: IsPrimeField<RepresentativeType = UnsignedInteger<N>>, P
         proof: &Self::Commitment,
     ) -> bool {
         let g1 = &self.srs.powers_main_group[0];
-        let g2 = &self.srs.powers_secondary_group[0];
-        let alpha_g2 = &self.srs.powers_secondary_group[1];
+        let [g2, alpha_g2] = &self.prepared_secondary_group;
 
-        let e = P::compute_batch(&[
+        // e(p_commitment - y * g1, g2) = e(proof, alpha_g2 - x * g2) is checked as
+        // e(p_commitment - y * g1 + x * proof, g2) * e(-proof, alpha_g2) = 1
+        // so that both points of G2 are fixed.
+        let e = P::compute_batch_prepared(&[
             (
-                &p_commitment.operate_with(&(g1.operate_with_self(y.representative())).neg()),
+                &p_commitment
+                    .operate_with(&(g1.operate_with_self(y.representative())).neg())
+                    .operate_with(&proof.operate_with_self(x.representative())),
                 g2,
             ),
-            (
-                &proof.neg(),
-                &(alpha_g2.operate_with(&(g2.operate_with_self(x.representative())).neg())),
-            ),
+            (&proof.neg(), alpha_g2),
         ]);
         e == FieldElement::one()
     }
@@ -264,20 +421,23 @@ mod tests {
                 curves::bls12_381::{
                     curve::BLS12381Curve,
                     default_types::{FrElement, FrField},
+                    field_extension::BLS12381PrimeField,
                     pairing::BLS12381AtePairing,
                     twist::BLS12381TwistCurve,
                 },
+                errors::DeserializationError,
                 point::ShortWeierstrassProjectivePoint,
             },
             traits::{IsEllipticCurve, IsPairing},
         },
         field::element::FieldElement,
+        msm::{pippenger::msm, precomputed::PrecomputedMSM},
         polynomial::Polynomial,
         traits::{Deserializable, Serializable},
         unsigned_integer::element::U256,
     };
 
-    use crate::commitments::traits::IsCommitmentScheme;
+    use crate::{commitments::traits::IsCommitmentScheme, errors::SrsFromFileError};
 
     use super::{KateZaveruchaGoldberg, StructuredReferenceString};
     use rand::Rng;
@@ -338,6 +498,23 @@ mod tests {
         assert!(kzg.verify(&x, &y, &p_commitment, &proof));
     }
 
+    #[test]
+    fn verify_rejects_a_wrong_evaluation() {
+        let kzg = KZG::new(create_srs());
+        let p = Polynomial::<FrElement>::new(&[
+            FieldElement::from(3),
+            FieldElement::from(5),
+            FieldElement::from(7),
+        ]);
+        let p_commitment: <BLS12381AtePairing as IsPairing>::G1Point = kzg.commit(&p);
+        let x = FieldElement::from(11);
+        let y = p.evaluate(&x);
+        let proof = kzg.open(&x, &y, &p);
+        assert!(kzg.verify(&x, &y, &p_commitment, &proof));
+        assert!(!kzg.verify(&x, &(&y + FieldElement::one()), &p_commitment, &proof));
+        assert!(!kzg.verify(&(&x + FieldElement::one()), &y, &p_commitment, &proof));
+    }
+
     #[test]
     fn poly_9000_batched_should_verify() {
         let kzg = KZG::new(create_srs());
@@ -431,4 +608,165 @@ mod tests {
 
         assert_eq!(srs.powers_main_group.len(), 3);
     }
+
+    #[test]
+    fn load_srs_from_file_checked() {
+        type TestSrsType = StructuredReferenceString<
+            ShortWeierstrassProjectivePoint<BLS12381Curve>,
+            ShortWeierstrassProjectivePoint<BLS12381TwistCurve>,
+        >;
+
+        let base_dir = env!("CARGO_MANIFEST_DIR");
+        let srs_file = base_dir.to_owned() + "/src/commitments/test_srs/srs_3_g1_elements.bin";
+
+        let srs = TestSrsType::from_file_checked(&srs_file).unwrap();
+
+        assert_eq!(srs, TestSrsType::from_file(&srs_file).unwrap());
+    }
+
+    #[test]
+    fn load_srs_from_file_checked_rejects_invalid_points() {
+        type TestSrsType = StructuredReferenceString<
+            ShortWeierstrassProjectivePoint<BLS12381Curve>,
+            ShortWeierstrassProjectivePoint<BLS12381TwistCurve>,
+        >;
+
+        // A point of the curve that is not in the prime order subgroup.
+        let x = FieldElement::<BLS12381PrimeField>::from(4);
+        let y = FieldElement::<BLS12381PrimeField>::new_base("a989badd40d6212b33cffc3f3763e9bc760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c");
+        let outside = BLS12381Curve::create_point_from_affine(x, y).unwrap();
+        let g1 = BLS12381Curve::generator();
+        let g2 = BLS12381TwistCurve::generator();
+        let srs = TestSrsType::new(&[g1.clone(), outside], &[g2.clone(), g2.clone()]);
+
+        let srs_file = std::env::temp_dir().join(format!(
+            "lambdaworks_kzg_srs_not_in_subgroup_{}.bin",
+            std::process::id()
+        ));
+        std::fs::write(&srs_file, srs.serialize()).unwrap();
+        let srs_file = srs_file.to_str().unwrap();
+        assert_eq!(TestSrsType::from_file(srs_file).unwrap(), srs);
+        assert!(matches!(
+            TestSrsType::from_file_checked(srs_file),
+            Err(SrsFromFileError::DeserializationError(
+                DeserializationError::NotInSubgroup
+            ))
+        ));
+        std::fs::remove_file(srs_file).unwrap();
+
+        // Changing the lowest byte of the `y` coordinate of the first point, stored in
+        // little endian after the 12 bytes of the header and the 48 bytes of `x`, moves it
+        // off the curve. Both loaders reject it, since deserialization checks the curve
+        // equation.
+        let srs = TestSrsType::new(&[g1], &[g2.clone(), g2]);
+        let mut bytes = srs.serialize();
+        bytes[12 + 48] ^= 1;
+        let srs_file = std::env::temp_dir().join(format!(
+            "lambdaworks_kzg_srs_not_on_curve_{}.bin",
+            std::process::id()
+        ));
+        std::fs::write(&srs_file, bytes).unwrap();
+        let srs_file = srs_file.to_str().unwrap();
+        assert!(matches!(
+            TestSrsType::from_file(srs_file),
+            Err(SrsFromFileError::DeserializationError(
+                DeserializationError::FieldFromBytesError
+            ))
+        ));
+        assert!(matches!(
+            TestSrsType::from_file_checked(srs_file),
+            Err(SrsFromFileError::DeserializationError(
+                DeserializationError::FieldFromBytesError
+            ))
+        ));
+        std::fs::remove_file(srs_file).unwrap();
+    }
+
+    #[test]
+    fn commit_with_precomputation_matches_commit() {
+        let srs = create_srs();
+        let kzg = KZG::new(srs.clone());
+        let precomputed_kzg = KZG::with_precomputation(srs, 8, 2);
+        let p = Polynomial::<FrElement>::new(&[
+            FieldElement::from(3),
+            -FieldElement::from(5),
+            FieldElement::from(7),
+            FieldElement::from(9000),
+        ]);
+        let p_commitment = precomputed_kzg.commit(&p);
+        assert_eq!(p_commitment, kzg.commit(&p));
+
+        let x = FieldElement::from(11);
+        let y = p.evaluate(&x);
+        let proof = precomputed_kzg.open(&x, &y, &p);
+        assert!(precomputed_kzg.verify(&x, &y, &p_commitment, &proof));
+    }
+
+    #[test]
+    fn precomputed_msm_is_only_accepted_for_its_srs() {
+        let srs = create_srs();
+        let kzg = KZG::with_precomputation(srs.clone(), 4, 4);
+        let bytes = kzg.precomputed_msm().unwrap().serialize();
+        let precomputed = PrecomputedMSM::<G1>::deserialize(&bytes).unwrap();
+
+        let other_srs = create_srs();
+        assert!(KZG::with_precomputed_msm(other_srs, precomputed.clone()).is_none());
+        let too_few_bits = PrecomputedMSM::new(&srs.powers_main_group, 64, 4, 4);
+        assert!(KZG::with_precomputed_msm(srs.clone(), too_few_bits).is_none());
+
+        let loaded_kzg = KZG::with_precomputed_msm(srs, precomputed).unwrap();
+        let p = Polynomial::<FrElement>::new(&[FieldElement::from(2), FieldElement::from(4)]);
+        assert_eq!(loaded_kzg.commit(&p), kzg.commit(&p));
+    }
+
+    #[test]
+    fn msm_main_group_from_file_matches_msm() {
+        type TestSrsType = StructuredReferenceString<
+            ShortWeierstrassProjectivePoint<BLS12381Curve>,
+            ShortWeierstrassProjectivePoint<BLS12381TwistCurve>,
+        >;
+
+        let srs = create_srs();
+        let srs_file = std::env::temp_dir().join(format!(
+            "lambdaworks_kzg_streaming_srs_{}.bin",
+            std::process::id()
+        ));
+        std::fs::write(&srs_file, srs.serialize()).unwrap();
+        let srs_file = srs_file.to_str().unwrap();
+
+        let cs: Vec<_> = (1..=40_u64)
+            .map(|i| (FrElement::from(i).pow(17_u64)).representative())
+            .collect();
+        let expected = msm(&cs, &srs.powers_main_group[..cs.len()]).unwrap();
+        let streamed = TestSrsType::msm_main_group_from_file(srs_file, cs.clone(), 7).unwrap();
+        assert_eq!(streamed, expected);
+
+        // There are only 100 points in the main group.
+        let too_many = vec![U256::from_u64(1); 101];
+        assert!(TestSrsType::msm_main_group_from_file(srs_file, too_many, 7).is_err());
+
+        std::fs::remove_file(srs_file).unwrap();
+    }
+
+    #[test]
+    fn msm_main_group_from_test_file_matches_msm() {
+        type TestSrsType = StructuredReferenceString<
+            ShortWeierstrassProjectivePoint<BLS12381Curve>,
+            ShortWeierstrassProjectivePoint<BLS12381TwistCurve>,
+        >;
+
+        let base_dir = env!("CARGO_MANIFEST_DIR");
+        let srs_file = base_dir.to_owned() + "/src/commitments/test_srs/srs_3_g1_elements.bin";
+        let srs = TestSrsType::from_file(&srs_file).unwrap();
+
+        let cs = [
+            U256::from_u64(5),
+            U256::from_u64(0),
+            U256::from_hex_unchecked("abcdef"),
+        ];
+        let streamed = TestSrsType::msm_main_group_from_file(&srs_file, cs, 2).unwrap();
+        assert_eq!(streamed, msm(&cs, &srs.powers_main_group).unwrap());
+        let streamed = TestSrsType::msm_main_group_from_file_checked(&srs_file, cs, 2).unwrap();
+        assert_eq!(streamed, msm(&cs, &srs.powers_main_group).unwrap());
+    }
 }
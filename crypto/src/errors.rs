@@ -1,4 +1,6 @@
-use lambdaworks_math::elliptic_curve::short_weierstrass::errors::DeserializationError;
+use lambdaworks_math::{
+    elliptic_curve::short_weierstrass::errors::DeserializationError, msm::naive::MSMError,
+};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -7,4 +9,58 @@ pub enum SrsFromFileError {
     FileError(#[from] std::io::Error),
     #[error("Error when deserializing")]
     DeserializationError(#[from] DeserializationError),
+    #[error("Error when computing a MSM over the points of the file")]
+    MSMError(#[from] MSMError),
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum HashToCurveError {
+    #[error("The requested length is too large for expand_message")]
+    InvalidOutputLength,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum EcdsaError {
+    #[error("The private key is not in [1, n - 1]")]
+    InvalidPrivateKey,
+    #[error("The public key is the neutral element")]
+    InvalidPublicKey,
+    #[error("The signature is not a pair of integers in [1, n - 1]")]
+    InvalidSignature,
+    #[error("The recovery id is not in [0, 3]")]
+    InvalidRecoveryId,
+    #[error("No public key can be recovered from the signature")]
+    RecoveryFailed,
+    #[error("The message hash is not smaller than 2^251")]
+    InvalidMessageHash,
+    #[error("The nonce does not produce a valid signature")]
+    InvalidNonce,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum EddsaError {
+    #[error("The public key is not the encoding of a point")]
+    InvalidPublicKey,
+    #[error("The signature is not an encoded point followed by an integer smaller than l")]
+    InvalidSignature,
+    #[error("The context is longer than 255 bytes")]
+    InvalidContext,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum BlsError {
+    #[error("The key material is shorter than 32 bytes")]
+    ShortKeyMaterial,
+    #[error("The secret key is not an integer in [1, r - 1]")]
+    InvalidSecretKey,
+    #[error(
+        "The public key is not the encoding of a point of the subgroup other than the identity"
+    )]
+    InvalidPublicKey,
+    #[error("The signature is not the encoding of a point of the subgroup")]
+    InvalidSignature,
+    #[error("There are no elements to aggregate")]
+    EmptyAggregation,
+    #[error("The aggregate of the public keys is the identity")]
+    IdentityAggregatePublicKey,
 }
@@ -0,0 +1,670 @@
+use super::{sswu::map_to_curve_simple_swu, sswu::Isogeny, HashToField, MapToCurve};
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::short_weierstrass::{
+        curves::bls12_381::{
+            curve::{BLS12381Curve, BLS12381FieldElement, BLS12381TwistCurveFieldElement},
+            field_extension::{BLS12381PrimeField, Degree2ExtensionField},
+            sqrt::sqrt_qfe,
+            twist::BLS12381TwistCurve,
+        },
+        point::ShortWeierstrassProjectivePoint,
+    },
+    field::{element::FieldElement, traits::LegendreSymbol},
+};
+use std::sync::OnceLock;
+
+/// Suite ID of the hash to curve suite for G1, using the random oracle construction.
+pub const BLS12381G1_XMD_SHA256_SSWU_RO: &[u8] = b"BLS12381G1_XMD:SHA-256_SSWU_RO_";
+/// Suite ID of the encode to curve suite for G1.
+pub const BLS12381G1_XMD_SHA256_SSWU_NU: &[u8] = b"BLS12381G1_XMD:SHA-256_SSWU_NU_";
+/// Suite ID of the hash to curve suite for G2, using the random oracle construction.
+pub const BLS12381G2_XMD_SHA256_SSWU_RO: &[u8] = b"BLS12381G2_XMD:SHA-256_SSWU_RO_";
+/// Suite ID of the encode to curve suite for G2.
+pub const BLS12381G2_XMD_SHA256_SSWU_NU: &[u8] = b"BLS12381G2_XMD:SHA-256_SSWU_NU_";
+
+impl HashToField for Degree2ExtensionField {
+    const EXTENSION_DEGREE: usize = 2;
+
+    fn bytes_per_prime_element() -> usize {
+        BLS12381PrimeField::bytes_per_prime_element()
+    }
+
+    fn from_uniform_bytes(bytes: &[u8]) -> FieldElement<Self> {
+        let (c0, c1) = bytes.split_at(bytes.len() / 2);
+        FieldElement::new([
+            BLS12381PrimeField::from_uniform_bytes(c0),
+            BLS12381PrimeField::from_uniform_bytes(c1),
+        ])
+    }
+
+    fn sgn0(a: &FieldElement<Self>) -> bool {
+        let [c0, c1] = a.value();
+        BLS12381PrimeField::sgn0(c0)
+            || (*c0 == FieldElement::zero() && BLS12381PrimeField::sgn0(c1))
+    }
+
+    fn is_square(a: &FieldElement<Self>) -> bool {
+        // `a` is a square if and only if its norm `c0^2 + c1^2` is a square in the base field.
+        let [c0, c1] = a.value();
+        (c0.square() + c1.square()).legendre_symbol() != LegendreSymbol::MinusOne
+    }
+
+    fn sqrt(a: &FieldElement<Self>) -> Option<FieldElement<Self>> {
+        sqrt_qfe(a, 0)
+    }
+}
+
+/// Simplified SWU map to G1, through the 11-isogenous curve
+/// `y^2 = x^3 + A' * x + B'`.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc9380.html#name-bls12-381-g1
+#[derive(Clone, Debug)]
+pub struct BLS12381G1Sswu;
+
+/// Simplified SWU map to G2, through the 3-isogenous curve
+/// `y^2 = x^3 + A' * x + B'`.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc9380.html#name-bls12-381-g2
+#[derive(Clone, Debug)]
+pub struct BLS12381G2Sswu;
+
+const G1_ISOGENOUS_A: BLS12381FieldElement = BLS12381FieldElement::from_hex_unchecked(
+    "144698a3b8e9433d693a02c96d4982b0ea985383ee66a8d8e8981aefd881ac98936f8da0e0f97f5cf428082d584c1d",
+);
+const G1_ISOGENOUS_B: BLS12381FieldElement = BLS12381FieldElement::from_hex_unchecked(
+    "12e2908d11688030018b12e8753eee3b2016c1f0f24f4070a0b9c14fcef35ef55a23215a316ceaa5d1cc48e98e172be0",
+);
+const G1_Z: BLS12381FieldElement = BLS12381FieldElement::from_hex_unchecked("b");
+
+const G1_ISOGENY_X_NUMERATOR: [BLS12381FieldElement; 12] = [
+    BLS12381FieldElement::from_hex_unchecked(
+        "11a05f2b1e833340b809101dd99815856b303e88a2d7005ff2627b56cdb4e2c85610c2d5f2e62d6eaeac1662734649b7",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "17294ed3e943ab2f0588bab22147a81c7c17e75b2f6a8417f565e33c70d1e86b4838f2a6f318c356e834eef1b3cb83bb",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "d54005db97678ec1d1048c5d10a9a1bce032473295983e56878e501ec68e25c958c3e3d2a09729fe0179f9dac9edcb0",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "1778e7166fcc6db74e0609d307e55412d7f5e4656a8dbf25f1b33289f1b330835336e25ce3107193c5b388641d9b6861",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "e99726a3199f4436642b4b3e4118e5499db995a1257fb3f086eeb65982fac18985a286f301e77c451154ce9ac8895d9",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "1630c3250d7313ff01d1201bf7a74ab5db3cb17dd952799b9ed3ab9097e68f90a0870d2dcae73d19cd13c1c66f652983",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "d6ed6553fe44d296a3726c38ae652bfb11586264f0f8ce19008e218f9c86b2a8da25128c1052ecaddd7f225a139ed84",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "17b81e7701abdbe2e8743884d1117e53356de5ab275b4db1a682c62ef0f2753339b7c8f8c8f475af9ccb5618e3f0c88e",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "80d3cf1f9a78fc47b90b33563be990dc43b756ce79f5574a2c596c928c5d1de4fa295f296b74e956d71986a8497e317",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "169b1f8e1bcfa7c42e0c37515d138f22dd2ecb803a0c5c99676314baf4bb1b7fa3190b2edc0327797f241067be390c9e",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "10321da079ce07e272d8ec09d2565b0dfa7dccdde6787f96d50af36003b14866f69b771f8c285decca67df3f1605fb7b",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "6e08c248e260e70bd1e962381edee3d31d79d7e22c837bc23c0bf1bc24c6b68c24b1b80b64d391fa9c8ba2e8ba2d229",
+    ),
+];
+
+const G1_ISOGENY_X_DENOMINATOR: [BLS12381FieldElement; 11] = [
+    BLS12381FieldElement::from_hex_unchecked(
+        "8ca8d548cff19ae18b2e62f4bd3fa6f01d5ef4ba35b48ba9c9588617fc8ac62b558d681be343df8993cf9fa40d21b1c",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "12561a5deb559c4348b4711298e536367041e8ca0cf0800c0126c2588c48bf5713daa8846cb026e9e5c8276ec82b3bff",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "b2962fe57a3225e8137e629bff2991f6f89416f5a718cd1fca64e00b11aceacd6a3d0967c94fedcfcc239ba5cb83e19",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "3425581a58ae2fec83aafef7c40eb545b08243f16b1655154cca8abc28d6fd04976d5243eecf5c4130de8938dc62cd8",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "13a8e162022914a80a6f1d5f43e7a07dffdfc759a12062bb8d6b44e833b306da9bd29ba81f35781d539d395b3532a21e",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "e7355f8e4e667b955390f7f0506c6e9395735e9ce9cad4d0a43bcef24b8982f7400d24bc4228f11c02df9a29f6304a5",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "772caacf16936190f3e0c63e0596721570f5799af53a1894e2e073062aede9cea73b3538f0de06cec2574496ee84a3a",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "14a7ac2a9d64a8b230b3f5b074cf01996e7f63c21bca68a81996e1cdf9822c580fa5b9489d11e2d311f7d99bbdcc5a5e",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "a10ecf6ada54f825e920b3dafc7a3cce07f8d1d7161366b74100da67f39883503826692abba43704776ec3a79a1d641",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "95fc13ab9e92ad4476d6e3eb3a56680f682b4ee96f7d03776df533978f31c1593174e4b4b7865002d6384d168ecdd0a",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "1",
+    ),
+];
+
+const G1_ISOGENY_Y_NUMERATOR: [BLS12381FieldElement; 16] = [
+    BLS12381FieldElement::from_hex_unchecked(
+        "90d97c81ba24ee0259d1f094980dcfa11ad138e48a869522b52af6c956543d3cd0c7aee9b3ba3c2be9845719707bb33",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "134996a104ee5811d51036d776fb46831223e96c254f383d0f906343eb67ad34d6c56711962fa8bfe097e75a2e41c696",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "cc786baa966e66f4a384c86a3b49942552e2d658a31ce2c344be4b91400da7d26d521628b00523b8dfe240c72de1f6",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "1f86376e8981c217898751ad8746757d42aa7b90eeb791c09e4a3ec03251cf9de405aba9ec61deca6355c77b0e5f4cb",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "8cc03fdefe0ff135caf4fe2a21529c4195536fbe3ce50b879833fd221351adc2ee7f8dc099040a841b6daecf2e8fedb",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "16603fca40634b6a2211e11db8f0a6a074a7d0d4afadb7bd76505c3d3ad5544e203f6326c95a807299b23ab13633a5f0",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "4ab0b9bcfac1bbcb2c977d027796b3ce75bb8ca2be184cb5231413c4d634f3747a87ac2460f415ec961f8855fe9d6f2",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "987c8d5333ab86fde9926bd2ca6c674170a05bfe3bdd81ffd038da6c26c842642f64550fedfe935a15e4ca31870fb29",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "9fc4018bd96684be88c9e221e4da1bb8f3abd16679dc26c1e8b6e6a1f20cabe69d65201c78607a360370e577bdba587",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "e1bba7a1186bdb5223abde7ada14a23c42a0ca7915af6fe06985e7ed1e4d43b9b3f7055dd4eba6f2bafaaebca731c30",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "19713e47937cd1be0dfd0b8f1d43fb93cd2fcbcb6caf493fd1183e416389e61031bf3a5cce3fbafce813711ad011c132",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "18b46a908f36f6deb918c143fed2edcc523559b8aaf0c2462e6bfe7f911f643249d9cdf41b44d606ce07c8a4d0074d8e",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "b182cac101b9399d155096004f53f447aa7b12a3426b08ec02710e807b4633f06c851c1919211f20d4c04f00b971ef8",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "245a394ad1eca9b72fc00ae7be315dc757b3b080d4c158013e6632d3c40659cc6cf90ad1c232a6442d9d3f5db980133",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "5c129645e44cf1102a159f748c4a3fc5e673d81d7e86568d9ab0f5d396a7ce46ba1049b6579afb7866b1e715475224b",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "15e6be4e990f03ce4ea50b3b42df2eb5cb181d8f84965a3957add4fa95af01b2b665027efec01c7704b456be69c8b604",
+    ),
+];
+
+const G1_ISOGENY_Y_DENOMINATOR: [BLS12381FieldElement; 16] = [
+    BLS12381FieldElement::from_hex_unchecked(
+        "16112c4c3a9c98b252181140fad0eae9601a6de578980be6eec3232b5be72e7a07f3688ef60c206d01479253b03663c1",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "1962d75c2381201e1a0cbd6c43c348b885c84ff731c4d59ca4a10356f453e01f78a4260763529e3532f6102c2e49a03d",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "58df3306640da276faaae7d6e8eb15778c4855551ae7f310c35a5dd279cd2eca6757cd636f96f891e2538b53dbf67f2",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "16b7d288798e5395f20d23bf89edb4d1d115c5dbddbcd30e123da489e726af41727364f2c28297ada8d26d98445f5416",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "be0e079545f43e4b00cc912f8228ddcc6d19c9f0f69bbb0542eda0fc9dec916a20b15dc0fd2ededda39142311a5001d",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "8d9e5297186db2d9fb266eaac783182b70152c65550d881c5ecd87b6f0f5a6449f38db9dfa9cce202c6477faaf9b7ac",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "166007c08a99db2fc3ba8734ace9824b5eecfdfa8d0cf8ef5dd365bc400a0051d5fa9c01a58b1fb93d1a1399126a775c",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "16a3ef08be3ea7ea03bcddfabba6ff6ee5a4375efa1f4fd7feb34fd206357132b920f5b00801dee460ee415a15812ed9",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "1866c8ed336c61231a1be54fd1d74cc4f9fb0ce4c6af5920abc5750c4bf39b4852cfe2f7bb9248836b233d9d55535d4a",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "167a55cda70a6e1cea820597d94a84903216f763e13d87bb5308592e7ea7d4fbc7385ea3d529b35e346ef48bb8913f55",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "4d2f259eea405bd48f010a01ad2911d9c6dd039bb61a6290e591b36e636a5c871a5c29f4f83060400f8b49cba8f6aa8",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "accbb67481d033ff5852c1e48c50c477f94ff8aefce42d28c0f9a88cea7913516f968986f7ebbea9684b529e2561092",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "ad6b9514c767fe3c3613144b45f1496543346d98adf02267d5ceef9a00d9b8693000763e3b90ac11e99b138573345cc",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "2660400eb2e4f3b628bdd0d53cd76f2bf565b94e72927c1cb748df27942480e420517bd8714cc80d1fadc1326ed06f7",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "e0fa1d816ddc03e6b24255e0d7819c171c40f65e273b853324efcd6356caa205ca2f570f13497804415473a1d634b8f",
+    ),
+    BLS12381FieldElement::from_hex_unchecked(
+        "1",
+    ),
+];
+
+const G2_ISOGENY_X_NUMERATOR: [[BLS12381FieldElement; 2]; 4] = [
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "5c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97d6",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "5c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97d6",
+        ),
+    ],
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "0",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "11560bf17baa99bc32126fced787c88f984f87adf7ae0c7f9a208c6b4f20a4181472aaa9cb8d555526a9ffffffffc71a",
+        ),
+    ],
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "11560bf17baa99bc32126fced787c88f984f87adf7ae0c7f9a208c6b4f20a4181472aaa9cb8d555526a9ffffffffc71e",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "8ab05f8bdd54cde190937e76bc3e447cc27c3d6fbd7063fcd104635a790520c0a395554e5c6aaaa9354ffffffffe38d",
+        ),
+    ],
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "171d6541fa38ccfaed6dea691f5fb614cb14b4e7f4e810aa22d6108f142b85757098e38d0f671c7188e2aaaaaaaa5ed1",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "0",
+        ),
+    ],
+];
+
+const G2_ISOGENY_X_DENOMINATOR: [[BLS12381FieldElement; 2]; 3] = [
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "0",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaa63",
+        ),
+    ],
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "c",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaa9f",
+        ),
+    ],
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "1",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "0",
+        ),
+    ],
+];
+
+const G2_ISOGENY_Y_NUMERATOR: [[BLS12381FieldElement; 2]; 4] = [
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "1530477c7ab4113b59a4c18b076d11930f7da5d4a07f649bf54439d87d27e500fc8c25ebf8c92f6812cfc71c71c6d706",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "1530477c7ab4113b59a4c18b076d11930f7da5d4a07f649bf54439d87d27e500fc8c25ebf8c92f6812cfc71c71c6d706",
+        ),
+    ],
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "0",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "5c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97be",
+        ),
+    ],
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "11560bf17baa99bc32126fced787c88f984f87adf7ae0c7f9a208c6b4f20a4181472aaa9cb8d555526a9ffffffffc71c",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "8ab05f8bdd54cde190937e76bc3e447cc27c3d6fbd7063fcd104635a790520c0a395554e5c6aaaa9354ffffffffe38f",
+        ),
+    ],
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "124c9ad43b6cf79bfbf7043de3811ad0761b0f37a1e26286b0e977c69aa274524e79097a56dc4bd9e1b371c71c718b10",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "0",
+        ),
+    ],
+];
+
+const G2_ISOGENY_Y_DENOMINATOR: [[BLS12381FieldElement; 2]; 4] = [
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffa8fb",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffa8fb",
+        ),
+    ],
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "0",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffa9d3",
+        ),
+    ],
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "12",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaa99",
+        ),
+    ],
+    [
+        BLS12381FieldElement::from_hex_unchecked(
+            "1",
+        ),
+        BLS12381FieldElement::from_hex_unchecked(
+            "0",
+        ),
+    ],
+];
+
+/// Returns the 11-isogeny from the curve of `G1_ISOGENOUS_A` and `G1_ISOGENOUS_B` to G1,
+/// which is built only once.
+fn g1_isogeny() -> &'static Isogeny<BLS12381PrimeField> {
+    static ISOGENY: OnceLock<Isogeny<BLS12381PrimeField>> = OnceLock::new();
+    ISOGENY.get_or_init(|| Isogeny {
+        x_numerator: G1_ISOGENY_X_NUMERATOR.to_vec(),
+        x_denominator: G1_ISOGENY_X_DENOMINATOR.to_vec(),
+        y_numerator: G1_ISOGENY_Y_NUMERATOR.to_vec(),
+        y_denominator: G1_ISOGENY_Y_DENOMINATOR.to_vec(),
+    })
+}
+
+impl MapToCurve for BLS12381G1Sswu {
+    type Field = BLS12381PrimeField;
+    type Curve = BLS12381Curve;
+
+    fn map_to_curve(
+        &self,
+        u: &BLS12381FieldElement,
+    ) -> ShortWeierstrassProjectivePoint<BLS12381Curve> {
+        let (x, y) = map_to_curve_simple_swu(u, &G1_ISOGENOUS_A, &G1_ISOGENOUS_B, &G1_Z);
+        match g1_isogeny().map(&x, &y) {
+            Some((x, y)) => ShortWeierstrassProjectivePoint::new([x, y, FieldElement::one()]),
+            None => ShortWeierstrassProjectivePoint::neutral_element(),
+        }
+    }
+}
+
+/// Constants of the simplified SWU map to G2. Elements of 𝔽p² can't be built in a
+/// `const`, so they are built only once, the first time they are needed.
+struct G2SswuConstants {
+    isogenous_a: BLS12381TwistCurveFieldElement,
+    isogenous_b: BLS12381TwistCurveFieldElement,
+    z: BLS12381TwistCurveFieldElement,
+    isogeny: Isogeny<Degree2ExtensionField>,
+}
+
+fn g2_constants(coefficients: &[[BLS12381FieldElement; 2]]) -> Vec<BLS12381TwistCurveFieldElement> {
+    coefficients
+        .iter()
+        .map(|c| BLS12381TwistCurveFieldElement::new(c.clone()))
+        .collect()
+}
+
+fn g2_sswu_constants() -> &'static G2SswuConstants {
+    static CONSTANTS: OnceLock<G2SswuConstants> = OnceLock::new();
+    CONSTANTS.get_or_init(|| G2SswuConstants {
+        // A' = 240 * i, B' = 1012 * (1 + i), Z = -(2 + i)
+        isogenous_a: BLS12381TwistCurveFieldElement::new([
+            FieldElement::zero(),
+            FieldElement::from(240),
+        ]),
+        isogenous_b: BLS12381TwistCurveFieldElement::new([
+            FieldElement::from(1012),
+            FieldElement::from(1012),
+        ]),
+        z: -BLS12381TwistCurveFieldElement::new([FieldElement::from(2), FieldElement::one()]),
+        isogeny: Isogeny {
+            x_numerator: g2_constants(&G2_ISOGENY_X_NUMERATOR),
+            x_denominator: g2_constants(&G2_ISOGENY_X_DENOMINATOR),
+            y_numerator: g2_constants(&G2_ISOGENY_Y_NUMERATOR),
+            y_denominator: g2_constants(&G2_ISOGENY_Y_DENOMINATOR),
+        },
+    })
+}
+
+impl MapToCurve for BLS12381G2Sswu {
+    type Field = Degree2ExtensionField;
+    type Curve = BLS12381TwistCurve;
+
+    fn map_to_curve(
+        &self,
+        u: &BLS12381TwistCurveFieldElement,
+    ) -> ShortWeierstrassProjectivePoint<BLS12381TwistCurve> {
+        let constants = g2_sswu_constants();
+        let (x, y) = map_to_curve_simple_swu(
+            u,
+            &constants.isogenous_a,
+            &constants.isogenous_b,
+            &constants.z,
+        );
+        match constants.isogeny.map(&x, &y) {
+            Some((x, y)) => ShortWeierstrassProjectivePoint::new([x, y, FieldElement::one()]),
+            None => ShortWeierstrassProjectivePoint::neutral_element(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::hash_to_curve::{
+        encode_to_curve, hash_to_curve, hash_to_field, svdw::ShallueVanDeWoestijne,
+    };
+    use lambdaworks_math::elliptic_curve::{
+        short_weierstrass::traits::IsShortWeierstrass, traits::HasCofactor,
+    };
+    use sha2::Sha256;
+
+    // Test vectors from https://www.rfc-editor.org/rfc/rfc9380.html#name-bls12-381-g1-2
+    const G1_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    #[allow(clippy::type_complexity)]
+    const G1_VECTORS: [(&[u8], [&str; 2], &str, &str); 3] = [
+        (
+            b"",
+            [
+                "0ba14bd907ad64a016293ee7c2d276b8eae71f25a4b941eece7b0d89f17f75cb3ae5438a614fb61d6835ad59f29c564f",
+                "019b9bd7979f12657976de2884c7cce192b82c177c80e0ec604436a7f538d231552f0d96d9f7babe5fa3b19b3ff25ac9",
+            ],
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+            "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+        ),
+        (
+            b"abc",
+            [
+                "0d921c33f2bad966478a03ca35d05719bdf92d347557ea166e5bba579eea9b83e9afa5c088573c2281410369fbd32951",
+                "003574a00b109ada2f26a37a91f9d1e740dffd8d69ec0c35e1e9f4652c7dba61123e9dd2e76c655d956e2b3462611139",
+            ],
+            "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+            "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+        ),
+        (
+            b"abcdef0123456789",
+            [
+                "062d1865eb80ebfa73dcfc45db1ad4266b9f3a93219976a3790ab8d52d3e5f1e62f3b01795e36834b17b70e7b76246d4",
+                "0cdc3e2f271f29c4ff75020857ce6c5d36008c9b48385ea2f2bf6f96f428a3deb798aa033cd482d1cdc8b30178b08e3a",
+            ],
+            "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98",
+            "03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+        ),
+    ];
+
+    // Test vectors from https://www.rfc-editor.org/rfc/rfc9380.html#name-bls12-381-g2-2
+    const G2_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+    #[allow(clippy::type_complexity)]
+    const G2_VECTORS: [(&[u8], [[&str; 2]; 2], [&str; 2], [&str; 2]); 3] = [
+        (
+            b"",
+            [
+                ["03dbc2cce174e91ba93cbb08f26b917f98194a2ea08d1cce75b2b9cc9f21689d80bd79b594a613d0a68eb807dfdc1cf8", "05a2acec64114845711a54199ea339abd125ba38253b70a92c876df10598bd1986b739cad67961eb94f7076511b3b39a"],
+                ["02f99798e8a5acdeed60d7e18e9120521ba1f47ec090984662846bc825de191b5b7641148c0dbc237726a334473eee94", "145a81e418d4010cc027a68f14391b30074e89e60ee7a22f87217b2f6eb0c4b94c9115b436e6fa4607e95a98de30a435"],
+            ],
+            ["0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a", "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d"],
+            ["0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92", "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6"],
+        ),
+        (
+            b"abc",
+            [
+                ["15f7c0aa8f6b296ab5ff9c2c7581ade64f4ee6f1bf18f55179ff44a2cf355fa53dd2a2158c5ecb17d7c52f63e7195771", "01c8067bf4c0ba709aa8b9abc3d1cef589a4758e09ef53732d670fd8739a7274e111ba2fcaa71b3d33df2a3a0c8529dd"],
+                ["187111d5e088b6b9acfdfad078c4dacf72dcd17ca17c82be35e79f8c372a693f60a033b461d81b025864a0ad051a06e4", "08b852331c96ed983e497ebc6dee9b75e373d923b729194af8e72a051ea586f3538a6ebb1e80881a082fa2b24df9f566"],
+            ],
+            ["02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6", "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8"],
+            ["1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48", "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16"],
+        ),
+        (
+            b"abcdef0123456789",
+            [
+                ["0313d9325081b415bfd4e5364efaef392ecf69b087496973b229303e1816d2080971470f7da112c4eb43053130b785e1", "062f84cb21ed89406890c051a0e8b9cf6c575cf6e8e18ecf63ba86826b0ae02548d83b483b79e48512b82a6c0686df8f"],
+                ["1739123845406baa7be5c5dc74492051b6d42504de008c635f3535bb831d478a341420e67dcc7b46b2e8cba5379cca97", "01897665d9cb5db16a27657760bbea7951f67ad68f8d55f7113f24ba6ddd82caef240a9bfa627972279974894701d975"],
+            ],
+            ["121982811d2491fde9ba7ed31ef9ca474f0e1501297f68c298e9f4c0028add35aea8bb83d53c08cfc007c1e005723cd0", "190d119345b94fbd15497bcba94ecf7db2cbfd1e1fe7da034d26cbba169fb3968288b3fafb265f9ebd380512a71c3f2c"],
+            ["05571a0f8d3c08d094576981f4a3b8eda0a8e771fcdcc8ecceaf1356a6acf17574518acb506e435b639353c2e14827c8", "0bb5e7572275c567462d91807de765611490205a941a5a6af3b1691bfe596c31225d3aabdf15faff860cb4ef17c7c3be"],
+        ),
+    ];
+
+    fn fp2(c: [&str; 2]) -> BLS12381TwistCurveFieldElement {
+        BLS12381TwistCurveFieldElement::new([
+            BLS12381FieldElement::from_hex_unchecked(c[0]),
+            BLS12381FieldElement::from_hex_unchecked(c[1]),
+        ])
+    }
+
+    #[test]
+    fn hash_to_field_g1_matches_test_vectors() {
+        for (msg, u, _, _) in G1_VECTORS {
+            let result = hash_to_field::<BLS12381PrimeField, Sha256>(msg, G1_DST, 2).unwrap();
+            assert_eq!(result[0], BLS12381FieldElement::from_hex_unchecked(u[0]));
+            assert_eq!(result[1], BLS12381FieldElement::from_hex_unchecked(u[1]));
+        }
+    }
+
+    #[test]
+    fn hash_to_g1_matches_test_vectors() {
+        for (msg, _, x, y) in G1_VECTORS {
+            let point = hash_to_curve::<_, Sha256>(&BLS12381G1Sswu, msg, G1_DST)
+                .unwrap()
+                .to_affine();
+            assert_eq!(*point.x(), BLS12381FieldElement::from_hex_unchecked(x));
+            assert_eq!(*point.y(), BLS12381FieldElement::from_hex_unchecked(y));
+        }
+    }
+
+    #[test]
+    fn map_to_g1_matches_test_vectors() {
+        let u = BLS12381FieldElement::from_hex_unchecked(
+            "0d921c33f2bad966478a03ca35d05719bdf92d347557ea166e5bba579eea9b83e9afa5c088573c2281410369fbd32951",
+        );
+        let q0 = BLS12381G1Sswu.map_to_curve(&u).to_affine();
+        assert_eq!(
+            *q0.x(),
+            BLS12381FieldElement::from_hex_unchecked(
+                "125435adce8e1cbd1c803e7123f45392dc6e326d292499c2c45c5865985fd74fe8f042ecdeeec5ecac80680d04317d80"
+            )
+        );
+        assert_eq!(
+            *q0.y(),
+            BLS12381FieldElement::from_hex_unchecked(
+                "0e8828948c989126595ee30e4f7c931cbd6f4570735624fd25aef2fa41d3f79cfb4b4ee7b7e55a8ce013af2a5ba20bf2"
+            )
+        );
+    }
+
+    #[test]
+    fn hash_to_field_g2_matches_test_vectors() {
+        for (msg, u, _, _) in G2_VECTORS {
+            let result = hash_to_field::<Degree2ExtensionField, Sha256>(msg, G2_DST, 2).unwrap();
+            assert_eq!(result[0], fp2(u[0]));
+            assert_eq!(result[1], fp2(u[1]));
+        }
+    }
+
+    #[test]
+    fn hash_to_g2_matches_test_vectors() {
+        for (msg, _, x, y) in G2_VECTORS {
+            let point = hash_to_curve::<_, Sha256>(&BLS12381G2Sswu, msg, G2_DST)
+                .unwrap()
+                .to_affine();
+            assert_eq!(*point.x(), fp2(x));
+            assert_eq!(*point.y(), fp2(y));
+        }
+    }
+
+    #[test]
+    fn encode_to_curve_outputs_points_in_the_subgroups() {
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_NU_";
+        let p = encode_to_curve::<_, Sha256>(&BLS12381G1Sswu, b"abc", dst).unwrap();
+        assert!(p.is_in_subgroup());
+        let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_NU_";
+        let q = encode_to_curve::<_, Sha256>(&BLS12381G2Sswu, b"abc", dst).unwrap();
+        assert!(q.is_in_subgroup());
+    }
+
+    #[test]
+    fn svdw_constant_for_g1_is_minus_three() {
+        let svdw = ShallueVanDeWoestijne::<BLS12381Curve>::new();
+        assert_eq!(*svdw.z(), -BLS12381FieldElement::from(3));
+    }
+
+    #[test]
+    fn svdw_hash_to_g1_outputs_points_in_the_subgroup() {
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SVDW_RO_";
+        let svdw = ShallueVanDeWoestijne::<BLS12381Curve>::new();
+        let u = hash_to_field::<BLS12381PrimeField, Sha256>(b"abc", dst, 2).unwrap();
+        for u_i in u {
+            let q = svdw.map_to_curve(&u_i);
+            assert_eq!(
+                BLS12381Curve::defining_equation(q.x(), q.y()),
+                FieldElement::zero()
+            );
+        }
+
+        let p = hash_to_curve::<_, Sha256>(&svdw, b"abc", dst).unwrap();
+        assert!(p.is_in_subgroup());
+        assert_eq!(p, hash_to_curve::<_, Sha256>(&svdw, b"abc", dst).unwrap());
+        assert_ne!(
+            p,
+            hash_to_curve::<_, Sha256>(&BLS12381G1Sswu, b"abc", G1_DST).unwrap()
+        );
+    }
+
+    #[test]
+    fn svdw_maps_to_the_twist() {
+        let svdw = ShallueVanDeWoestijne::<BLS12381TwistCurve>::new();
+        let u = hash_to_field::<Degree2ExtensionField, Sha256>(b"abc", G2_DST, 4).unwrap();
+        for u_i in u {
+            let q = svdw.map_to_curve(&u_i);
+            assert_eq!(
+                BLS12381TwistCurve::defining_equation(q.x(), q.y()),
+                FieldElement::zero()
+            );
+            assert!(q.clear_cofactor().is_in_subgroup());
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::errors::HashToCurveError;
+use sha2::digest::{core_api::BlockSizeUser, Digest};
+
+/// Prefix used to hash domain separation tags longer than 255 bytes.
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Expands `msg` into `len_in_bytes` uniformly random bytes using a
+/// Merkle-Damgard hash function `D`, as in `expand_message_xmd`.
+/// Returns an error if `len_in_bytes` is larger than `2^16 - 1` or needs
+/// more than 255 calls to the hash function.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xmd
+pub fn expand_message_xmd<D: Digest + BlockSizeUser>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, HashToCurveError> {
+    let b_in_bytes = <D as Digest>::output_size();
+    let s_in_bytes = D::block_size();
+
+    let ell = len_in_bytes.div_ceil(b_in_bytes);
+    if ell > 255 || len_in_bytes > u16::MAX as usize {
+        return Err(HashToCurveError::InvalidOutputLength);
+    }
+
+    let dst = reduce_dst::<D>(dst);
+    let dst_prime = [dst.as_slice(), &[dst.len() as u8]].concat();
+
+    let b_0 = D::new()
+        .chain_update(vec![0u8; s_in_bytes])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut b_i = D::new()
+        .chain_update(&b_0)
+        .chain_update([1u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let xored: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+        b_i = D::new()
+            .chain_update(xored)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+
+    Ok(uniform_bytes)
+}
+
+/// Domain separation tags longer than 255 bytes are replaced by their hash.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc9380.html#name-using-dsts-longer-than-255-
+fn reduce_dst<D: Digest>(dst: &[u8]) -> Vec<u8> {
+    if dst.len() > 255 {
+        D::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(dst)
+            .finalize()
+            .to_vec()
+    } else {
+        dst.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::Sha256;
+
+    // Test vectors from https://www.rfc-editor.org/rfc/rfc9380.html#name-expand_message_xmdsha-256
+    const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    fn bytes_from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn expand_empty_message_to_32_bytes() {
+        let uniform_bytes = expand_message_xmd::<Sha256>(b"", DST, 0x20).unwrap();
+        assert_eq!(
+            uniform_bytes,
+            bytes_from_hex("68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235")
+        );
+    }
+
+    #[test]
+    fn expand_abc_to_32_bytes() {
+        let uniform_bytes = expand_message_xmd::<Sha256>(b"abc", DST, 0x20).unwrap();
+        assert_eq!(
+            uniform_bytes,
+            bytes_from_hex("d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615")
+        );
+    }
+
+    #[test]
+    fn expand_message_to_128_bytes() {
+        let uniform_bytes = expand_message_xmd::<Sha256>(b"abcdef0123456789", DST, 0x80).unwrap();
+        assert_eq!(
+            uniform_bytes,
+            bytes_from_hex(
+                "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9\
+                 ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4b\
+                 c95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be1\
+                 4cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df"
+            )
+        );
+    }
+
+    #[test]
+    fn expand_message_fails_if_too_many_blocks_are_requested() {
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"abc", DST, 255 * 32 + 1),
+            Err(HashToCurveError::InvalidOutputLength)
+        );
+    }
+}
@@ -0,0 +1,144 @@
+pub mod bls12_381;
+pub mod expand_message;
+pub mod pasta;
+pub mod sswu;
+pub mod svdw;
+
+use crate::errors::HashToCurveError;
+use expand_message::expand_message_xmd;
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{point::ShortWeierstrassProjectivePoint, traits::IsShortWeierstrass},
+        traits::HasCofactor,
+    },
+    field::{
+        element::FieldElement,
+        fields::montgomery_backed_prime_fields::{IsModulus, MontgomeryBackendPrimeField},
+        traits::{IsField, IsPrimeField, LegendreSymbol},
+    },
+    unsigned_integer::element::UnsignedInteger,
+};
+use sha2::digest::{core_api::BlockSizeUser, Digest};
+use std::fmt::Debug;
+
+/// Target security level, in bits, of the hash to field procedure.
+const SECURITY_PARAMETER: usize = 128;
+
+/// Operations on a field needed to hash into it and to map its elements to a curve.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc9380.html#name-utility-functions
+pub trait HashToField: IsField + Clone + Debug {
+    /// Degree `m` of the field over its prime subfield.
+    const EXTENSION_DEGREE: usize;
+
+    /// Number of uniform bytes `L` used to obtain an element of the prime subfield.
+    fn bytes_per_prime_element() -> usize;
+
+    /// Builds an element from `EXTENSION_DEGREE * L` uniform bytes, each coordinate being
+    /// the big endian integer of `L` bytes reduced modulo the characteristic.
+    fn from_uniform_bytes(bytes: &[u8]) -> FieldElement<Self>;
+
+    /// Returns the "sign" of an element, as defined by `sgn0`.
+    fn sgn0(a: &FieldElement<Self>) -> bool;
+
+    fn is_square(a: &FieldElement<Self>) -> bool;
+
+    /// Returns a square root of `a`, if it exists.
+    fn sqrt(a: &FieldElement<Self>) -> Option<FieldElement<Self>>;
+}
+
+impl<M, const NUM_LIMBS: usize> HashToField for MontgomeryBackendPrimeField<M, NUM_LIMBS>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    const EXTENSION_DEGREE: usize = 1;
+
+    fn bytes_per_prime_element() -> usize {
+        // L = ceil((ceil(log2(p)) + k) / 8)
+        (Self::field_bit_size() + SECURITY_PARAMETER).div_ceil(8)
+    }
+
+    fn from_uniform_bytes(bytes: &[u8]) -> FieldElement<Self> {
+        let base = FieldElement::<Self>::from(256);
+        bytes.iter().fold(FieldElement::zero(), |acc, byte| {
+            acc * &base + FieldElement::from(*byte as u64)
+        })
+    }
+
+    fn sgn0(a: &FieldElement<Self>) -> bool {
+        a.representative().limbs[NUM_LIMBS - 1] & 1 == 1
+    }
+
+    fn is_square(a: &FieldElement<Self>) -> bool {
+        a.legendre_symbol() != LegendreSymbol::MinusOne
+    }
+
+    fn sqrt(a: &FieldElement<Self>) -> Option<FieldElement<Self>> {
+        a.sqrt().map(|(root, _)| root)
+    }
+}
+
+/// Hashes `msg` to `count` field elements, using `expand_message_xmd` with the hash function `D`.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc9380.html#name-hash_to_field-implementatio
+pub fn hash_to_field<F, D>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+) -> Result<Vec<FieldElement<F>>, HashToCurveError>
+where
+    F: HashToField,
+    D: Digest + BlockSizeUser,
+{
+    let len_per_element = F::EXTENSION_DEGREE * F::bytes_per_prime_element();
+    let uniform_bytes = expand_message_xmd::<D>(msg, dst, count * len_per_element)?;
+    Ok(uniform_bytes
+        .chunks(len_per_element)
+        .map(F::from_uniform_bytes)
+        .collect())
+}
+
+/// Deterministic map from the base field of a curve to its points.
+pub trait MapToCurve {
+    type Field: HashToField;
+    type Curve: IsShortWeierstrass<BaseField = Self::Field>;
+
+    fn map_to_curve(
+        &self,
+        u: &FieldElement<Self::Field>,
+    ) -> ShortWeierstrassProjectivePoint<Self::Curve>;
+}
+
+/// Hashes `msg` to a point of the prime order subgroup of the curve, with an output
+/// distribution indistinguishable from random.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc9380.html#name-encoding-byte-strings-to-el
+pub fn hash_to_curve<M, D>(
+    map: &M,
+    msg: &[u8],
+    dst: &[u8],
+) -> Result<ShortWeierstrassProjectivePoint<M::Curve>, HashToCurveError>
+where
+    M: MapToCurve,
+    D: Digest + BlockSizeUser,
+    ShortWeierstrassProjectivePoint<M::Curve>: HasCofactor,
+{
+    let u = hash_to_field::<M::Field, D>(msg, dst, 2)?;
+    let q0 = map.map_to_curve(&u[0]);
+    let q1 = map.map_to_curve(&u[1]);
+    Ok(q0.operate_with(&q1).clear_cofactor())
+}
+
+/// Encodes `msg` to a point of the prime order subgroup of the curve. Cheaper than
+/// `hash_to_curve`, but the output only covers a fraction of the points.
+pub fn encode_to_curve<M, D>(
+    map: &M,
+    msg: &[u8],
+    dst: &[u8],
+) -> Result<ShortWeierstrassProjectivePoint<M::Curve>, HashToCurveError>
+where
+    M: MapToCurve,
+    D: Digest + BlockSizeUser,
+    ShortWeierstrassProjectivePoint<M::Curve>: HasCofactor,
+{
+    let u = hash_to_field::<M::Field, D>(msg, dst, 1)?;
+    Ok(map.map_to_curve(&u[0]).clear_cofactor())
+}
@@ -0,0 +1,91 @@
+use super::svdw::ShallueVanDeWoestijne;
+use lambdaworks_math::elliptic_curve::short_weierstrass::curves::pasta::{
+    pallas::PallasCurve, vesta::VestaCurve,
+};
+
+/// Shallue-van de Woestijne map to Pallas. The simplified SWU map does not apply
+/// directly because `a = 0`, and both curves have prime order, so no cofactor is cleared.
+///
+/// No hash to curve suite is standardized for the Pasta curves. The `pasta_curves`
+/// crate maps to an isogenous curve with the simplified SWU map instead, so the
+/// points obtained with this map differ from the ones it computes.
+pub type PallasSvdw = ShallueVanDeWoestijne<PallasCurve>;
+
+/// Shallue-van de Woestijne map to Vesta.
+pub type VestaSvdw = ShallueVanDeWoestijne<VestaCurve>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::hash_to_curve::{encode_to_curve, hash_to_curve};
+    use lambdaworks_math::{
+        cyclic_group::IsGroup,
+        elliptic_curve::{short_weierstrass::traits::IsShortWeierstrass, traits::HasEndomorphism},
+        field::element::FieldElement,
+    };
+    use sha2::Sha256;
+
+    const PALLAS_DST: &[u8] = b"LAMBDAWORKS-TEST-pallas_XMD:SHA-256_SVDW";
+    const VESTA_DST: &[u8] = b"LAMBDAWORKS-TEST-vesta_XMD:SHA-256_SVDW";
+
+    #[test]
+    fn svdw_constant_is_one() {
+        assert_eq!(*PallasSvdw::new().z(), FieldElement::one());
+        assert_eq!(*VestaSvdw::new().z(), FieldElement::one());
+    }
+
+    #[test]
+    fn hash_to_curve_outputs_distinct_points_on_the_curves() {
+        let map = PallasSvdw::new();
+        let mut points = Vec::new();
+        for msg in [b"".as_slice(), b"abc", b"abcdef0123456789"] {
+            let p = hash_to_curve::<_, Sha256>(&map, msg, PALLAS_DST).unwrap();
+            assert_eq!(
+                p,
+                hash_to_curve::<_, Sha256>(&map, msg, PALLAS_DST).unwrap()
+            );
+            let p = p.to_affine();
+            assert_eq!(
+                PallasCurve::defining_equation(p.x(), p.y()),
+                FieldElement::zero()
+            );
+            assert!(!points.contains(&p));
+            points.push(p);
+        }
+
+        let map = VestaSvdw::new();
+        let p = hash_to_curve::<_, Sha256>(&map, b"abc", VESTA_DST)
+            .unwrap()
+            .to_affine();
+        assert_eq!(
+            VestaCurve::defining_equation(p.x(), p.y()),
+            FieldElement::zero()
+        );
+        let q = hash_to_curve::<_, Sha256>(&map, b"abc", PALLAS_DST)
+            .unwrap()
+            .to_affine();
+        assert_ne!(p, q);
+    }
+
+    #[test]
+    fn encode_to_curve_outputs_points_on_the_curves() {
+        let p = encode_to_curve::<_, Sha256>(&PallasSvdw::new(), b"abc", PALLAS_DST)
+            .unwrap()
+            .to_affine();
+        assert_eq!(
+            PallasCurve::defining_equation(p.x(), p.y()),
+            FieldElement::zero()
+        );
+        // The endomorphism has order three on every point of the curve.
+        assert_eq!(p.endomorphism().endomorphism().endomorphism(), p);
+
+        let q = encode_to_curve::<_, Sha256>(&VestaSvdw::new(), b"abc", VESTA_DST)
+            .unwrap()
+            .to_affine();
+        assert_eq!(
+            VestaCurve::defining_equation(q.x(), q.y()),
+            FieldElement::zero()
+        );
+        assert!(!q.is_neutral_element());
+    }
+}
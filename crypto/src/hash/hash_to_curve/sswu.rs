@@ -0,0 +1,91 @@
+use super::HashToField;
+use lambdaworks_math::field::element::FieldElement;
+
+/// Simplified Shallue-van de Woestijne-Ulas map to the curve `y^2 = x^3 + a * x + b`,
+/// which requires `a * b != 0`. Returns the affine coordinates of the point.
+/// `z` is the non-square constant of the suite.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc9380.html#name-simplified-shallue-van-de-w
+pub fn map_to_curve_simple_swu<F: HashToField>(
+    u: &FieldElement<F>,
+    a: &FieldElement<F>,
+    b: &FieldElement<F>,
+    z: &FieldElement<F>,
+) -> (FieldElement<F>, FieldElement<F>) {
+    let z_u2 = z * u.square();
+    let tv1 = z_u2.square() + &z_u2;
+
+    let x1 = if tv1 == FieldElement::zero() {
+        // Exceptional case, x1 = b / (z * a)
+        b * (z * a).inv()
+    } else {
+        (-b * a.inv()) * (FieldElement::one() + tv1.inv())
+    };
+    let gx1 = curve_equation(&x1, a, b);
+
+    let (x, mut y) = if F::is_square(&gx1) {
+        let y1 = F::sqrt(&gx1).expect("gx1 is a square");
+        (x1, y1)
+    } else {
+        let x2 = z_u2 * x1;
+        let gx2 = curve_equation(&x2, a, b);
+        let y2 = F::sqrt(&gx2).expect("if gx1 is not a square, gx2 is");
+        (x2, y2)
+    };
+
+    if F::sgn0(u) != F::sgn0(&y) {
+        y = -y;
+    }
+    (x, y)
+}
+
+/// Returns `x^3 + a * x + b`.
+pub(crate) fn curve_equation<F: HashToField>(
+    x: &FieldElement<F>,
+    a: &FieldElement<F>,
+    b: &FieldElement<F>,
+) -> FieldElement<F> {
+    (x.square() + a) * x + b
+}
+
+/// Rational map from an isogenous curve, with coordinates
+/// `x = x_num(x') / x_den(x')` and `y = y' * y_num(x') / y_den(x')`.
+/// The coefficients of each polynomial are given in increasing degree.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc9380.html#name-simplified-swu-for-ab-0
+#[derive(Clone, Debug)]
+pub struct Isogeny<F: HashToField> {
+    pub x_numerator: Vec<FieldElement<F>>,
+    pub x_denominator: Vec<FieldElement<F>>,
+    pub y_numerator: Vec<FieldElement<F>>,
+    pub y_denominator: Vec<FieldElement<F>>,
+}
+
+impl<F: HashToField> Isogeny<F> {
+    /// Maps the affine point `(x, y)`. Returns `None` if the image is the point at infinity.
+    pub fn map(
+        &self,
+        x: &FieldElement<F>,
+        y: &FieldElement<F>,
+    ) -> Option<(FieldElement<F>, FieldElement<F>)> {
+        let x_den = evaluate(&self.x_denominator, x);
+        let y_den = evaluate(&self.y_denominator, x);
+        if x_den == FieldElement::zero() || y_den == FieldElement::zero() {
+            return None;
+        }
+        let x_num = evaluate(&self.x_numerator, x);
+        let y_num = evaluate(&self.y_numerator, x);
+        Some((x_num * x_den.inv(), y * y_num * y_den.inv()))
+    }
+}
+
+/// Evaluates the polynomial with the given coefficients at `x` using Horner's rule.
+fn evaluate<F: HashToField>(
+    coefficients: &[FieldElement<F>],
+    x: &FieldElement<F>,
+) -> FieldElement<F> {
+    coefficients
+        .iter()
+        .rev()
+        .fold(FieldElement::zero(), |acc, coefficient| {
+            acc * x + coefficient
+        })
+}
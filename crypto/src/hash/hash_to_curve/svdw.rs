@@ -0,0 +1,148 @@
+use super::{sswu::curve_equation, HashToField, MapToCurve};
+use lambdaworks_math::{
+    elliptic_curve::short_weierstrass::{
+        point::ShortWeierstrassProjectivePoint, traits::IsShortWeierstrass,
+    },
+    field::element::FieldElement,
+};
+use std::marker::PhantomData;
+
+/// Shallue-van de Woestijne map to a short Weierstrass curve. It works for any curve,
+/// so it is the fallback for curves where the simplified SWU map does not apply.
+/// The constants of the map depend only on the curve and are computed once in `new`.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc9380.html#name-shallue-van-de-woestijne-met
+#[derive(Clone, Debug)]
+pub struct ShallueVanDeWoestijne<E: IsShortWeierstrass>
+where
+    E::BaseField: HashToField,
+{
+    z: FieldElement<E::BaseField>,
+    /// g(Z)
+    c1: FieldElement<E::BaseField>,
+    /// -Z / 2
+    c2: FieldElement<E::BaseField>,
+    /// sqrt(-g(Z) * (3 * Z^2 + 4 * A)), with sgn0(c3) = 0
+    c3: FieldElement<E::BaseField>,
+    /// -4 * g(Z) / (3 * Z^2 + 4 * A)
+    c4: FieldElement<E::BaseField>,
+    phantom: PhantomData<E>,
+}
+
+impl<E: IsShortWeierstrass> ShallueVanDeWoestijne<E>
+where
+    E::BaseField: HashToField,
+{
+    pub fn new() -> Self {
+        let a = E::a();
+        let b = E::b();
+        let z = Self::find_z();
+
+        let c1 = curve_equation(&z, &a, &b);
+        let c2 = -&z * FieldElement::from(2).inv();
+        let t = FieldElement::from(3) * z.square() + FieldElement::from(4) * &a;
+        let mut c3 = E::BaseField::sqrt(&(-&c1 * &t)).expect("Z is chosen so that c3 exists");
+        if E::BaseField::sgn0(&c3) {
+            c3 = -c3;
+        }
+        let c4 = -FieldElement::from(4) * &c1 * t.inv();
+
+        Self {
+            z,
+            c1,
+            c2,
+            c3,
+            c4,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn z(&self) -> &FieldElement<E::BaseField> {
+        &self.z
+    }
+
+    /// Finds the constant `Z` of the map, the first element of the sequence
+    /// `1, -1, 2, -2, ...` satisfying the criteria of the RFC.
+    /// For more info, see https://www.rfc-editor.org/rfc/rfc9380.html#name-finding-z-for-the-shallue-v
+    fn find_z() -> FieldElement<E::BaseField> {
+        let a = E::a();
+        let b = E::b();
+        let g = |x: &FieldElement<E::BaseField>| curve_equation(x, &a, &b);
+        let h = |z: &FieldElement<E::BaseField>| {
+            -(FieldElement::from(3) * z.square() + FieldElement::from(4) * &a)
+                * (FieldElement::from(4) * g(z)).inv()
+        };
+
+        let mut ctr = FieldElement::<E::BaseField>::one();
+        loop {
+            for z in [ctr.clone(), -&ctr] {
+                let gz = g(&z);
+                if gz == FieldElement::zero() {
+                    continue;
+                }
+                let hz = h(&z);
+                if hz == FieldElement::zero() || !E::BaseField::is_square(&hz) {
+                    continue;
+                }
+                if E::BaseField::is_square(&gz)
+                    || E::BaseField::is_square(&g(&(-&z * FieldElement::from(2).inv())))
+                {
+                    return z;
+                }
+            }
+            ctr += FieldElement::one();
+        }
+    }
+}
+
+impl<E: IsShortWeierstrass> Default for ShallueVanDeWoestijne<E>
+where
+    E::BaseField: HashToField,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: IsShortWeierstrass> MapToCurve for ShallueVanDeWoestijne<E>
+where
+    E::BaseField: HashToField,
+{
+    type Field = E::BaseField;
+    type Curve = E;
+
+    fn map_to_curve(&self, u: &FieldElement<E::BaseField>) -> ShortWeierstrassProjectivePoint<E> {
+        let a = E::a();
+        let b = E::b();
+
+        let tv1 = u.square() * &self.c1;
+        let tv2 = FieldElement::one() + &tv1;
+        let tv1 = FieldElement::one() - tv1;
+        let tv1_tv2 = &tv1 * &tv2;
+        // inv0, with the convention that the inverse of zero is zero
+        let tv3 = if tv1_tv2 == FieldElement::zero() {
+            FieldElement::zero()
+        } else {
+            tv1_tv2.inv()
+        };
+        let tv5 = u * tv1 * &tv3 * &self.c3;
+
+        let x1 = &self.c2 - &tv5;
+        let x2 = &self.c2 + tv5;
+        let x3 = &self.z + &self.c4 * (tv2.square() * tv3).square();
+
+        let (x, gx) = [x1, x2, x3]
+            .into_iter()
+            .map(|x| {
+                let gx = curve_equation(&x, &a, &b);
+                (x, gx)
+            })
+            .find(|(_, gx)| E::BaseField::is_square(gx))
+            .expect("one of g(x1), g(x2), g(x3) is a square");
+        let mut y = E::BaseField::sqrt(&gx).expect("gx is a square");
+
+        if E::BaseField::sgn0(u) != E::BaseField::sgn0(&y) {
+            y = -y;
+        }
+        ShortWeierstrassProjectivePoint::new([x, y, FieldElement::one()])
+    }
+}
@@ -1,3 +1,3 @@
-pub mod hash_to_field;
+pub mod hash_to_curve;
 pub mod poseidon;
 pub mod sha3;
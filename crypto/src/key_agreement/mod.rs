@@ -0,0 +1,2 @@
+pub mod x25519;
+pub mod x448;
@@ -0,0 +1,170 @@
+use lambdaworks_math::{
+    elliptic_curve::{
+        edwards::curves::ed25519::curve::Ed25519FieldElement,
+        montgomery::{curves::curve25519::Curve25519, point::MontgomeryProjectivePoint},
+    },
+    field::element::FieldElement,
+    traits::ByteConversion,
+    unsigned_integer::element::U256,
+};
+
+/// Number of bytes of scalars, u-coordinates and shared secrets.
+pub const X25519_KEY_SIZE: usize = 32;
+
+/// The u-coordinate of the base point of Curve25519.
+const BASE_POINT_U: u8 = 9;
+
+/// Sets the three least significant bits of `scalar` to zero, its bit 255 to zero and
+/// its bit 254 to one, so that it is a multiple of the cofactor 8 in `[2^254, 2^255)`.
+fn clamp(scalar: &[u8; X25519_KEY_SIZE]) -> [u8; X25519_KEY_SIZE] {
+    let mut scalar = *scalar;
+    scalar[0] &= 248;
+    scalar[31] &= 127;
+    scalar[31] |= 64;
+    scalar
+}
+
+/// Decodes a u-coordinate: the most significant bit is ignored and the remaining
+/// 255-bit integer is reduced modulo `2^255 - 19`.
+fn decode_u(u: &[u8; X25519_KEY_SIZE]) -> Ed25519FieldElement {
+    let mut u = *u;
+    u[31] &= 127;
+    FieldElement::new(U256::from_bytes_le(&u).expect("32 bytes fit in a U256"))
+}
+
+/// The X25519 function of RFC 7748, section 5: multiplies the point of Curve25519 with
+/// u-coordinate `u` by the clamped `scalar` with a Montgomery ladder, and returns the
+/// u-coordinate of the result. Both inputs and the output are little-endian.
+pub fn x25519(scalar: &[u8; X25519_KEY_SIZE], u: &[u8; X25519_KEY_SIZE]) -> [u8; X25519_KEY_SIZE] {
+    let scalar = U256::from_bytes_le(&clamp(scalar)).expect("32 bytes fit in a U256");
+    let [x, z] = MontgomeryProjectivePoint::<Curve25519>::x_only_ladder(&decode_u(u), scalar, 255);
+    // The point at infinity is encoded as zero.
+    let u = if z == FieldElement::zero() {
+        FieldElement::zero()
+    } else {
+        x / z
+    };
+    u.to_bytes_le().try_into().unwrap()
+}
+
+/// Returns the public key of `secret_key`, the X25519 function applied to the base point.
+pub fn public_key(secret_key: &[u8; X25519_KEY_SIZE]) -> [u8; X25519_KEY_SIZE] {
+    let mut base_point = [0; X25519_KEY_SIZE];
+    base_point[0] = BASE_POINT_U;
+    x25519(secret_key, &base_point)
+}
+
+/// Computes the secret shared with the owner of `peer_public_key`. Returns `None` if
+/// the result is zero, which happens when the peer's key is a point of small order.
+pub fn diffie_hellman(
+    secret_key: &[u8; X25519_KEY_SIZE],
+    peer_public_key: &[u8; X25519_KEY_SIZE],
+) -> Option<[u8; X25519_KEY_SIZE]> {
+    let shared_secret = x25519(secret_key, peer_public_key);
+    if shared_secret.iter().all(|byte| *byte == 0) {
+        None
+    } else {
+        Some(shared_secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(hex: &str) -> [u8; X25519_KEY_SIZE] {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    // Test vectors from RFC 7748, section 5.2. The u-coordinate of the second one
+    // has its most significant bit set.
+    #[test]
+    fn x25519_matches_rfc7748_vectors() {
+        let vectors = [
+            (
+                "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+                "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+                "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+            ),
+            (
+                "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+                "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+                "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+            ),
+        ];
+        for (scalar, u, expected) in vectors {
+            assert_eq!(x25519(&from_hex(scalar), &from_hex(u)), from_hex(expected));
+        }
+    }
+
+    fn iterate(iterations: usize) -> [u8; X25519_KEY_SIZE] {
+        let mut k = [0; X25519_KEY_SIZE];
+        k[0] = 9;
+        let mut u = k;
+        for _ in 0..iterations {
+            let result = x25519(&k, &u);
+            u = k;
+            k = result;
+        }
+        k
+    }
+
+    #[test]
+    fn x25519_matches_rfc7748_iterated_vector_after_one_iteration() {
+        assert_eq!(
+            iterate(1),
+            from_hex("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+        );
+    }
+
+    #[test]
+    fn x25519_matches_rfc7748_iterated_vector_after_one_thousand_iterations() {
+        assert_eq!(
+            iterate(1000),
+            from_hex("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51")
+        );
+    }
+
+    // Test vector from RFC 7748, section 6.1.
+    #[test]
+    fn diffie_hellman_matches_rfc7748_vector() {
+        let alice_secret =
+            from_hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob_secret =
+            from_hex("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+        let alice_public = public_key(&alice_secret);
+        let bob_public = public_key(&bob_secret);
+        assert_eq!(
+            alice_public,
+            from_hex("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+        assert_eq!(
+            bob_public,
+            from_hex("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        );
+        let shared_secret =
+            from_hex("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        assert_eq!(
+            diffie_hellman(&alice_secret, &bob_public),
+            Some(shared_secret)
+        );
+        assert_eq!(
+            diffie_hellman(&bob_secret, &alice_public),
+            Some(shared_secret)
+        );
+    }
+
+    #[test]
+    fn diffie_hellman_rejects_points_of_small_order() {
+        let secret = [7; X25519_KEY_SIZE];
+        assert_eq!(diffie_hellman(&secret, &[0; X25519_KEY_SIZE]), None);
+        let mut one = [0; X25519_KEY_SIZE];
+        one[0] = 1;
+        assert_eq!(diffie_hellman(&secret, &one), None);
+    }
+}
@@ -0,0 +1,153 @@
+use lambdaworks_math::{
+    elliptic_curve::montgomery::{curves::curve448::Curve448, point::MontgomeryProjectivePoint},
+    field::{
+        element::FieldElement,
+        fields::p448_goldilocks_prime_field::{P448GoldilocksPrimeField, U448},
+    },
+    traits::ByteConversion,
+};
+
+/// Number of bytes of scalars, u-coordinates and shared secrets.
+pub const X448_KEY_SIZE: usize = 56;
+
+/// The u-coordinate of the base point of Curve448.
+const BASE_POINT_U: u8 = 5;
+
+/// Sets the two least significant bits of `scalar` to zero and its most significant
+/// bit to one, so that it is a multiple of the cofactor 4 in `[2^447, 2^448)`.
+fn clamp(scalar: &[u8; X448_KEY_SIZE]) -> [u8; X448_KEY_SIZE] {
+    let mut scalar = *scalar;
+    scalar[0] &= 252;
+    scalar[55] |= 128;
+    scalar
+}
+
+/// The X448 function of RFC 7748, section 5: multiplies the point of Curve448 with
+/// u-coordinate `u` by the clamped `scalar` with a Montgomery ladder, and returns the
+/// u-coordinate of the result. Both inputs and the output are little-endian.
+pub fn x448(scalar: &[u8; X448_KEY_SIZE], u: &[u8; X448_KEY_SIZE]) -> [u8; X448_KEY_SIZE] {
+    let scalar = U448::from_bytes_le(&clamp(scalar)).expect("56 bytes fit in a U448");
+    let u = U448::from_bytes_le(u).expect("56 bytes fit in a U448");
+    let u = FieldElement::from_raw(&P448GoldilocksPrimeField::from_integer(&u));
+    let [x, z] = MontgomeryProjectivePoint::<Curve448>::x_only_ladder(&u, scalar, 448);
+    // `z^(p - 2)` is zero when `z` is, so the point at infinity is encoded as zero.
+    (x * z.inv()).to_bytes_le().try_into().unwrap()
+}
+
+/// Returns the public key of `secret_key`, the X448 function applied to the base point.
+pub fn public_key(secret_key: &[u8; X448_KEY_SIZE]) -> [u8; X448_KEY_SIZE] {
+    let mut base_point = [0; X448_KEY_SIZE];
+    base_point[0] = BASE_POINT_U;
+    x448(secret_key, &base_point)
+}
+
+/// Computes the secret shared with the owner of `peer_public_key`. Returns `None` if
+/// the result is zero, which happens when the peer's key is a point of small order.
+pub fn diffie_hellman(
+    secret_key: &[u8; X448_KEY_SIZE],
+    peer_public_key: &[u8; X448_KEY_SIZE],
+) -> Option<[u8; X448_KEY_SIZE]> {
+    let shared_secret = x448(secret_key, peer_public_key);
+    if shared_secret.iter().all(|byte| *byte == 0) {
+        None
+    } else {
+        Some(shared_secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(hex: &str) -> [u8; X448_KEY_SIZE] {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    // Test vectors from RFC 7748, section 5.2.
+    #[test]
+    fn x448_matches_rfc7748_vectors() {
+        let vectors = [
+            (
+                "3d262fddf9ec8e88495266fea19a34d28882acef045104d0d1aae121700a779c984c24f8cdd78fbff44943eba368f54b29259a4f1c600ad3",
+                "06fce640fa3487bfda5f6cf2d5263f8aad88334cbd07437f020f08f9814dc031ddbdc38c19c6da2583fa5429db94ada18aa7a7fb4ef8a086",
+                "ce3e4ff95a60dc6697da1db1d85e6afbdf79b50a2412d7546d5f239fe14fbaadeb445fc66a01b0779d98223961111e21766282f73dd96b6f",
+            ),
+            (
+                "203d494428b8399352665ddca42f9de8fef600908e0d461cb021f8c538345dd77c3e4806e25f46d3315c44e0a5b4371282dd2c8d5be3095f",
+                "0fbcc2f993cd56d3305b0b7d9e55d4c1a8fb5dbb52f8e9a1e9b6201b165d015894e56c4d3570bee52fe205e28a78b91cdfbde71ce8d157db",
+                "884a02576239ff7a2f2f63b2db6a9ff37047ac13568e1e30fe63c4a7ad1b3ee3a5700df34321d62077e63633c575c1c954514e99da7c179d",
+            ),
+        ];
+        for (scalar, u, expected) in vectors {
+            assert_eq!(x448(&from_hex(scalar), &from_hex(u)), from_hex(expected));
+        }
+    }
+
+    fn iterate(iterations: usize) -> [u8; X448_KEY_SIZE] {
+        let mut k = [0; X448_KEY_SIZE];
+        k[0] = 5;
+        let mut u = k;
+        for _ in 0..iterations {
+            let result = x448(&k, &u);
+            u = k;
+            k = result;
+        }
+        k
+    }
+
+    #[test]
+    fn x448_matches_rfc7748_iterated_vector_after_one_iteration() {
+        assert_eq!(
+            iterate(1),
+            from_hex("3f482c8a9f19b01e6c46ee9711d9dc14fd4bf67af30765c2ae2b846a4d23a8cd0db897086239492caf350b51f833868b9bc2b3bca9cf4113")
+        );
+    }
+
+    #[test]
+    fn x448_matches_rfc7748_iterated_vector_after_one_thousand_iterations() {
+        assert_eq!(
+            iterate(1000),
+            from_hex("aa3b4749d55b9daf1e5b00288826c467274ce3ebbdd5c17b975e09d4af6c67cf10d087202db88286e2b79fceea3ec353ef54faa26e219f38")
+        );
+    }
+
+    // Test vector from RFC 7748, section 6.2.
+    #[test]
+    fn diffie_hellman_matches_rfc7748_vector() {
+        let alice_secret = from_hex("9a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28dd9c9baf574a9419744897391006382a6f127ab1d9ac2d8c0a598726b");
+        let bob_secret = from_hex("1c306a7ac2a0e2e0990b294470cba339e6453772b075811d8fad0d1d6927c120bb5ee8972b0d3e21374c9c921b09d1b0366f10b65173992d");
+        let alice_public = public_key(&alice_secret);
+        let bob_public = public_key(&bob_secret);
+        assert_eq!(
+            alice_public,
+            from_hex("9b08f7cc31b7e3e67d22d5aea121074a273bd2b83de09c63faa73d2c22c5d9bbc836647241d953d40c5b12da88120d53177f80e532c41fa0")
+        );
+        assert_eq!(
+            bob_public,
+            from_hex("3eb7a829b0cd20f5bcfc0b599b6feccf6da4627107bdb0d4f345b43027d8b972fc3e34fb4232a13ca706dcb57aec3dae07bdc1c67bf33609")
+        );
+        let shared_secret = from_hex("07fff4181ac6cc95ec1c16a94a0f74d12da232ce40a77552281d282bb60c0b56fd2464c335543936521c24403085d59a449a5037514a879d");
+        assert_eq!(
+            diffie_hellman(&alice_secret, &bob_public),
+            Some(shared_secret)
+        );
+        assert_eq!(
+            diffie_hellman(&bob_secret, &alice_public),
+            Some(shared_secret)
+        );
+    }
+
+    #[test]
+    fn diffie_hellman_rejects_points_of_small_order() {
+        let secret = [7; X448_KEY_SIZE];
+        assert_eq!(diffie_hellman(&secret, &[0; X448_KEY_SIZE]), None);
+        let mut one = [0; X448_KEY_SIZE];
+        one[0] = 1;
+        assert_eq!(diffie_hellman(&secret, &one), None);
+    }
+}
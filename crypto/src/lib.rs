@@ -2,4 +2,6 @@ pub mod commitments;
 pub mod errors;
 pub mod fiat_shamir;
 pub mod hash;
+pub mod key_agreement;
 pub mod merkle_tree;
+pub mod signatures;
@@ -0,0 +1,244 @@
+use super::{IsBlsVariant, PublicKeyPoint, SignaturePoint};
+use crate::hash::hash_to_curve::{bls12_381::BLS12381G2Sswu, hash_to_curve};
+use lambdaworks_math::{
+    elliptic_curve::{
+        short_weierstrass::curves::bls12_381::{
+            curve::BLS12381Curve, pairing::BLS12381AtePairing, twist::BLS12381TwistCurve,
+        },
+        traits::{HasCofactor, IsPairing},
+    },
+    field::element::FieldElement,
+};
+use sha2::Sha256;
+
+/// The minimal-pubkey-size variant: public keys are points of G1, of 48 bytes, and
+/// signatures are points of G2, of 96 bytes. Used by Ethereum consensus.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MinPk;
+
+impl IsBlsVariant for MinPk {
+    type PublicKeyCurve = BLS12381Curve;
+    type SignatureCurve = BLS12381TwistCurve;
+
+    const SIGNATURE_DST: &'static [u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+    const POP_DST: &'static [u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+    fn hash_to_point(message: &[u8], dst: &[u8]) -> SignaturePoint<Self> {
+        hash_to_curve::<_, Sha256>(&BLS12381G2Sswu, message, dst)
+            .expect("the domain separation tags of the ciphersuite are valid")
+    }
+
+    fn public_key_is_in_subgroup(point: &PublicKeyPoint<Self>) -> bool {
+        point.is_in_subgroup()
+    }
+
+    fn signature_is_in_subgroup(point: &SignaturePoint<Self>) -> bool {
+        point.is_in_subgroup()
+    }
+
+    fn pairing_product_is_one(pairs: &[(&PublicKeyPoint<Self>, &SignaturePoint<Self>)]) -> bool {
+        BLS12381AtePairing::compute_batch(pairs) == FieldElement::one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::BlsError,
+        signatures::bls::{
+            aggregate_verify, fast_aggregate_verify, PublicKey, SecretKey, Signature,
+        },
+    };
+    use lambdaworks_math::{
+        cyclic_group::IsGroup, elliptic_curve::short_weierstrass::traits::Compress,
+    };
+    use std::marker::PhantomData;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    const SECRET_KEYS: [&str; 3] = [
+        "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3",
+        "47b8192d77bf871b62e87859d653922725724a5c031afeabc60bcef5ff665138",
+        "328388aff0d4a5b7dc9205abd374e7e98f3cd9f3418edb4eafda5fb16473d216",
+    ];
+
+    fn secret_keys() -> Vec<SecretKey<MinPk>> {
+        SECRET_KEYS
+            .iter()
+            .map(|key| SecretKey::from_bytes(&from_hex(key)).unwrap())
+            .collect()
+    }
+
+    // Test vectors from the Ethereum consensus specs BLS tests, which use this ciphersuite.
+    #[test]
+    fn public_keys_match_vectors() {
+        let expected = [
+            "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a",
+            "b301803f8b5ac4a1133581fc676dfedc60d891dd5fa99028805e5ea5b08d3491af75d0707adab3b70c6a6a580217bf81",
+            "b53d21a4cfd562c469cc81514d4ce5a6b577d8403d32a394dc265dd190b47fa9f829fdd7963afdf972e5e77854051f6f",
+        ];
+        for (secret_key, expected) in secret_keys().iter().zip(expected) {
+            assert_eq!(secret_key.public_key().to_bytes(), from_hex(expected));
+        }
+    }
+
+    #[test]
+    fn signatures_match_vectors() {
+        let secret_key = &secret_keys()[0];
+        let vectors = [
+            ([0x00; 32], "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55"),
+            ([0x56; 32], "882730e5d03f6b42c3abc26d3372625034e1d871b65a8a6b900a56dae22da98abbe1b68f85e49fe7652a55ec3d0591c20767677e33e5cbb1207315c41a9ac03be39c2e7668edc043d6cb1d9fd93033caa8a1c5b0e84bedaeb6c64972503a43eb"),
+            ([0xab; 32], "91347bccf740d859038fcdcaf233eeceb2a436bcaaee9b2aa3bfb70efe29dfb2677562ccbea1c8e061fb9971b0753c240622fab78489ce96768259fc01360346da5b9f579e5da0d941e4c6ba18a0e64906082375394f337fa1af2b7127b0d121"),
+        ];
+        for (message, expected) in vectors {
+            let signature = secret_key.sign(&message);
+            assert_eq!(signature.to_bytes(), from_hex(expected));
+            assert!(secret_key.public_key().verify(&message, &signature));
+        }
+    }
+
+    #[test]
+    fn fast_aggregate_verify_matches_vector() {
+        let message = [0xab; 32];
+        let secret_keys = secret_keys();
+        let public_keys: Vec<_> = secret_keys.iter().map(|key| key.public_key()).collect();
+        let signatures: Vec<_> = secret_keys.iter().map(|key| key.sign(&message)).collect();
+        let signature = Signature::aggregate(&signatures).unwrap();
+        assert_eq!(
+            signature.to_bytes(),
+            from_hex("9712c3edd73a209c742b8250759db12549b3eaf43b5ca61376d9f30e2747dbcf842d8b2ac0901d2a093713e20284a7670fcf6954e9ab93de991bb9b313e664785a075fc285806fa5224c82bde146561b446ccfc706a64b8579513cfc4ff1d930")
+        );
+        assert!(fast_aggregate_verify(&public_keys, &message, &signature));
+        assert!(!fast_aggregate_verify(
+            &public_keys[..2],
+            &message,
+            &signature
+        ));
+        assert!(!fast_aggregate_verify(
+            &public_keys,
+            &[0x56; 32],
+            &signature
+        ));
+        assert!(!fast_aggregate_verify(&[], &message, &signature));
+    }
+
+    #[test]
+    fn verify_rejects_wrong_message_and_key() {
+        let secret_keys = secret_keys();
+        let signature = secret_keys[0].sign(b"message");
+        assert!(!secret_keys[0]
+            .public_key()
+            .verify(b"other message", &signature));
+        assert!(!secret_keys[1].public_key().verify(b"message", &signature));
+    }
+
+    #[test]
+    fn aggregate_verify_checks_every_message() {
+        let secret_keys = secret_keys();
+        let public_keys: Vec<_> = secret_keys.iter().map(|key| key.public_key()).collect();
+        let messages: [&[u8]; 3] = [b"first", b"second", b"third"];
+        let signatures: Vec<_> = secret_keys
+            .iter()
+            .zip(messages)
+            .map(|(key, message)| key.sign(message))
+            .collect();
+        let signature = Signature::aggregate(&signatures).unwrap();
+        assert!(aggregate_verify(&public_keys, &messages, &signature));
+        assert!(!aggregate_verify(
+            &public_keys,
+            &[b"first", b"second", b"fourth"],
+            &signature
+        ));
+        assert!(!aggregate_verify(
+            &public_keys[..2],
+            &messages[..2],
+            &signature
+        ));
+        assert!(!aggregate_verify(&public_keys, &messages[..2], &signature));
+    }
+
+    #[test]
+    fn proof_of_possession_is_bound_to_the_key() {
+        let secret_keys = secret_keys();
+        let proof = secret_keys[0].pop_prove();
+        assert!(secret_keys[0].public_key().pop_verify(&proof));
+        assert!(!secret_keys[1].public_key().pop_verify(&proof));
+        // A signature of the encoding of the public key is not a proof of possession.
+        let public_key = secret_keys[0].public_key();
+        assert!(!public_key.pop_verify(&secret_keys[0].sign(&public_key.to_bytes())));
+    }
+
+    #[test]
+    fn encodings_roundtrip() {
+        let secret_key = &secret_keys()[1];
+        assert_eq!(
+            SecretKey::<MinPk>::from_bytes(&secret_key.to_bytes())
+                .unwrap()
+                .to_bytes(),
+            secret_key.to_bytes()
+        );
+        let public_key = secret_key.public_key();
+        assert_eq!(
+            PublicKey::from_bytes(&public_key.to_bytes()).unwrap(),
+            public_key
+        );
+        let signature = secret_key.sign(b"message");
+        assert_eq!(
+            Signature::from_bytes(&signature.to_bytes()).unwrap(),
+            signature
+        );
+    }
+
+    #[test]
+    fn identity_public_key_is_rejected() {
+        let identity = BLS12381Curve::compress(&PublicKeyPoint::<MinPk>::neutral_element());
+        assert_eq!(
+            PublicKey::<MinPk>::from_bytes(&identity),
+            Err(BlsError::InvalidPublicKey)
+        );
+        assert_eq!(
+            PublicKey::<MinPk>::from_bytes(&[0; 48]),
+            Err(BlsError::InvalidPublicKey)
+        );
+    }
+
+    #[test]
+    fn opposite_keys_do_not_accept_the_identity_signature() {
+        let secret_key = &secret_keys()[0];
+        let opposite_key = SecretKey::<MinPk> {
+            scalar: -&secret_key.scalar,
+            phantom: PhantomData,
+        };
+        let public_keys = [secret_key.public_key(), opposite_key.public_key()];
+        assert!(public_keys[0].pop_verify(&secret_key.pop_prove()));
+        assert!(public_keys[1].pop_verify(&opposite_key.pop_prove()));
+        assert_eq!(
+            PublicKey::aggregate(&public_keys),
+            Err(BlsError::IdentityAggregatePublicKey)
+        );
+        let identity = Signature::from_bytes(&BLS12381TwistCurve::compress(
+            &SignaturePoint::<MinPk>::neutral_element(),
+        ))
+        .unwrap();
+        assert!(!fast_aggregate_verify(&public_keys, b"message", &identity));
+        assert!(!fast_aggregate_verify(&public_keys, b"other", &identity));
+    }
+
+    #[test]
+    fn aggregating_nothing_fails() {
+        assert_eq!(
+            Signature::<MinPk>::aggregate(&[]),
+            Err(BlsError::EmptyAggregation)
+        );
+        assert_eq!(
+            PublicKey::<MinPk>::aggregate(&[]),
+            Err(BlsError::EmptyAggregation)
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use super::{IsBlsVariant, PublicKeyPoint, SignaturePoint};
+use crate::hash::hash_to_curve::{bls12_381::BLS12381G1Sswu, hash_to_curve};
+use lambdaworks_math::{
+    elliptic_curve::{
+        short_weierstrass::curves::bls12_381::{
+            curve::BLS12381Curve, pairing::BLS12381AtePairing, twist::BLS12381TwistCurve,
+        },
+        traits::{HasCofactor, IsPairing},
+    },
+    field::element::FieldElement,
+};
+use sha2::Sha256;
+
+/// The minimal-signature-size variant: public keys are points of G2, of 96 bytes, and
+/// signatures are points of G1, of 48 bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MinSig;
+
+impl IsBlsVariant for MinSig {
+    type PublicKeyCurve = BLS12381TwistCurve;
+    type SignatureCurve = BLS12381Curve;
+
+    const SIGNATURE_DST: &'static [u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+    const POP_DST: &'static [u8] = b"BLS_POP_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+
+    fn hash_to_point(message: &[u8], dst: &[u8]) -> SignaturePoint<Self> {
+        hash_to_curve::<_, Sha256>(&BLS12381G1Sswu, message, dst)
+            .expect("the domain separation tags of the ciphersuite are valid")
+    }
+
+    fn public_key_is_in_subgroup(point: &PublicKeyPoint<Self>) -> bool {
+        point.is_in_subgroup()
+    }
+
+    fn signature_is_in_subgroup(point: &SignaturePoint<Self>) -> bool {
+        point.is_in_subgroup()
+    }
+
+    fn pairing_product_is_one(pairs: &[(&PublicKeyPoint<Self>, &SignaturePoint<Self>)]) -> bool {
+        let pairs: Vec<_> = pairs
+            .iter()
+            .map(|(public_key, signature)| (*signature, *public_key))
+            .collect();
+        BLS12381AtePairing::compute_batch(&pairs) == FieldElement::one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::BlsError,
+        signatures::bls::{
+            aggregate_verify, fast_aggregate_verify, PublicKey, SecretKey, Signature,
+        },
+    };
+    use lambdaworks_math::{
+        cyclic_group::IsGroup, elliptic_curve::short_weierstrass::traits::Compress,
+    };
+
+    fn secret_keys() -> Vec<SecretKey<MinSig>> {
+        (0..3_u8)
+            .map(|i| SecretKey::key_gen(&[i; 32], b"min sig").unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn keys_and_signatures_have_the_minimal_signature_sizes() {
+        let secret_key = &secret_keys()[0];
+        assert_eq!(secret_key.public_key().to_bytes().len(), 96);
+        assert_eq!(secret_key.sign(b"message").to_bytes().len(), 48);
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let secret_keys = secret_keys();
+        let signature = secret_keys[0].sign(b"message");
+        assert!(secret_keys[0].public_key().verify(b"message", &signature));
+        assert!(!secret_keys[0]
+            .public_key()
+            .verify(b"other message", &signature));
+        assert!(!secret_keys[1].public_key().verify(b"message", &signature));
+    }
+
+    #[test]
+    fn aggregate_signatures_verify() {
+        let secret_keys = secret_keys();
+        let public_keys: Vec<_> = secret_keys.iter().map(|key| key.public_key()).collect();
+        let messages: [&[u8]; 3] = [b"first", b"second", b"third"];
+        let signatures: Vec<_> = secret_keys
+            .iter()
+            .zip(messages)
+            .map(|(key, message)| key.sign(message))
+            .collect();
+        let signature = Signature::aggregate(&signatures).unwrap();
+        assert!(aggregate_verify(&public_keys, &messages, &signature));
+        assert!(!aggregate_verify(
+            &public_keys,
+            &[b"first", b"third", b"second"],
+            &signature
+        ));
+
+        let signatures: Vec<_> = secret_keys.iter().map(|key| key.sign(b"same")).collect();
+        let signature = Signature::aggregate(&signatures).unwrap();
+        assert!(fast_aggregate_verify(&public_keys, b"same", &signature));
+        assert!(!fast_aggregate_verify(
+            &public_keys[1..],
+            b"same",
+            &signature
+        ));
+    }
+
+    #[test]
+    fn proof_of_possession_is_bound_to_the_key() {
+        let secret_keys = secret_keys();
+        let proof = secret_keys[0].pop_prove();
+        assert!(secret_keys[0].public_key().pop_verify(&proof));
+        assert!(!secret_keys[1].public_key().pop_verify(&proof));
+    }
+
+    #[test]
+    fn encodings_roundtrip_and_identity_is_rejected() {
+        let secret_key = &secret_keys()[2];
+        let public_key = secret_key.public_key();
+        assert_eq!(
+            PublicKey::from_bytes(&public_key.to_bytes()).unwrap(),
+            public_key
+        );
+        let signature = secret_key.sign(b"message");
+        assert_eq!(
+            Signature::from_bytes(&signature.to_bytes()).unwrap(),
+            signature
+        );
+        let identity = BLS12381TwistCurve::compress(&PublicKeyPoint::<MinSig>::neutral_element());
+        assert_eq!(
+            PublicKey::<MinSig>::from_bytes(&identity),
+            Err(BlsError::InvalidPublicKey)
+        );
+    }
+}
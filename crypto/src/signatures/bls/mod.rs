@@ -0,0 +1,417 @@
+pub mod min_pk;
+pub mod min_sig;
+
+use crate::{errors::BlsError, hash::hash_to_curve::HashToField, signatures::ecdsa::rfc6979::hmac};
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::short_weierstrass::curves::bls12_381::default_types::{
+        FrConfig, FrElement, FrField,
+    },
+    elliptic_curve::{
+        short_weierstrass::{point::ShortWeierstrassProjectivePoint, traits::Compress},
+        traits::IsEllipticCurve,
+    },
+    field::{element::FieldElement, fields::montgomery_backed_prime_fields::IsModulus},
+    traits::ByteConversion,
+    unsigned_integer::element::U256,
+};
+use sha2::{Digest, Sha256};
+use std::marker::PhantomData;
+
+/// Number of bytes of the encoding of a secret key.
+pub const SECRET_KEY_SIZE: usize = 32;
+
+/// Minimum number of bytes of the key material given to `SecretKey::key_gen`.
+pub const MIN_KEY_MATERIAL_SIZE: usize = 32;
+
+/// A variant of BLS signatures on BLS12-381, which fixes the groups of public keys
+/// and signatures and the domain separation tags of the proof of possession
+/// ciphersuite. Public keys are multiples of the generator of their group.
+/// For more info, see https://datatracker.ietf.org/doc/draft-irtf-cfrg-bls-signature/
+pub trait IsBlsVariant {
+    type PublicKeyCurve: Compress
+        + IsEllipticCurve<PointRepresentation = ShortWeierstrassProjectivePoint<Self::PublicKeyCurve>>;
+    type SignatureCurve: Compress
+        + IsEllipticCurve<PointRepresentation = ShortWeierstrassProjectivePoint<Self::SignatureCurve>>;
+
+    /// Domain separation tag used to hash messages.
+    const SIGNATURE_DST: &'static [u8];
+    /// Domain separation tag used to hash public keys in proofs of possession.
+    const POP_DST: &'static [u8];
+
+    /// Hashes `message` to the group of signatures with the hash to curve suite of
+    /// the ciphersuite.
+    fn hash_to_point(message: &[u8], dst: &[u8]) -> SignaturePoint<Self>;
+
+    /// Returns true if `point` belongs to the prime order subgroup.
+    fn public_key_is_in_subgroup(point: &PublicKeyPoint<Self>) -> bool;
+
+    /// Returns true if `point` belongs to the prime order subgroup.
+    fn signature_is_in_subgroup(point: &SignaturePoint<Self>) -> bool;
+
+    /// Returns true if the product of the pairings of the pairs is one.
+    fn pairing_product_is_one(pairs: &[(&PublicKeyPoint<Self>, &SignaturePoint<Self>)]) -> bool;
+}
+
+pub type PublicKeyPoint<V> = ShortWeierstrassProjectivePoint<<V as IsBlsVariant>::PublicKeyCurve>;
+pub type SignaturePoint<V> = ShortWeierstrassProjectivePoint<<V as IsBlsVariant>::SignatureCurve>;
+
+/// A BLS secret key, an integer in `[1, r - 1]`.
+pub struct SecretKey<V: IsBlsVariant> {
+    scalar: FrElement,
+    phantom: PhantomData<V>,
+}
+
+impl<V: IsBlsVariant> SecretKey<V> {
+    /// Derives a secret key from the key material `ikm`, of at least 32 bytes, and the
+    /// optional `key_info`, with the HKDF based procedure KeyGen.
+    /// For more info, see section 2.3 of draft-irtf-cfrg-bls-signature-05.
+    pub fn key_gen(ikm: &[u8], key_info: &[u8]) -> Result<Self, BlsError> {
+        if ikm.len() < MIN_KEY_MATERIAL_SIZE {
+            return Err(BlsError::ShortKeyMaterial);
+        }
+        // L = ceil((3 * ceil(log2(r))) / 16)
+        const L: usize = 48;
+        let mut salt = b"BLS-SIG-KEYGEN-SALT-".to_vec();
+        loop {
+            salt = Sha256::digest(&salt).to_vec();
+            let prk = hmac::<Sha256>(&salt, &[ikm, &[0]]);
+            let info = [key_info, &(L as u16).to_be_bytes()].concat();
+            let okm = hkdf_expand(&prk, &info, L);
+            let scalar = FrField::from_uniform_bytes(&okm);
+            if scalar != FieldElement::zero() {
+                return Ok(Self {
+                    scalar,
+                    phantom: PhantomData,
+                });
+            }
+        }
+    }
+
+    /// Decodes a secret key from its big-endian encoding in 32 bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BlsError> {
+        if bytes.len() != SECRET_KEY_SIZE {
+            return Err(BlsError::InvalidSecretKey);
+        }
+        let integer = U256::from_bytes_be(bytes).map_err(|_| BlsError::InvalidSecretKey)?;
+        if integer == U256::from_u64(0) || integer >= FrConfig::MODULUS {
+            return Err(BlsError::InvalidSecretKey);
+        }
+        Ok(Self {
+            scalar: FieldElement::new(integer),
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns the big-endian encoding of the secret key in 32 bytes.
+    pub fn to_bytes(&self) -> [u8; SECRET_KEY_SIZE] {
+        self.scalar
+            .representative()
+            .to_bytes_be()
+            .try_into()
+            .unwrap()
+    }
+
+    /// Returns the public key `sk * G`, where `G` generates the group of public keys.
+    pub fn public_key(&self) -> PublicKey<V> {
+        PublicKey {
+            point: V::PublicKeyCurve::generator().operate_with_self(self.scalar.representative()),
+        }
+    }
+
+    /// Signs `message`, returning `sk * H(message)`.
+    pub fn sign(&self, message: &[u8]) -> Signature<V> {
+        self.sign_with_dst(message, V::SIGNATURE_DST)
+    }
+
+    /// Returns a proof of possession of the secret key, the signature of the encoding
+    /// of the public key with the tag `POP_DST`. It prevents rogue key attacks when
+    /// public keys are aggregated.
+    pub fn pop_prove(&self) -> Signature<V> {
+        self.sign_with_dst(&self.public_key().to_bytes(), V::POP_DST)
+    }
+
+    fn sign_with_dst(&self, message: &[u8], dst: &[u8]) -> Signature<V> {
+        Signature {
+            point: V::hash_to_point(message, dst).operate_with_self(self.scalar.representative()),
+        }
+    }
+}
+
+/// Computes the first `length` bytes of `HKDF-Expand(prk, info)` with SHA-256.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc5869#section-2.3
+fn hkdf_expand(prk: &[u8], info: &[u8], length: usize) -> Vec<u8> {
+    let mut okm = Vec::with_capacity(length);
+    let mut block = Vec::new();
+    let mut counter = 1_u8;
+    while okm.len() < length {
+        block = hmac::<Sha256>(prk, &[&block, info, &[counter]]);
+        okm.extend_from_slice(&block);
+        counter += 1;
+    }
+    okm.truncate(length);
+    okm
+}
+
+/// A BLS public key, a point of the prime order subgroup other than the identity.
+pub struct PublicKey<V: IsBlsVariant> {
+    point: PublicKeyPoint<V>,
+}
+
+impl<V: IsBlsVariant> PublicKey<V> {
+    /// Decodes a compressed public key, validating it as KeyValidate does: the point
+    /// must belong to the prime order subgroup and not be the identity.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BlsError> {
+        let point = V::PublicKeyCurve::decompress(bytes).map_err(|_| BlsError::InvalidPublicKey)?;
+        if point.is_neutral_element() || !V::public_key_is_in_subgroup(&point) {
+            return Err(BlsError::InvalidPublicKey);
+        }
+        Ok(Self { point })
+    }
+
+    /// Returns the compressed encoding of the public key.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        V::PublicKeyCurve::compress(&self.point)
+    }
+
+    pub fn point(&self) -> &PublicKeyPoint<V> {
+        &self.point
+    }
+
+    /// Verifies a signature of `message`, checking that
+    /// `e(pk, H(message)) = e(G, signature)`.
+    pub fn verify(&self, message: &[u8], signature: &Signature<V>) -> bool {
+        self.verify_with_dst(message, V::SIGNATURE_DST, signature)
+    }
+
+    /// Verifies a proof of possession of the secret key of `self`.
+    pub fn pop_verify(&self, proof: &Signature<V>) -> bool {
+        self.verify_with_dst(&self.to_bytes(), V::POP_DST, proof)
+    }
+
+    fn verify_with_dst(&self, message: &[u8], dst: &[u8], signature: &Signature<V>) -> bool {
+        let hash = V::hash_to_point(message, dst);
+        let minus_generator = V::PublicKeyCurve::generator().neg();
+        V::pairing_product_is_one(&[(&self.point, &hash), (&minus_generator, &signature.point)])
+    }
+
+    /// Adds public keys, so that the result verifies the aggregate of their signatures
+    /// of the same message with `verify`. Each public key must come with a valid proof
+    /// of possession, otherwise an attacker can choose its key to forge signatures.
+    /// Fails if the sum is the identity, which KeyValidate rejects: keys such as `sk`
+    /// and `-sk` have valid proofs of possession but would accept the identity
+    /// signature of any message.
+    pub fn aggregate(public_keys: &[Self]) -> Result<Self, BlsError> {
+        let (first, rest) = public_keys
+            .split_first()
+            .ok_or(BlsError::EmptyAggregation)?;
+        let point = rest.iter().fold(first.point.clone(), |acc, public_key| {
+            acc.operate_with(&public_key.point)
+        });
+        if point.is_neutral_element() {
+            return Err(BlsError::IdentityAggregatePublicKey);
+        }
+        Ok(Self { point })
+    }
+}
+
+/// A BLS signature, a point of the prime order subgroup.
+pub struct Signature<V: IsBlsVariant> {
+    point: SignaturePoint<V>,
+}
+
+impl<V: IsBlsVariant> Signature<V> {
+    /// Decodes a compressed signature, checking that it belongs to the prime order subgroup.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BlsError> {
+        let point = V::SignatureCurve::decompress(bytes).map_err(|_| BlsError::InvalidSignature)?;
+        if !V::signature_is_in_subgroup(&point) {
+            return Err(BlsError::InvalidSignature);
+        }
+        Ok(Self { point })
+    }
+
+    /// Returns the compressed encoding of the signature.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        V::SignatureCurve::compress(&self.point)
+    }
+
+    pub fn point(&self) -> &SignaturePoint<V> {
+        &self.point
+    }
+
+    /// Adds signatures into a single signature, to be checked with `aggregate_verify`
+    /// or, if all the messages are equal, with `fast_aggregate_verify`.
+    pub fn aggregate(signatures: &[Self]) -> Result<Self, BlsError> {
+        let (first, rest) = signatures.split_first().ok_or(BlsError::EmptyAggregation)?;
+        let point = rest.iter().fold(first.point.clone(), |acc, signature| {
+            acc.operate_with(&signature.point)
+        });
+        Ok(Self { point })
+    }
+}
+
+/// Verifies an aggregate signature of `messages[i]` by the owner of `public_keys[i]`,
+/// checking that `e(pk_1, H(m_1)) * ... * e(pk_n, H(m_n)) = e(G, signature)`.
+/// Returns false if there are no public keys or the lengths differ.
+pub fn aggregate_verify<V: IsBlsVariant>(
+    public_keys: &[PublicKey<V>],
+    messages: &[&[u8]],
+    signature: &Signature<V>,
+) -> bool {
+    if public_keys.is_empty() || public_keys.len() != messages.len() {
+        return false;
+    }
+    let hashes: Vec<_> = messages
+        .iter()
+        .map(|message| V::hash_to_point(message, V::SIGNATURE_DST))
+        .collect();
+    let minus_generator = V::PublicKeyCurve::generator().neg();
+    let pairs: Vec<_> = public_keys
+        .iter()
+        .zip(hashes.iter())
+        .map(|(public_key, hash)| (&public_key.point, hash))
+        .chain([(&minus_generator, &signature.point)])
+        .collect();
+    V::pairing_product_is_one(&pairs)
+}
+
+/// Verifies an aggregate signature of the same `message` by all the owners of
+/// `public_keys`, with a single pairing check against the sum of the public keys.
+/// Every public key must have been checked with `PublicKey::pop_verify`.
+pub fn fast_aggregate_verify<V: IsBlsVariant>(
+    public_keys: &[PublicKey<V>],
+    message: &[u8],
+    signature: &Signature<V>,
+) -> bool {
+    match PublicKey::aggregate(public_keys) {
+        Ok(public_key) => public_key.verify(message, signature),
+        Err(_) => false,
+    }
+}
+
+impl<V: IsBlsVariant> Clone for SecretKey<V> {
+    fn clone(&self) -> Self {
+        Self {
+            scalar: self.scalar.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<V: IsBlsVariant> core::fmt::Debug for SecretKey<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SecretKey").finish_non_exhaustive()
+    }
+}
+
+impl<V: IsBlsVariant> Clone for PublicKey<V> {
+    fn clone(&self) -> Self {
+        Self {
+            point: self.point.clone(),
+        }
+    }
+}
+
+impl<V: IsBlsVariant> core::fmt::Debug for PublicKey<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PublicKey")
+            .field("point", &self.point)
+            .finish()
+    }
+}
+
+impl<V: IsBlsVariant> PartialEq for PublicKey<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl<V: IsBlsVariant> Eq for PublicKey<V> {}
+
+impl<V: IsBlsVariant> Clone for Signature<V> {
+    fn clone(&self) -> Self {
+        Self {
+            point: self.point.clone(),
+        }
+    }
+}
+
+impl<V: IsBlsVariant> core::fmt::Debug for Signature<V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Signature")
+            .field("point", &self.point)
+            .finish()
+    }
+}
+
+impl<V: IsBlsVariant> PartialEq for Signature<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl<V: IsBlsVariant> Eq for Signature<V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use min_pk::MinPk;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // Master key of the first test case of EIP-2333, which derives it with KeyGen.
+    #[test]
+    fn key_gen_matches_eip2333_vector() {
+        let seed = from_hex("c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04");
+        let secret_key = SecretKey::<MinPk>::key_gen(&seed, &[]).unwrap();
+        assert_eq!(
+            secret_key.scalar,
+            FrElement::from_hex_unchecked(
+                "d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070"
+            )
+        );
+    }
+
+    #[test]
+    fn key_gen_rejects_short_key_material() {
+        assert!(matches!(
+            SecretKey::<MinPk>::key_gen(&[0; 31], &[]),
+            Err(BlsError::ShortKeyMaterial)
+        ));
+        let key = SecretKey::<MinPk>::key_gen(&[0; 32], &[]).unwrap();
+        let other = SecretKey::<MinPk>::key_gen(&[0; 32], b"info").unwrap();
+        assert_ne!(key.to_bytes(), other.to_bytes());
+    }
+
+    #[test]
+    fn secret_keys_out_of_range_are_rejected() {
+        assert!(matches!(
+            SecretKey::<MinPk>::from_bytes(&[0; 32]),
+            Err(BlsError::InvalidSecretKey)
+        ));
+        assert!(matches!(
+            SecretKey::<MinPk>::from_bytes(&FrConfig::MODULUS.to_bytes_be()),
+            Err(BlsError::InvalidSecretKey)
+        ));
+        assert!(matches!(
+            SecretKey::<MinPk>::from_bytes(&[1; 31]),
+            Err(BlsError::InvalidSecretKey)
+        ));
+    }
+
+    #[test]
+    fn hkdf_expand_matches_rfc5869_vector() {
+        // Test case 1 of RFC 5869, appendix A.
+        let prk = from_hex("077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5");
+        let info = from_hex("f0f1f2f3f4f5f6f7f8f9");
+        assert_eq!(
+            hkdf_expand(&prk, &info, 42),
+            from_hex("3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865")
+        );
+    }
+}
@@ -0,0 +1,368 @@
+pub mod rfc6979;
+pub mod secp256k1;
+pub mod starknet;
+
+use crate::errors::EcdsaError;
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{point::ShortWeierstrassProjectivePoint, traits::IsShortWeierstrass},
+        traits::IsEllipticCurve,
+    },
+    field::{element::FieldElement, traits::IsPrimeField},
+    traits::ByteConversion,
+    unsigned_integer::element::U256,
+};
+use rfc6979::NonceGenerator;
+use sha2::digest::{core_api::BlockSizeUser, Digest};
+
+/// A curve in short Weierstrass form whose generator has prime order `n`,
+/// on which ECDSA signatures are defined. Both `n` and the modulus of the
+/// base field must fit in 256 bits.
+/// For more info, see section 4.1 of "SEC 1: Elliptic Curve Cryptography"
+/// (https://www.secg.org/sec1-v2.pdf).
+pub trait IsEcdsaCurve:
+    IsShortWeierstrass + IsEllipticCurve<PointRepresentation = ShortWeierstrassProjectivePoint<Self>>
+{
+    /// The field of integers modulo `n`.
+    type ScalarField: IsPrimeField<BaseType = U256, RepresentativeType = U256>;
+
+    /// Returns the integer representative of an element of the base field.
+    fn to_integer(a: &FieldElement<Self::BaseField>) -> U256;
+
+    /// Returns the point with abscissa `x` whose ordinate has the given parity,
+    /// or `None` if there is no such point.
+    fn lift_x(x: &U256, y_is_odd: bool) -> Option<ShortWeierstrassProjectivePoint<Self>>;
+}
+
+type Scalar<E> = FieldElement<<E as IsEcdsaCurve>::ScalarField>;
+
+/// Returns the number of bytes of the encoding of a scalar, `rlen` in RFC 6979.
+fn scalar_length<E: IsEcdsaCurve>() -> usize {
+    E::ScalarField::field_bit_size().div_ceil(8)
+}
+
+/// Returns `n`, the modulus of the scalar field.
+fn scalar_modulus<E: IsEcdsaCurve>() -> U256 {
+    E::ScalarField::modulus_minus_one() + U256::from_u64(1)
+}
+
+/// Returns the integer whose big-endian encoding is the leftmost `qlen` bits of
+/// `bytes`, where `qlen` is the number of bits of `n`.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc6979#section-2.3.2
+fn bits_to_integer<E: IsEcdsaCurve>(bytes: &[u8]) -> U256 {
+    let length = scalar_length::<E>().min(bytes.len());
+    let mut padded = vec![0; 32 - length];
+    padded.extend_from_slice(&bytes[..length]);
+    let integer = U256::from_bytes_be(&padded).unwrap();
+    let bits = 8 * length;
+    if bits > E::ScalarField::field_bit_size() {
+        integer >> (bits - E::ScalarField::field_bit_size())
+    } else {
+        integer
+    }
+}
+
+/// Returns `bits2int(bytes) mod n` as a scalar.
+fn bits_to_scalar<E: IsEcdsaCurve>(bytes: &[u8]) -> Scalar<E> {
+    integer_to_scalar::<E>(bits_to_integer::<E>(bytes))
+}
+
+/// Reduces an integer smaller than `2^qlen` modulo `n`, which is larger than `2^(qlen - 1)`.
+fn integer_to_scalar<E: IsEcdsaCurve>(integer: U256) -> Scalar<E> {
+    let modulus = scalar_modulus::<E>();
+    if integer >= modulus {
+        FieldElement::new(integer - modulus)
+    } else {
+        FieldElement::new(integer)
+    }
+}
+
+/// Returns the big-endian encoding of a scalar in `rlen` bytes.
+fn scalar_to_bytes<E: IsEcdsaCurve>(scalar: &Scalar<E>) -> Vec<u8> {
+    scalar.representative().to_bytes_be()[32 - scalar_length::<E>()..].to_vec()
+}
+
+/// Reads a scalar from its big-endian encoding in `rlen` bytes,
+/// returning `None` if it is not in `[1, n - 1]`.
+fn scalar_from_bytes<E: IsEcdsaCurve>(bytes: &[u8]) -> Option<Scalar<E>> {
+    if bytes.len() != scalar_length::<E>() {
+        return None;
+    }
+    let mut padded = vec![0; 32 - bytes.len()];
+    padded.extend_from_slice(bytes);
+    nonzero_scalar_from_integer::<E>(U256::from_bytes_be(&padded).ok()?)
+}
+
+/// Returns the integer as a scalar if it is in `[1, n - 1]`.
+fn nonzero_scalar_from_integer<E: IsEcdsaCurve>(integer: U256) -> Option<Scalar<E>> {
+    if integer == U256::from_u64(0) || integer >= scalar_modulus::<E>() {
+        None
+    } else {
+        Some(FieldElement::new(integer))
+    }
+}
+
+/// An ECDSA signature `(r, s)`, with `r` and `s` in `[1, n - 1]`.
+pub struct Signature<E: IsEcdsaCurve> {
+    pub r: Scalar<E>,
+    pub s: Scalar<E>,
+}
+
+impl<E: IsEcdsaCurve> Signature<E> {
+    /// Encodes the signature as the concatenation of `r` and `s` in big-endian,
+    /// in the format of IEEE P1363.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [scalar_to_bytes::<E>(&self.r), scalar_to_bytes::<E>(&self.s)].concat()
+    }
+
+    /// Decodes a signature encoded with `to_bytes`, checking that `r` and `s` are in `[1, n - 1]`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EcdsaError> {
+        if bytes.len() != 2 * scalar_length::<E>() {
+            return Err(EcdsaError::InvalidSignature);
+        }
+        let (r, s) = bytes.split_at(scalar_length::<E>());
+        Ok(Self {
+            r: scalar_from_bytes::<E>(r).ok_or(EcdsaError::InvalidSignature)?,
+            s: scalar_from_bytes::<E>(s).ok_or(EcdsaError::InvalidSignature)?,
+        })
+    }
+
+    /// Returns true if `s` is at most `(n - 1) / 2`.
+    pub fn is_low_s(&self) -> bool {
+        self.s.representative() <= scalar_modulus::<E>() >> 1
+    }
+
+    /// Returns the equivalent signature `(r, -s)` if `s` is larger than `(n - 1) / 2`, as
+    /// required by Bitcoin and Ethereum to prevent malleability. Normalizing flips the
+    /// parity bit of the recovery id.
+    pub fn normalize_s(&self) -> Self {
+        if self.is_low_s() {
+            self.clone()
+        } else {
+            Self {
+                r: self.r.clone(),
+                s: -&self.s,
+            }
+        }
+    }
+}
+
+impl<E: IsEcdsaCurve> Clone for Signature<E> {
+    fn clone(&self) -> Self {
+        Self {
+            r: self.r.clone(),
+            s: self.s.clone(),
+        }
+    }
+}
+
+impl<E: IsEcdsaCurve> core::fmt::Debug for Signature<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Signature")
+            .field("r", &self.r)
+            .field("s", &self.s)
+            .finish()
+    }
+}
+
+impl<E: IsEcdsaCurve> PartialEq for Signature<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.r == other.r && self.s == other.s
+    }
+}
+
+impl<E: IsEcdsaCurve> Eq for Signature<E> {}
+
+/// An ECDSA private key, an integer `d` in `[1, n - 1]`.
+pub struct SigningKey<E: IsEcdsaCurve> {
+    secret: Scalar<E>,
+}
+
+impl<E: IsEcdsaCurve> SigningKey<E> {
+    /// Returns the private key `secret`, which must not be zero.
+    pub fn new(secret: Scalar<E>) -> Result<Self, EcdsaError> {
+        if secret == FieldElement::zero() {
+            return Err(EcdsaError::InvalidPrivateKey);
+        }
+        Ok(Self { secret })
+    }
+
+    /// Decodes a private key from its big-endian encoding in `rlen` bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EcdsaError> {
+        let secret = scalar_from_bytes::<E>(bytes).ok_or(EcdsaError::InvalidPrivateKey)?;
+        Ok(Self { secret })
+    }
+
+    /// Returns the big-endian encoding of the private key in `rlen` bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        scalar_to_bytes::<E>(&self.secret)
+    }
+
+    /// Returns the public key `d * G`.
+    pub fn verifying_key(&self) -> VerifyingKey<E> {
+        VerifyingKey {
+            point: E::generator().operate_with_self(self.secret.representative()),
+        }
+    }
+
+    /// Signs the hash of `message` by the hash function `D`, which is also
+    /// used to derive the nonce.
+    pub fn sign<D: Digest + BlockSizeUser>(&self, message: &[u8]) -> Signature<E> {
+        self.sign_prehash::<D>(&D::digest(message))
+    }
+
+    /// Signs a message hash, deriving the nonce with the hash function `D`.
+    pub fn sign_prehash<D: Digest + BlockSizeUser>(&self, message_hash: &[u8]) -> Signature<E> {
+        self.sign_prehash_recoverable::<D>(message_hash).0
+    }
+
+    /// Signs a message hash, deriving the nonce deterministically with the HMAC of the
+    /// hash function `D` as in RFC 6979. Returns the signature and the recovery id,
+    /// which allows to recover the public key from it with `VerifyingKey::recover_from_prehash`.
+    /// For more info, see https://www.rfc-editor.org/rfc/rfc6979
+    pub fn sign_prehash_recoverable<D: Digest + BlockSizeUser>(
+        &self,
+        message_hash: &[u8],
+    ) -> (Signature<E>, u8) {
+        let e = bits_to_scalar::<E>(message_hash);
+        let mut nonces = NonceGenerator::<D>::new(&self.to_bytes(), &scalar_to_bytes::<E>(&e));
+        loop {
+            let candidate = bits_to_integer::<E>(&nonces.next_candidate());
+            if let Some(result) = nonzero_scalar_from_integer::<E>(candidate)
+                .and_then(|k| self.sign_with_nonce(&e, &k))
+            {
+                return result;
+            }
+        }
+    }
+
+    /// Returns the signature with nonce `k` and the recovery id,
+    /// or `None` if `r` or `s` is zero.
+    fn sign_with_nonce(&self, e: &Scalar<E>, k: &Scalar<E>) -> Option<(Signature<E>, u8)> {
+        let point = E::generator()
+            .operate_with_self(k.representative())
+            .to_affine();
+        let x = E::to_integer(point.x());
+        let r = integer_to_scalar::<E>(x);
+        if r == FieldElement::zero() {
+            return None;
+        }
+        let s = k.inv() * (e + &r * &self.secret);
+        if s == FieldElement::zero() {
+            return None;
+        }
+        let y_is_odd = E::to_integer(point.y()).limbs[3] & 1 == 1;
+        let x_is_reduced = x >= scalar_modulus::<E>();
+        let recovery_id = u8::from(y_is_odd) | (u8::from(x_is_reduced) << 1);
+        Some((Signature { r, s }, recovery_id))
+    }
+}
+
+/// An ECDSA public key, a point of the curve different from the neutral element.
+pub struct VerifyingKey<E: IsEcdsaCurve> {
+    point: ShortWeierstrassProjectivePoint<E>,
+}
+
+impl<E: IsEcdsaCurve> VerifyingKey<E> {
+    /// Returns the public key `point`, which must be a point of the curve different
+    /// from the neutral element. The curve is assumed to have prime order, so that
+    /// the point belongs to the subgroup generated by the generator.
+    pub fn new(point: ShortWeierstrassProjectivePoint<E>) -> Result<Self, EcdsaError> {
+        if point.is_neutral_element() {
+            return Err(EcdsaError::InvalidPublicKey);
+        }
+        let affine = point.to_affine();
+        if E::defining_equation(affine.x(), affine.y()) != FieldElement::zero() {
+            return Err(EcdsaError::InvalidPublicKey);
+        }
+        Ok(Self { point })
+    }
+
+    pub fn point(&self) -> &ShortWeierstrassProjectivePoint<E> {
+        &self.point
+    }
+
+    /// Verifies a signature of the hash of `message` by the hash function `D`.
+    pub fn verify<D: Digest>(&self, message: &[u8], signature: &Signature<E>) -> bool {
+        self.verify_prehash(&D::digest(message), signature)
+    }
+
+    /// Verifies a signature of a message hash.
+    pub fn verify_prehash(&self, message_hash: &[u8], signature: &Signature<E>) -> bool {
+        let Signature { r, s } = signature;
+        if *r == FieldElement::zero() || *s == FieldElement::zero() {
+            return false;
+        }
+        let e = bits_to_scalar::<E>(message_hash);
+        let w = s.inv();
+        let u1 = &e * &w;
+        let u2 = r * w;
+        let point = E::generator()
+            .operate_with_self(u1.representative())
+            .operate_with(&self.point.operate_with_self(u2.representative()));
+        if point.is_neutral_element() {
+            return false;
+        }
+        integer_to_scalar::<E>(E::to_integer(point.to_affine().x())) == *r
+    }
+
+    /// Recovers the public key from a signature of a message hash and its recovery id.
+    /// For more info, see section 4.1.6 of "SEC 1: Elliptic Curve Cryptography".
+    pub fn recover_from_prehash(
+        message_hash: &[u8],
+        signature: &Signature<E>,
+        recovery_id: u8,
+    ) -> Result<Self, EcdsaError> {
+        let Signature { r, s } = signature;
+        if recovery_id > 3 {
+            return Err(EcdsaError::InvalidRecoveryId);
+        }
+        if *r == FieldElement::zero() || *s == FieldElement::zero() {
+            return Err(EcdsaError::InvalidSignature);
+        }
+        let mut x = r.representative();
+        if recovery_id & 2 != 0 {
+            let (sum, overflow) = U256::add(&x, &scalar_modulus::<E>());
+            if overflow {
+                return Err(EcdsaError::RecoveryFailed);
+            }
+            x = sum;
+        }
+        let point = E::lift_x(&x, recovery_id & 1 == 1).ok_or(EcdsaError::RecoveryFailed)?;
+
+        // Q = r^-1 (s R - e G)
+        let e = bits_to_scalar::<E>(message_hash);
+        let r_inv = r.inv();
+        let u1 = -(e * &r_inv);
+        let u2 = s * r_inv;
+        let public_key = E::generator()
+            .operate_with_self(u1.representative())
+            .operate_with(&point.operate_with_self(u2.representative()));
+        Self::new(public_key).map_err(|_| EcdsaError::RecoveryFailed)
+    }
+}
+
+impl<E: IsEcdsaCurve> Clone for VerifyingKey<E> {
+    fn clone(&self) -> Self {
+        Self {
+            point: self.point.clone(),
+        }
+    }
+}
+
+impl<E: IsEcdsaCurve> core::fmt::Debug for VerifyingKey<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("VerifyingKey")
+            .field("point", &self.point)
+            .finish()
+    }
+}
+
+impl<E: IsEcdsaCurve> PartialEq for VerifyingKey<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl<E: IsEcdsaCurve> Eq for VerifyingKey<E> {}
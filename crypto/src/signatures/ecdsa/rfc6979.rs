@@ -0,0 +1,154 @@
+use sha2::digest::{core_api::BlockSizeUser, Digest};
+
+const INNER_PAD: u8 = 0x36;
+const OUTER_PAD: u8 = 0x5c;
+
+/// Computes `HMAC_key(data[0] || data[1] || ...)` with the hash function `D`.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc2104
+pub fn hmac<D: Digest + BlockSizeUser>(key: &[u8], data: &[&[u8]]) -> Vec<u8> {
+    let mut padded_key = if key.len() > D::block_size() {
+        D::digest(key).to_vec()
+    } else {
+        key.to_vec()
+    };
+    padded_key.resize(D::block_size(), 0);
+
+    let inner_key: Vec<u8> = padded_key.iter().map(|byte| byte ^ INNER_PAD).collect();
+    let inner = data
+        .iter()
+        .fold(D::new().chain_update(inner_key), |hasher, chunk| {
+            hasher.chain_update(chunk)
+        })
+        .finalize();
+
+    let outer_key: Vec<u8> = padded_key.iter().map(|byte| byte ^ OUTER_PAD).collect();
+    D::new()
+        .chain_update(outer_key)
+        .chain_update(inner)
+        .finalize()
+        .to_vec()
+}
+
+/// Deterministic generation of the candidates for the nonce `k` of a DSA or ECDSA
+/// signature, with the HMAC_DRBG of the hash function `D`.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc6979#section-3.2
+pub struct NonceGenerator<D> {
+    k: Vec<u8>,
+    v: Vec<u8>,
+    /// Number of bytes of each candidate, `rlen` in the RFC.
+    length: usize,
+    started: bool,
+    phantom: std::marker::PhantomData<D>,
+}
+
+impl<D: Digest + BlockSizeUser> NonceGenerator<D> {
+    /// Initializes the generator with the private key `int2octets(x)` and the
+    /// reduced message hash `bits2octets(h1)`, both of `length` bytes.
+    pub fn new(private_key: &[u8], message_hash: &[u8]) -> Self {
+        Self::with_additional_data(private_key, message_hash, &[])
+    }
+
+    /// Initializes the generator as in `new`, also feeding the additional data `k'`
+    /// to the HMAC_DRBG, which is how Starknet retries with a new nonce.
+    /// For more info, see https://www.rfc-editor.org/rfc/rfc6979#section-3.6
+    pub fn with_additional_data(
+        private_key: &[u8],
+        message_hash: &[u8],
+        additional_data: &[u8],
+    ) -> Self {
+        let length = private_key.len();
+        let output_size = <D as Digest>::output_size();
+        let v = vec![0x01; output_size];
+        let k = vec![0x00; output_size];
+        let k = hmac::<D>(
+            &k,
+            &[&v, &[0x00], private_key, message_hash, additional_data],
+        );
+        let v = hmac::<D>(&k, &[&v]);
+        let k = hmac::<D>(
+            &k,
+            &[&v, &[0x01], private_key, message_hash, additional_data],
+        );
+        let v = hmac::<D>(&k, &[&v]);
+        Self {
+            k,
+            v,
+            length,
+            started: false,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the next candidate `T`, of `length` bytes. The caller has to
+    /// apply `bits2int` to it and check that the result is in `[1, q - 1]`.
+    pub fn next_candidate(&mut self) -> Vec<u8> {
+        if self.started {
+            self.k = hmac::<D>(&self.k, &[&self.v, &[0x00]]);
+            self.v = hmac::<D>(&self.k, &[&self.v]);
+        }
+        self.started = true;
+
+        let mut candidate = Vec::with_capacity(self.length);
+        while candidate.len() < self.length {
+            self.v = hmac::<D>(&self.k, &[&self.v]);
+            candidate.extend_from_slice(&self.v);
+        }
+        candidate.truncate(self.length);
+        candidate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Sha256, Sha512};
+
+    fn bytes_from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // Test case 2 of https://www.rfc-editor.org/rfc/rfc4231#section-4.3
+    #[test]
+    fn hmac_sha256_matches_rfc_4231() {
+        let mac = hmac::<Sha256>(b"Jefe", &[b"what do ya want ", b"for nothing?"]);
+        assert_eq!(
+            mac,
+            bytes_from_hex("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+        );
+    }
+
+    // Test case 6 of https://www.rfc-editor.org/rfc/rfc4231#section-4.7, with a key
+    // longer than the block size.
+    #[test]
+    fn hmac_sha512_hashes_long_keys() {
+        let mac = hmac::<Sha512>(
+            &[0xaa; 131],
+            &[b"Test Using Larger Than Block-Size Key - Hash Key First"],
+        );
+        assert_eq!(
+            mac,
+            bytes_from_hex("80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f3526b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598")
+        );
+    }
+
+    // The example of https://www.rfc-editor.org/rfc/rfc6979#appendix-A.1, where `q` has
+    // 163 bits and the first candidate is rejected.
+    #[test]
+    fn nonce_candidates_match_rfc_6979_example() {
+        let private_key = bytes_from_hex("009a4d6792295a7f730fc3f2b49cbc0f62e862272f");
+        let message_hash = bytes_from_hex("01795edf0d54db760f156d0dac04c0322b3a204224");
+        let mut generator = NonceGenerator::<Sha256>::new(&private_key, &message_hash);
+        assert_eq!(
+            generator.next_candidate(),
+            bytes_from_hex("9305a46de7ff8eb107194debd3fd48aa20d5e7656c")
+        );
+        assert_eq!(
+            generator.next_candidate(),
+            bytes_from_hex("c70c78608a3b5be9289be90ef6e81a9e2c1516d5751d2f75f50033e45f73bdeb")
+                [..21]
+        );
+    }
+}
@@ -0,0 +1,389 @@
+use super::IsEcdsaCurve;
+use lambdaworks_math::{
+    elliptic_curve::short_weierstrass::{
+        curves::secp256k1::{
+            curve::{Secp256k1Curve, Secp256k1FieldElement, Secp256k1FieldModulus},
+            default_types::FrField,
+        },
+        point::ShortWeierstrassProjectivePoint,
+    },
+    field::{element::FieldElement, fields::montgomery_backed_prime_fields::IsModulus},
+    unsigned_integer::element::U256,
+};
+
+impl IsEcdsaCurve for Secp256k1Curve {
+    type ScalarField = FrField;
+
+    fn to_integer(a: &Secp256k1FieldElement) -> U256 {
+        a.representative()
+    }
+
+    fn lift_x(x: &U256, y_is_odd: bool) -> Option<ShortWeierstrassProjectivePoint<Self>> {
+        if *x >= Secp256k1FieldModulus::MODULUS {
+            return None;
+        }
+        Secp256k1Curve::lift_x(&FieldElement::new(*x), y_is_odd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::EcdsaError;
+    use crate::signatures::ecdsa::{Signature, SigningKey, VerifyingKey};
+    use lambdaworks_math::{
+        cyclic_group::IsGroup, elliptic_curve::traits::IsEllipticCurve,
+        field::traits::IsPrimeField, traits::ByteConversion,
+    };
+    use sha2::{Digest, Sha256, Sha512};
+
+    type Secp256k1Signature = Signature<Secp256k1Curve>;
+    type Secp256k1SigningKey = SigningKey<Secp256k1Curve>;
+    type Secp256k1VerifyingKey = VerifyingKey<Secp256k1Curve>;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn signing_key(hex: &str) -> Secp256k1SigningKey {
+        Secp256k1SigningKey::from_bytes(&from_hex(hex)).unwrap()
+    }
+
+    fn verifying_key(sec1_hex: &str) -> Secp256k1VerifyingKey {
+        Secp256k1VerifyingKey::new(Secp256k1Curve::from_sec1(&from_hex(sec1_hex)).unwrap()).unwrap()
+    }
+
+    fn signature(r_hex: &str, s_hex: &str) -> Secp256k1Signature {
+        Secp256k1Signature::from_bytes(&from_hex(&[r_hex, s_hex].concat())).unwrap()
+    }
+
+    /// A deterministic signature, with the public key in compressed SEC1 form.
+    struct DeterministicVector {
+        private_key: &'static str,
+        public_key: &'static str,
+        message: &'static [u8],
+        r: &'static str,
+        s: &'static str,
+    }
+
+    const SHA256_VECTORS: [DeterministicVector; 3] = [
+        DeterministicVector {
+            private_key: "0000000000000000000000000000000000000000000000000000000000000001",
+            public_key: "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            message: b"Satoshi Nakamoto",
+            r: "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8",
+            s: "dbbd3162d46e9f9bef7feb87c16dc13b4f6568a87f4e83f728e2443ba586675c",
+        },
+        DeterministicVector {
+            private_key: "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721",
+            public_key: "032c8c31fc9f990c6b55e3865a184a4ce50e09481f2eaeb3e60ec1cea13a6ae645",
+            message: b"sample",
+            r: "432310e32cb80eb6503a26ce83cc165c783b870845fb8aad6d970889fcd7a6c8",
+            s: "530128b6b81c548874a6305d93ed071ca6e05074d85863d4056ce89b02bfab69",
+        },
+        DeterministicVector {
+            private_key: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+            public_key: "0379be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            message: b"Everything should be made as simple as possible, but not simpler.",
+            r: "ba1afd54f9975fe2962bad597193d51c86de515418aa66f6a6d5cd246a519ab1",
+            s: "4125fe0e7d12428a70c706bf091bb40c164eddb0aea6aa61c0f2256009de59e6",
+        },
+    ];
+
+    /// A test case of Wycheproof: a signature encoded as `r || s` of the SHA-256
+    /// hash of `message`, with the expected result of its verification.
+    struct VerificationVector {
+        tc_id: usize,
+        comment: &'static str,
+        message: &'static str,
+        signature: &'static str,
+        valid: bool,
+    }
+
+    /// The public key of the first test group of `ecdsa_secp256k1_sha256_test.json`
+    /// in Wycheproof (https://github.com/C2SP/wycheproof).
+    const WYCHEPROOF_PUBLIC_KEY: &str = "04b838ff44e5bc177bf21189d0766082fc9d843226887fc9760371100b7ee20a6ff0c9d75bfba7b31a6bca1974496eeb56de357071955d83c4b1badaa0b21832e9";
+
+    /// Test cases of that group, with their DER signatures rewritten as `r || s`.
+    const WYCHEPROOF_VECTORS: [VerificationVector; 2] = [
+        VerificationVector {
+            tc_id: 1,
+            comment: "signature malleability",
+            message: "313233343030",
+            signature: "813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365900e75ad233fcc908509dbff5922647db37c21f4afd3203ae8dc4ae7794b0f87",
+            valid: true,
+        },
+        VerificationVector {
+            tc_id: 3,
+            comment: "valid",
+            message: "313233343030",
+            signature: "813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323656ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba",
+            valid: true,
+        },
+    ];
+
+    type Scalar = FieldElement<FrField>;
+
+    /// Returns the public key `Q = r^-1 (s R - e G)`, for which `(r, s)` is a signature
+    /// of a message hash reduced to `e` with `R` as the point of the nonce, as long as
+    /// `r = x(R) mod n`. Wycheproof builds its keys for special signatures in this way.
+    fn key_for_signature(
+        point: &ShortWeierstrassProjectivePoint<Secp256k1Curve>,
+        r: &Scalar,
+        s: &Scalar,
+        e: &Scalar,
+    ) -> Secp256k1VerifyingKey {
+        let q = point
+            .operate_with_self(s.representative())
+            .operate_with(&Secp256k1Curve::generator().operate_with_self((-e).representative()))
+            .operate_with_self(r.inv().representative());
+        Secp256k1VerifyingKey::new(q).unwrap()
+    }
+
+    fn scalar_of(point: &ShortWeierstrassProjectivePoint<Secp256k1Curve>) -> Scalar {
+        let x = point.to_affine().x().representative();
+        let n = FrField::modulus_minus_one() + U256::from_u64(1);
+        Scalar::new(if x >= n { x - n } else { x })
+    }
+
+    #[test]
+    fn sign_matches_rfc6979_vectors_with_sha256() {
+        for vector in SHA256_VECTORS {
+            let key = signing_key(vector.private_key);
+            let expected = signature(vector.r, vector.s);
+            assert_eq!(key.sign::<Sha256>(vector.message), expected);
+            assert_eq!(key.verifying_key(), verifying_key(vector.public_key));
+        }
+    }
+
+    #[test]
+    fn sign_matches_rfc6979_vector_with_sha512() {
+        let key = signing_key("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let expected = signature(
+            "2aaed0e23c13f46adff7820b5c61f2692645aa9fadceb3d05297a2d33790dd5a",
+            "9b24785eeaffef0b188a3d0b65b6322495b0311fcc90fef5331aeb5b10aaa6e4",
+        );
+        assert_eq!(key.sign::<Sha512>(b"test"), expected);
+        assert!(key.verifying_key().verify::<Sha512>(b"test", &expected));
+    }
+
+    #[test]
+    fn signatures_are_verified() {
+        for vector in SHA256_VECTORS {
+            let key = signing_key(vector.private_key).verifying_key();
+            let signature = signature(vector.r, vector.s);
+            assert!(key.verify::<Sha256>(vector.message, &signature));
+            assert!(key.verify::<Sha256>(vector.message, &signature.normalize_s()));
+            assert!(!key.verify::<Sha256>(b"another message", &signature));
+        }
+    }
+
+    #[test]
+    fn wycheproof_vectors() {
+        let key = verifying_key(WYCHEPROOF_PUBLIC_KEY);
+        for vector in WYCHEPROOF_VECTORS {
+            let message = from_hex(vector.message);
+            let result = Secp256k1Signature::from_bytes(&from_hex(vector.signature))
+                .map(|signature| key.verify::<Sha256>(&message, &signature))
+                .unwrap_or(false);
+            assert_eq!(
+                result, vector.valid,
+                "tcId {}: {}",
+                vector.tc_id, vector.comment
+            );
+            assert!(!key.verify::<Sha256>(
+                b"123401",
+                &Secp256k1Signature::from_bytes(&from_hex(vector.signature)).unwrap()
+            ));
+        }
+    }
+
+    #[test]
+    fn r_and_s_out_of_range_are_rejected() {
+        let n = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+        let n_minus_one = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140";
+        let zero = "0000000000000000000000000000000000000000000000000000000000000000";
+        let one = "0000000000000000000000000000000000000000000000000000000000000001";
+        for (r, s) in [(zero, one), (one, zero), (n, one), (one, n)] {
+            assert_eq!(
+                Secp256k1Signature::from_bytes(&from_hex(&[r, s].concat())),
+                Err(EcdsaError::InvalidSignature)
+            );
+        }
+        let key = verifying_key(WYCHEPROOF_PUBLIC_KEY);
+        for (r, s) in [(one, one), (n_minus_one, n_minus_one), (one, n_minus_one)] {
+            assert!(!key.verify::<Sha256>(b"123400", &signature(r, s)));
+        }
+    }
+
+    #[test]
+    fn extreme_values_of_s_are_accepted() {
+        let e = Scalar::new(U256::from_bytes_be(&Sha256::digest(b"123400")).unwrap());
+        let point = Secp256k1Curve::generator().operate_with_self(7_u64);
+        let r = scalar_of(&point);
+        for s in [Scalar::one(), -Scalar::one()] {
+            let key = key_for_signature(&point, &r, &s, &e);
+            let signature = Secp256k1Signature { r: r.clone(), s };
+            assert!(key.verify::<Sha256>(b"123400", &signature));
+            assert!(!key.verify::<Sha256>(b"123401", &signature));
+        }
+    }
+
+    #[test]
+    fn r_is_compared_with_the_abscissa_reduced_modulo_n() {
+        // The smallest abscissa of a point of the curve that is larger than n.
+        let mut x = FrField::modulus_minus_one() + U256::from_u64(2);
+        let point = loop {
+            if let Some(point) = <Secp256k1Curve as IsEcdsaCurve>::lift_x(&x, false) {
+                break point;
+            }
+            x = x + U256::from_u64(1);
+        };
+        let e = Scalar::new(U256::from_bytes_be(&Sha256::digest(b"123400")).unwrap());
+        let r = scalar_of(&point);
+        assert_eq!(
+            r.representative(),
+            x - (FrField::modulus_minus_one() + U256::from_u64(1))
+        );
+        let s = Scalar::from(2);
+        let key = key_for_signature(&point, &r, &s, &e);
+        assert!(key.verify::<Sha256>(b"123400", &Secp256k1Signature { r, s }));
+
+        // The abscissa itself is not a valid value of r.
+        assert_eq!(
+            Secp256k1Signature::from_bytes(
+                &[x.to_bytes_be(), U256::from_u64(2).to_bytes_be()].concat()
+            ),
+            Err(EcdsaError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn u1_equal_to_zero_is_accepted() {
+        // A message hash of 0 or n gives e = 0, so u1 = e / s = 0.
+        let key = signing_key(SHA256_VECTORS[1].private_key);
+        let n = from_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+        let signature = key.sign_prehash::<Sha256>(&[0; 32]);
+        for message_hash in [vec![0; 32], n] {
+            assert!(key
+                .verifying_key()
+                .verify_prehash(&message_hash, &signature));
+        }
+    }
+
+    #[test]
+    fn u1_g_plus_u2_q_equal_to_the_neutral_element_is_rejected() {
+        // With Q = -(e / r) G, u1 G + u2 Q = (e + r * (-e / r)) / s * G is the neutral element.
+        let e = Scalar::new(U256::from_bytes_be(&Sha256::digest(b"123400")).unwrap());
+        let r = scalar_of(&Secp256k1Curve::generator());
+        let s = Scalar::from(3);
+        let key = key_for_signature(
+            &ShortWeierstrassProjectivePoint::neutral_element(),
+            &r,
+            &s,
+            &e,
+        );
+        assert!(!key.verify::<Sha256>(b"123400", &Secp256k1Signature { r, s }));
+    }
+
+    #[test]
+    fn normalize_s_gives_low_s() {
+        let signature = signature(SHA256_VECTORS[0].r, SHA256_VECTORS[0].s);
+        assert!(!signature.is_low_s());
+        let normalized = signature.normalize_s();
+        assert!(normalized.is_low_s());
+        assert_eq!(
+            normalized.to_bytes()[32..],
+            from_hex("2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5")
+        );
+        assert_eq!(normalized.normalize_s(), normalized);
+    }
+
+    #[test]
+    fn public_key_is_recovered_from_the_signature() {
+        for vector in SHA256_VECTORS {
+            let key = signing_key(vector.private_key);
+            let message_hash = Sha256::digest(vector.message);
+            let (signature, recovery_id) = key.sign_prehash_recoverable::<Sha256>(&message_hash);
+            assert_eq!(
+                Secp256k1VerifyingKey::recover_from_prehash(&message_hash, &signature, recovery_id),
+                Ok(key.verifying_key())
+            );
+            // Normalizing s flips the parity of R.
+            assert_eq!(
+                Secp256k1VerifyingKey::recover_from_prehash(
+                    &message_hash,
+                    &signature.normalize_s(),
+                    recovery_id ^ u8::from(!signature.is_low_s())
+                ),
+                Ok(key.verifying_key())
+            );
+            assert_ne!(
+                Secp256k1VerifyingKey::recover_from_prehash(
+                    &message_hash,
+                    &signature,
+                    recovery_id ^ 1
+                ),
+                Ok(key.verifying_key())
+            );
+        }
+    }
+
+    #[test]
+    fn recovery_rejects_invalid_ids() {
+        let key = signing_key(SHA256_VECTORS[1].private_key);
+        let message_hash = Sha256::digest(SHA256_VECTORS[1].message);
+        let (signature, _) = key.sign_prehash_recoverable::<Sha256>(&message_hash);
+        assert_eq!(
+            Secp256k1VerifyingKey::recover_from_prehash(&message_hash, &signature, 4),
+            Err(EcdsaError::InvalidRecoveryId)
+        );
+    }
+
+    #[test]
+    fn signature_bytes_roundtrip() {
+        let signature = signature(SHA256_VECTORS[1].r, SHA256_VECTORS[1].s);
+        let bytes = signature.to_bytes();
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(Secp256k1Signature::from_bytes(&bytes), Ok(signature));
+        assert_eq!(
+            Secp256k1Signature::from_bytes(&bytes[1..]),
+            Err(EcdsaError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn invalid_keys_are_rejected() {
+        assert!(matches!(
+            Secp256k1SigningKey::from_bytes(&[0; 32]),
+            Err(EcdsaError::InvalidPrivateKey)
+        ));
+        assert!(matches!(
+            Secp256k1SigningKey::from_bytes(&from_hex(
+                "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+            )),
+            Err(EcdsaError::InvalidPrivateKey)
+        ));
+        assert!(matches!(
+            Secp256k1VerifyingKey::new(ShortWeierstrassProjectivePoint::neutral_element()),
+            Err(EcdsaError::InvalidPublicKey)
+        ));
+        assert!(matches!(
+            Secp256k1VerifyingKey::new(ShortWeierstrassProjectivePoint::new([
+                FieldElement::one(),
+                FieldElement::one(),
+                FieldElement::one(),
+            ])),
+            Err(EcdsaError::InvalidPublicKey)
+        ));
+    }
+
+    #[test]
+    fn private_key_bytes_roundtrip() {
+        let key = signing_key(SHA256_VECTORS[1].private_key);
+        assert_eq!(key.to_bytes(), from_hex(SHA256_VECTORS[1].private_key));
+    }
+}
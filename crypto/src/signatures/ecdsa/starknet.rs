@@ -0,0 +1,366 @@
+use super::rfc6979::NonceGenerator;
+use crate::errors::EcdsaError;
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{
+            curves::stark_curve::{
+                curve::{StarkCurve, StarkFieldElement},
+                default_types::{FrConfig, FrElement},
+            },
+            point::ShortWeierstrassProjectivePoint,
+            traits::IsShortWeierstrass,
+        },
+        traits::IsEllipticCurve,
+    },
+    field::{element::FieldElement, fields::montgomery_backed_prime_fields::IsModulus},
+    traits::ByteConversion,
+    unsigned_integer::element::U256,
+};
+use sha2::Sha256;
+
+/// `2^251`, the bound on the message hash and on `r`, `s` and `s^-1` in Starknet ECDSA,
+/// `ELEMENT_UPPER_BOUND` in starknet-rs.
+const ELEMENT_UPPER_BOUND: U256 =
+    U256::from_hex_unchecked("800000000000000000000000000000000000000000000000000000000000000");
+
+/// Returns true if the integer is in `[1, 2^251 - 1]`.
+fn is_nonzero_and_bounded(x: &U256) -> bool {
+    *x != U256::from_u64(0) && *x < ELEMENT_UPPER_BOUND
+}
+
+/// Reduces an element of the base field modulo `n`, which is slightly smaller than its modulus.
+fn felt_to_scalar(felt: &StarkFieldElement) -> FrElement {
+    let x = felt.representative();
+    if x >= FrConfig::MODULUS {
+        FieldElement::new(x - FrConfig::MODULUS)
+    } else {
+        FieldElement::new(x)
+    }
+}
+
+/// A Starknet ECDSA signature, with `r` and `s` in `[1, 2^251 - 1]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StarknetSignature {
+    pub r: StarkFieldElement,
+    pub s: StarkFieldElement,
+}
+
+/// A Starknet private key, an integer `d` in `[1, n - 1]`.
+pub struct StarknetSigningKey {
+    secret: FrElement,
+}
+
+impl StarknetSigningKey {
+    /// Returns the private key `secret`, which must not be zero.
+    pub fn new(secret: FrElement) -> Result<Self, EcdsaError> {
+        if secret == FieldElement::zero() {
+            return Err(EcdsaError::InvalidPrivateKey);
+        }
+        Ok(Self { secret })
+    }
+
+    /// Decodes a private key from its big-endian encoding in 32 bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EcdsaError> {
+        if bytes.len() != 32 {
+            return Err(EcdsaError::InvalidPrivateKey);
+        }
+        let secret = U256::from_bytes_be(bytes).map_err(|_| EcdsaError::InvalidPrivateKey)?;
+        if secret >= FrConfig::MODULUS {
+            return Err(EcdsaError::InvalidPrivateKey);
+        }
+        Self::new(FieldElement::new(secret))
+    }
+
+    /// Returns the big-endian encoding of the private key in 32 bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.secret.representative().to_bytes_be()
+    }
+
+    /// Returns the public key `d * G`.
+    pub fn verifying_key(&self) -> StarknetVerifyingKey {
+        StarknetVerifyingKey {
+            point: StarkCurve::generator().operate_with_self(self.secret.representative()),
+        }
+    }
+
+    /// Derives the nonce as cairo-lang and starknet-rs do: the HMAC_DRBG of SHA-256 of
+    /// RFC 6979, fed with the private key, the message hash and, on the retries, the
+    /// minimal big-endian encoding of `seed` as additional data. Each candidate is the
+    /// 256-bit output shifted right by 4 bits, since `n` has 252 bits.
+    pub fn generate_k(&self, message_hash: &StarkFieldElement, seed: Option<u64>) -> FrElement {
+        let seed_bytes = seed.map_or(vec![], |seed| {
+            let bytes = seed.to_be_bytes();
+            let first_nonzero = bytes.iter().position(|byte| *byte != 0).unwrap_or(8);
+            bytes[first_nonzero..].to_vec()
+        });
+        let mut nonces = NonceGenerator::<Sha256>::with_additional_data(
+            &self.to_bytes(),
+            &felt_to_scalar(message_hash).representative().to_bytes_be(),
+            &seed_bytes,
+        );
+        loop {
+            let candidate = U256::from_bytes_be(&nonces.next_candidate()).unwrap() >> 4;
+            if candidate != U256::from_u64(0) && candidate < FrConfig::MODULUS {
+                return FieldElement::new(candidate);
+            }
+        }
+    }
+
+    /// Signs a message hash smaller than `2^251`, retrying with the seeds `1, 2, ...`
+    /// whenever the nonce does not produce a valid signature, as `sign` in cairo-lang.
+    /// For more info, see https://github.com/starkware-libs/cairo-lang/blob/master/src/starkware/crypto/starkware/crypto/signature/signature.py
+    pub fn sign(&self, message_hash: &StarkFieldElement) -> Result<StarknetSignature, EcdsaError> {
+        let mut seed = None;
+        loop {
+            let k = self.generate_k(message_hash, seed);
+            match self.sign_with_nonce(message_hash, &k) {
+                Err(EcdsaError::InvalidNonce) => seed = Some(seed.map_or(1, |seed| seed + 1)),
+                result => return result,
+            }
+        }
+    }
+
+    /// Signs a message hash with the nonce `k`: `r` is the abscissa of `k * G`, not reduced
+    /// modulo `n`, and `s = (z + r * d) / k`. Fails with `InvalidNonce` if `r`, `s` or `s^-1`
+    /// is not in `[1, 2^251 - 1]`.
+    pub fn sign_with_nonce(
+        &self,
+        message_hash: &StarkFieldElement,
+        k: &FrElement,
+    ) -> Result<StarknetSignature, EcdsaError> {
+        if message_hash.representative() >= ELEMENT_UPPER_BOUND {
+            return Err(EcdsaError::InvalidMessageHash);
+        }
+        if *k == FieldElement::zero() {
+            return Err(EcdsaError::InvalidNonce);
+        }
+        let point = StarkCurve::generator()
+            .operate_with_self(k.representative())
+            .to_affine();
+        let r = point.x().clone();
+        if !is_nonzero_and_bounded(&r.representative()) {
+            return Err(EcdsaError::InvalidNonce);
+        }
+        let t = felt_to_scalar(message_hash) + felt_to_scalar(&r) * &self.secret;
+        if t == FieldElement::zero() {
+            return Err(EcdsaError::InvalidNonce);
+        }
+        let s = t * k.inv();
+        if !is_nonzero_and_bounded(&s.representative())
+            || !is_nonzero_and_bounded(&s.inv().representative())
+        {
+            return Err(EcdsaError::InvalidNonce);
+        }
+        Ok(StarknetSignature {
+            r,
+            s: FieldElement::new(s.representative()),
+        })
+    }
+}
+
+/// A Starknet public key. Starknet only keeps the abscissa of the point `d * G`,
+/// so signatures are checked against both points with that abscissa.
+#[derive(Clone, Debug)]
+pub struct StarknetVerifyingKey {
+    point: ShortWeierstrassProjectivePoint<StarkCurve>,
+}
+
+impl StarknetVerifyingKey {
+    /// Returns the public key `point`, which must be a point of the curve different
+    /// from the neutral element.
+    pub fn new(point: ShortWeierstrassProjectivePoint<StarkCurve>) -> Result<Self, EcdsaError> {
+        if point.is_neutral_element() {
+            return Err(EcdsaError::InvalidPublicKey);
+        }
+        let affine = point.to_affine();
+        if StarkCurve::defining_equation(affine.x(), affine.y()) != FieldElement::zero() {
+            return Err(EcdsaError::InvalidPublicKey);
+        }
+        Ok(Self { point })
+    }
+
+    /// Returns the public key with abscissa `x`, the form used by Starknet accounts.
+    pub fn from_x(x: &StarkFieldElement) -> Result<Self, EcdsaError> {
+        let point = StarkCurve::lift_x(x, false).ok_or(EcdsaError::InvalidPublicKey)?;
+        Ok(Self { point })
+    }
+
+    /// Returns the abscissa of the public key.
+    pub fn x(&self) -> StarkFieldElement {
+        self.point.to_affine().x().clone()
+    }
+
+    pub fn point(&self) -> &ShortWeierstrassProjectivePoint<StarkCurve> {
+        &self.point
+    }
+
+    /// Verifies a signature of a message hash, rejecting it if the message hash is not
+    /// smaller than `2^251` or if `r`, `s` or `s^-1` is not in `[1, 2^251 - 1]`, as
+    /// `verify` in starknet-rs.
+    pub fn verify(&self, message_hash: &StarkFieldElement, signature: &StarknetSignature) -> bool {
+        let StarknetSignature { r, s } = signature;
+        if message_hash.representative() >= ELEMENT_UPPER_BOUND
+            || !is_nonzero_and_bounded(&r.representative())
+            || !is_nonzero_and_bounded(&s.representative())
+        {
+            return false;
+        }
+        let w = felt_to_scalar(s).inv();
+        if !is_nonzero_and_bounded(&w.representative()) {
+            return false;
+        }
+        let u1 = felt_to_scalar(message_hash) * &w;
+        let u2 = felt_to_scalar(r) * w;
+        let zw_g = StarkCurve::generator().operate_with_self(u1.representative());
+        let rw_q = self.point.operate_with_self(u2.representative());
+        [zw_g.operate_with(&rw_q), zw_g.operate_with(&rw_q.neg())]
+            .iter()
+            .any(|point| !point.is_neutral_element() && point.to_affine().x() == r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn felt(hex: &str) -> StarkFieldElement {
+        StarkFieldElement::from_hex_unchecked(hex)
+    }
+
+    fn signing_key(hex: &str) -> StarknetSigningKey {
+        StarknetSigningKey::new(FrElement::from_hex_unchecked(hex)).unwrap()
+    }
+
+    /// A signature, with the public key given by its abscissa.
+    struct SignatureVector {
+        private_key: &'static str,
+        public_key: &'static str,
+        message_hash: &'static str,
+        r: &'static str,
+        s: &'static str,
+    }
+
+    // `test_sign` of `starknet-crypto/src/ecdsa.rs` in starknet-rs, whose expected
+    // signature comes from `sign` of cairo-lang with the RFC 6979 nonce.
+    const SIGNATURE_VECTORS: [SignatureVector; 1] = [SignatureVector {
+        private_key: "0139fe4d6f02e666e86a6f58e65060f115cd3c185bd9e98bd829636931458f79",
+        public_key: "02c5dbad71c92a45cc4b40573ae661f8147869a91d57b8d9b8f48c8af7f83159",
+        message_hash: "06fea80189363a786037ed3e7ba546dad0ef7de49fccae0e31eb658b7dd4ea76",
+        r: "061ec782f76a66f6984efc3a1b6d152a124c701c00abdd2bf76641b4135c770f",
+        s: "04e44e759cea02c23568bb4d8a09929bbca8768ab68270d50c18d214166ccd9a",
+    }];
+
+    #[test]
+    fn signatures_match_test_vectors() {
+        for vector in SIGNATURE_VECTORS {
+            let key = signing_key(vector.private_key);
+            assert_eq!(key.verifying_key().x(), felt(vector.public_key));
+            let signature = key.sign(&felt(vector.message_hash)).unwrap();
+            assert_eq!(signature.r, felt(vector.r));
+            assert_eq!(signature.s, felt(vector.s));
+
+            let verifying_key = StarknetVerifyingKey::from_x(&felt(vector.public_key)).unwrap();
+            assert!(verifying_key.verify(&felt(vector.message_hash), &signature));
+        }
+    }
+
+    // `test_verify_valid_message` of `starknet-crypto/src/ecdsa.rs` in starknet-rs, signed with the private key 1 and the nonce 3.
+    #[test]
+    fn verify_accepts_starknet_rs_signature() {
+        let verifying_key = StarknetVerifyingKey::from_x(&felt(
+            "01ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca",
+        ))
+        .unwrap();
+        let message_hash = felt("02");
+        let signature = StarknetSignature {
+            r: felt("0411494b501a98abd8262b0da1351e17899a0c4ef23dd2f96fec5ba847310b20"),
+            s: felt("0405c3191ab3883ef2b763af35bc5f5d15b3b4e99461d70e84c654a351a7c81b"),
+        };
+        assert!(verifying_key.verify(&message_hash, &signature));
+        assert_eq!(
+            signing_key("01")
+                .sign_with_nonce(&message_hash, &FrElement::from(3))
+                .unwrap(),
+            signature
+        );
+    }
+
+    #[test]
+    fn nonces_match_cairo_lang() {
+        let key = signing_key("0139fe4d6f02e666e86a6f58e65060f115cd3c185bd9e98bd829636931458f79");
+        let message_hash = felt("06fea80189363a786037ed3e7ba546dad0ef7de49fccae0e31eb658b7dd4ea76");
+        for (seed, k) in [
+            (
+                None,
+                "0738245b914bff42937b4cb377be9920abd5e8476e8998bfc74cb6cca4b9167f",
+            ),
+            (
+                Some(1),
+                "01b76fc776b9f7e4ed6c5816e569baf8a69a6dd5e39162ba7a35bdcda5a83b01",
+            ),
+            (
+                Some(258),
+                "04c1e7040b43f080991611b7f9287cfece85e5e727642e640ced9160a0d5f898",
+            ),
+        ] {
+            assert_eq!(
+                key.generate_k(&message_hash, seed),
+                FrElement::from_hex_unchecked(k)
+            );
+        }
+    }
+
+    #[test]
+    fn verify_rejects_tampered_signatures() {
+        let vector = &SIGNATURE_VECTORS[0];
+        let verifying_key = StarknetVerifyingKey::from_x(&felt(vector.public_key)).unwrap();
+        let message_hash = felt(vector.message_hash);
+        let signature = StarknetSignature {
+            r: felt(vector.r),
+            s: felt(vector.s),
+        };
+        assert!(!verifying_key.verify(&(&message_hash + StarkFieldElement::one()), &signature));
+        let tampered = StarknetSignature {
+            r: signature.r.clone(),
+            s: &signature.s + StarkFieldElement::one(),
+        };
+        assert!(!verifying_key.verify(&message_hash, &tampered));
+        let other_key = signing_key("02").verifying_key();
+        assert!(!other_key.verify(&message_hash, &signature));
+    }
+
+    #[test]
+    fn message_hashes_must_be_smaller_than_two_to_the_251() {
+        let key = signing_key("01");
+        let message_hash = StarkFieldElement::new(ELEMENT_UPPER_BOUND);
+        assert_eq!(key.sign(&message_hash), Err(EcdsaError::InvalidMessageHash));
+        let signature = key.sign(&felt("02")).unwrap();
+        assert!(!key.verifying_key().verify(&message_hash, &signature));
+    }
+
+    #[test]
+    fn invalid_keys_are_rejected() {
+        assert!(StarknetSigningKey::new(FrElement::zero()).is_err());
+        assert!(StarknetSigningKey::from_bytes(&FrConfig::MODULUS.to_bytes_be()).is_err());
+        assert!(
+            StarknetVerifyingKey::new(ShortWeierstrassProjectivePoint::neutral_element()).is_err()
+        );
+        assert!(
+            StarknetVerifyingKey::new(ShortWeierstrassProjectivePoint::new([
+                StarkFieldElement::one(),
+                StarkFieldElement::one(),
+                StarkFieldElement::one(),
+            ]))
+            .is_err()
+        );
+        // About half of the field elements are not the abscissa of a point.
+        let mut rejected = 0;
+        for x in 1..20_u64 {
+            if StarknetVerifyingKey::from_x(&StarkFieldElement::from(x)).is_err() {
+                rejected += 1;
+            }
+        }
+        assert!(rejected > 0);
+    }
+}
@@ -0,0 +1,399 @@
+use crate::errors::EddsaError;
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        edwards::{
+            curves::ed25519::{
+                compression::ED25519_ENCODING_SIZE,
+                curve::Ed25519Curve,
+                default_types::{FrConfig, FrElement},
+            },
+            extended_point::EdwardsExtendedPoint,
+        },
+        traits::{HasCofactor, IsEllipticCurve},
+    },
+    field::{element::FieldElement, fields::montgomery_backed_prime_fields::IsModulus},
+    msm::pippenger::msm,
+    traits::ByteConversion,
+    unsigned_integer::element::U256,
+};
+use sha2::{Digest, Sha512};
+
+pub const SECRET_KEY_SIZE: usize = 32;
+pub const PUBLIC_KEY_SIZE: usize = ED25519_ENCODING_SIZE;
+pub const SIGNATURE_SIZE: usize = 2 * ED25519_ENCODING_SIZE;
+
+type Ed25519Point = EdwardsExtendedPoint<Ed25519Curve>;
+
+/// Returns the little-endian integer `bytes` modulo `l`. It is computed with Horner's
+/// rule over chunks of 31 bytes, which are smaller than `l`.
+fn scalar_from_bytes_mod_order(bytes: &[u8]) -> FrElement {
+    const CHUNK_SIZE: usize = 31;
+    let shift = FrElement::from(2).pow(8 * CHUNK_SIZE);
+    bytes
+        .chunks(CHUNK_SIZE)
+        .rev()
+        .fold(FrElement::zero(), |acc, chunk| {
+            let mut padded = [0; 32];
+            padded[..chunk.len()].copy_from_slice(chunk);
+            acc * &shift + FrElement::new(U256::from_bytes_le(&padded).unwrap())
+        })
+}
+
+/// Returns `SHA-512(R || A || message) mod l`, the challenge of a signature.
+fn challenge(r: &[u8], public_key: &[u8], message: &[u8]) -> FrElement {
+    let digest = Sha512::new()
+        .chain_update(r)
+        .chain_update(public_key)
+        .chain_update(message)
+        .finalize();
+    scalar_from_bytes_mod_order(&digest)
+}
+
+/// An Ed25519 signature: the encoding of the point `R` and the integer `S < l`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ed25519Signature {
+    r: [u8; ED25519_ENCODING_SIZE],
+    s: FrElement,
+}
+
+impl Ed25519Signature {
+    /// Encodes the signature as `R || S`, with `S` in little-endian.
+    pub fn to_bytes(&self) -> [u8; SIGNATURE_SIZE] {
+        let mut bytes = [0; SIGNATURE_SIZE];
+        bytes[..ED25519_ENCODING_SIZE].copy_from_slice(&self.r);
+        bytes[ED25519_ENCODING_SIZE..].copy_from_slice(&self.s.to_bytes_le());
+        bytes
+    }
+
+    /// Decodes a signature, rejecting it if `S` is not smaller than `l`.
+    /// The encoding of `R` is checked during the verification.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EddsaError> {
+        if bytes.len() != SIGNATURE_SIZE {
+            return Err(EddsaError::InvalidSignature);
+        }
+        let (r, s) = bytes.split_at(ED25519_ENCODING_SIZE);
+        let s = U256::from_bytes_le(s).map_err(|_| EddsaError::InvalidSignature)?;
+        if s >= FrConfig::MODULUS {
+            return Err(EddsaError::InvalidSignature);
+        }
+        Ok(Self {
+            r: r.try_into().unwrap(),
+            s: FieldElement::new(s),
+        })
+    }
+}
+
+/// An Ed25519 public key, the point `A = s * B` together with its encoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ed25519VerifyingKey {
+    point: Ed25519Point,
+    bytes: [u8; PUBLIC_KEY_SIZE],
+}
+
+impl Ed25519VerifyingKey {
+    /// Decodes a public key, which must be the canonical encoding of a point of the curve.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EddsaError> {
+        let point = Ed25519Curve::decompress(bytes).map_err(|_| EddsaError::InvalidPublicKey)?;
+        Ok(Self {
+            point,
+            bytes: bytes.try_into().unwrap(),
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; PUBLIC_KEY_SIZE] {
+        self.bytes
+    }
+
+    pub fn point(&self) -> &Ed25519Point {
+        &self.point
+    }
+
+    /// Verifies a signature of `message` with the cofactored equation
+    /// `8 * S * B = 8 * R + 8 * k * A` of RFC 8032, section 5.1.7, which gives the
+    /// same results as the batch verification.
+    pub fn verify(&self, message: &[u8], signature: &Ed25519Signature) -> bool {
+        let Ok(r) = Ed25519Curve::decompress(&signature.r) else {
+            return false;
+        };
+        let k = challenge(&signature.r, &self.bytes, message);
+        let lhs = Ed25519Curve::generator().operate_with_self(signature.s.representative());
+        let rhs = r.operate_with(&self.point.operate_with_self(k.representative()));
+        lhs.operate_with(&rhs.neg())
+            .clear_cofactor()
+            .is_neutral_element()
+    }
+}
+
+/// An Ed25519 private key: the 32-byte secret key and the values derived from it
+/// in RFC 8032, section 5.1.5.
+#[derive(Clone)]
+pub struct Ed25519SigningKey {
+    secret_key: [u8; SECRET_KEY_SIZE],
+    scalar: FrElement,
+    prefix: [u8; 32],
+    verifying_key: Ed25519VerifyingKey,
+}
+
+/// Only the verifying key is printed, so that the secret key and the values
+/// derived from it do not end up in logs.
+impl core::fmt::Debug for Ed25519SigningKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Ed25519SigningKey")
+            .field("verifying_key", &self.verifying_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Ed25519SigningKey {
+    /// Derives the key from a 32-byte secret key: the first half of its SHA-512 hash,
+    /// clamped, is the scalar `s` and the second half is the prefix for the nonces.
+    pub fn from_bytes(secret_key: &[u8; SECRET_KEY_SIZE]) -> Self {
+        let digest = Sha512::digest(secret_key);
+        let mut scalar_bytes = [0; 32];
+        scalar_bytes.copy_from_slice(&digest[..32]);
+        scalar_bytes[0] &= 0xf8;
+        scalar_bytes[31] &= 0x7f;
+        scalar_bytes[31] |= 0x40;
+        let scalar = scalar_from_bytes_mod_order(&scalar_bytes);
+
+        let point = Ed25519Curve::generator().operate_with_self(scalar.representative());
+        let verifying_key = Ed25519VerifyingKey {
+            bytes: Ed25519Curve::compress(&point),
+            point,
+        };
+        Self {
+            secret_key: *secret_key,
+            scalar,
+            prefix: digest[32..].try_into().unwrap(),
+            verifying_key,
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; SECRET_KEY_SIZE] {
+        self.secret_key
+    }
+
+    pub fn verifying_key(&self) -> &Ed25519VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Signs `message` as in RFC 8032, section 5.1.6. The nonce is
+    /// `SHA-512(prefix || message) mod l`, so signatures are deterministic.
+    pub fn sign(&self, message: &[u8]) -> Ed25519Signature {
+        let digest = Sha512::new()
+            .chain_update(self.prefix)
+            .chain_update(message)
+            .finalize();
+        let nonce = scalar_from_bytes_mod_order(&digest);
+        let r = Ed25519Curve::compress(
+            &Ed25519Curve::generator().operate_with_self(nonce.representative()),
+        );
+        let k = challenge(&r, &self.verifying_key.bytes, message);
+        Ed25519Signature {
+            r,
+            s: nonce + k * &self.scalar,
+        }
+    }
+}
+
+/// Verifies many signatures at once, checking a random linear combination of their
+/// cofactored verification equations with a single multi-scalar multiplication:
+/// `8 * (sum z_i * R_i + sum (z_i * k_i) * A_i - (sum z_i * S_i) * B) = 0`
+/// for random 128-bit `z_i`. It returns true if and only if, with overwhelming
+/// probability, `verify` returns true for every signature.
+///
+/// Panics if the slices have different lengths.
+pub fn verify_batch(
+    messages: &[&[u8]],
+    signatures: &[Ed25519Signature],
+    keys: &[Ed25519VerifyingKey],
+) -> bool {
+    assert_eq!(messages.len(), signatures.len());
+    assert_eq!(messages.len(), keys.len());
+
+    let mut scalars = Vec::with_capacity(2 * messages.len() + 1);
+    let mut points = Vec::with_capacity(2 * messages.len() + 1);
+    let mut s_sum = FrElement::zero();
+    for ((message, signature), key) in messages.iter().zip(signatures).zip(keys) {
+        let Ok(r) = Ed25519Curve::decompress(&signature.r) else {
+            return false;
+        };
+        let z = FrElement::from(rand::random::<u64>())
+            + FrElement::from(rand::random::<u64>()) * FrElement::from(2).pow(64_u32);
+        let k = challenge(&signature.r, &key.bytes, message);
+        s_sum += &z * &signature.s;
+        scalars.push(z.representative());
+        points.push(r);
+        scalars.push((z * k).representative());
+        points.push(key.point.clone());
+    }
+    scalars.push((-s_sum).representative());
+    points.push(Ed25519Curve::generator());
+
+    msm(&scalars, &points)
+        .map(|sum| sum.clear_cofactor().is_neutral_element())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    struct TestVector {
+        secret_key: &'static str,
+        public_key: &'static str,
+        message: &'static str,
+        signature: &'static str,
+    }
+
+    /// Test vectors from RFC 8032, section 7.1.
+    const RFC8032_VECTORS: [TestVector; 4] = [
+        TestVector {
+            secret_key: "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+            public_key: "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            message: "",
+            signature: "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        },
+        TestVector {
+            secret_key: "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+            public_key: "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            message: "72",
+            signature: "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+        },
+        TestVector {
+            secret_key: "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+            public_key: "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            message: "af82",
+            signature: "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+        },
+        TestVector {
+            secret_key: "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+            public_key: "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+            message: "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+            signature: "dc2a4459e7369633a52b1bf277839a00201009a3efbf3ecb69bea2186c26b58909351fc9ac90b3ecfdfbc7c66431e0303dca179c138ac17ad9bef1177331a704",
+        },
+    ];
+
+    fn signing_key(hex: &str) -> Ed25519SigningKey {
+        Ed25519SigningKey::from_bytes(&from_hex(hex).try_into().unwrap())
+    }
+
+    #[test]
+    fn public_keys_match_rfc8032_vectors() {
+        for vector in RFC8032_VECTORS {
+            let key = signing_key(vector.secret_key);
+            assert_eq!(
+                key.verifying_key().to_bytes().to_vec(),
+                from_hex(vector.public_key)
+            );
+        }
+    }
+
+    #[test]
+    fn signatures_match_rfc8032_vectors() {
+        for vector in RFC8032_VECTORS {
+            let key = signing_key(vector.secret_key);
+            let signature = key.sign(&from_hex(vector.message));
+            assert_eq!(signature.to_bytes().to_vec(), from_hex(vector.signature));
+        }
+    }
+
+    #[test]
+    fn rfc8032_signatures_are_verified() {
+        for vector in RFC8032_VECTORS {
+            let key = Ed25519VerifyingKey::from_bytes(&from_hex(vector.public_key)).unwrap();
+            let signature = Ed25519Signature::from_bytes(&from_hex(vector.signature)).unwrap();
+            assert!(key.verify(&from_hex(vector.message), &signature));
+            assert!(!key.verify(b"another message", &signature));
+        }
+    }
+
+    #[test]
+    fn signatures_with_a_modified_point_are_rejected() {
+        let vector = &RFC8032_VECTORS[1];
+        let key = Ed25519VerifyingKey::from_bytes(&from_hex(vector.public_key)).unwrap();
+        let mut bytes = from_hex(vector.signature);
+        bytes[0] ^= 1;
+        let signature = Ed25519Signature::from_bytes(&bytes).unwrap();
+        assert!(!key.verify(&from_hex(vector.message), &signature));
+    }
+
+    #[test]
+    fn signatures_with_s_not_smaller_than_l_are_rejected() {
+        let mut bytes = from_hex(RFC8032_VECTORS[0].signature);
+        // S + l, which is smaller than 2^256.
+        let s = U256::from_bytes_le(&bytes[32..]).unwrap();
+        let (s_plus_l, _) = U256::add(&s, &FrConfig::MODULUS);
+        bytes[32..].copy_from_slice(&s_plus_l.to_bytes_le());
+        assert_eq!(
+            Ed25519Signature::from_bytes(&bytes),
+            Err(EddsaError::InvalidSignature)
+        );
+        assert_eq!(
+            Ed25519Signature::from_bytes(&bytes[1..]),
+            Err(EddsaError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn invalid_public_keys_are_rejected() {
+        let mut bytes = [0; 32];
+        bytes[0] = 2;
+        assert_eq!(
+            Ed25519VerifyingKey::from_bytes(&bytes),
+            Err(EddsaError::InvalidPublicKey)
+        );
+    }
+
+    #[test]
+    fn batch_verification_accepts_valid_signatures() {
+        let messages: Vec<Vec<u8>> = RFC8032_VECTORS
+            .iter()
+            .map(|vector| from_hex(vector.message))
+            .collect();
+        let messages: Vec<&[u8]> = messages.iter().map(Vec::as_slice).collect();
+        let signatures: Vec<_> = RFC8032_VECTORS
+            .iter()
+            .map(|vector| Ed25519Signature::from_bytes(&from_hex(vector.signature)).unwrap())
+            .collect();
+        let keys: Vec<_> = RFC8032_VECTORS
+            .iter()
+            .map(|vector| Ed25519VerifyingKey::from_bytes(&from_hex(vector.public_key)).unwrap())
+            .collect();
+        assert!(verify_batch(&messages, &signatures, &keys));
+        assert!(verify_batch(&[], &[], &[]));
+
+        // Swapping two messages invalidates the batch.
+        let mut swapped = messages.clone();
+        swapped.swap(1, 2);
+        assert!(!verify_batch(&swapped, &signatures, &keys));
+    }
+
+    #[test]
+    fn scalar_reduction_matches_the_field_arithmetic() {
+        let bytes = [0xff; 64];
+        // 2^512 - 1
+        let expected = FrElement::from(2).pow(512_u32) - FrElement::one();
+        assert_eq!(scalar_from_bytes_mod_order(&bytes), expected);
+    }
+
+    #[test]
+    fn debug_output_does_not_contain_the_secret_key() {
+        let key = signing_key(RFC8032_VECTORS[0].secret_key);
+        assert_eq!(
+            format!("{:?}", key),
+            format!(
+                "Ed25519SigningKey {{ verifying_key: {:?}, .. }}",
+                key.verifying_key()
+            )
+        );
+    }
+}
@@ -0,0 +1,430 @@
+use crate::errors::EddsaError;
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        edwards::{
+            curves::ed448::{
+                compression::ED448_ENCODING_SIZE,
+                curve::Ed448Curve,
+                default_types::{FrConfig, FrElement},
+            },
+            extended_point::EdwardsExtendedPoint,
+        },
+        traits::{HasCofactor, IsEllipticCurve},
+    },
+    field::{
+        element::FieldElement,
+        fields::{montgomery_backed_prime_fields::IsModulus, p448_goldilocks_prime_field::U448},
+    },
+    traits::ByteConversion,
+};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+pub const SECRET_KEY_SIZE: usize = 57;
+pub const PUBLIC_KEY_SIZE: usize = ED448_ENCODING_SIZE;
+pub const SIGNATURE_SIZE: usize = 2 * ED448_ENCODING_SIZE;
+
+/// Maximum length of the context string of a signature.
+pub const MAX_CONTEXT_SIZE: usize = 255;
+
+/// Number of bytes of the outputs of SHAKE256 used by Ed448.
+const DIGEST_SIZE: usize = 114;
+
+type Ed448Point = EdwardsExtendedPoint<Ed448Curve>;
+
+/// Returns the first 114 bytes of the output of `SHAKE256(dom4(0, context) || data[0] || ...)`,
+/// where `dom4` is the prefix that separates Ed448 from its variants.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc8032#section-5.2
+fn shake256_with_domain(context: &[u8], data: &[&[u8]]) -> [u8; DIGEST_SIZE] {
+    let mut hasher = Shake256::default();
+    hasher.update(b"SigEd448");
+    hasher.update(&[0, context.len() as u8]);
+    hasher.update(context);
+    for chunk in data {
+        hasher.update(chunk);
+    }
+    let mut digest = [0; DIGEST_SIZE];
+    hasher.finalize_xof().read(&mut digest);
+    digest
+}
+
+/// Returns the little-endian integer `bytes` modulo `l`. It is computed with Horner's
+/// rule over chunks of 55 bytes, which are smaller than `l`.
+fn scalar_from_bytes_mod_order(bytes: &[u8]) -> FrElement {
+    const CHUNK_SIZE: usize = 55;
+    let shift = FrElement::from(2).pow(8 * CHUNK_SIZE);
+    bytes
+        .chunks(CHUNK_SIZE)
+        .rev()
+        .fold(FrElement::zero(), |acc, chunk| {
+            let mut padded = [0; 56];
+            padded[..chunk.len()].copy_from_slice(chunk);
+            acc * &shift + FrElement::new(U448::from_bytes_le(&padded).unwrap())
+        })
+}
+
+/// Returns `SHAKE256(dom4(0, context) || R || A || message, 114) mod l`, the challenge
+/// of a signature.
+fn challenge(context: &[u8], r: &[u8], public_key: &[u8], message: &[u8]) -> FrElement {
+    scalar_from_bytes_mod_order(&shake256_with_domain(context, &[r, public_key, message]))
+}
+
+/// An Ed448 signature: the encoding of the point `R` and the integer `S < l`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ed448Signature {
+    r: [u8; ED448_ENCODING_SIZE],
+    s: FrElement,
+}
+
+impl Ed448Signature {
+    /// Encodes the signature as `R || S`, with `S` in little-endian in 57 bytes.
+    pub fn to_bytes(&self) -> [u8; SIGNATURE_SIZE] {
+        let mut bytes = [0; SIGNATURE_SIZE];
+        bytes[..ED448_ENCODING_SIZE].copy_from_slice(&self.r);
+        bytes[ED448_ENCODING_SIZE..SIGNATURE_SIZE - 1].copy_from_slice(&self.s.to_bytes_le());
+        bytes
+    }
+
+    /// Decodes a signature, rejecting it if `S` is not smaller than `l`.
+    /// The encoding of `R` is checked during the verification.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EddsaError> {
+        if bytes.len() != SIGNATURE_SIZE || bytes[SIGNATURE_SIZE - 1] != 0 {
+            return Err(EddsaError::InvalidSignature);
+        }
+        let (r, s) = bytes.split_at(ED448_ENCODING_SIZE);
+        let s = U448::from_bytes_le(s).map_err(|_| EddsaError::InvalidSignature)?;
+        if s >= FrConfig::MODULUS {
+            return Err(EddsaError::InvalidSignature);
+        }
+        Ok(Self {
+            r: r.try_into().unwrap(),
+            s: FieldElement::new(s),
+        })
+    }
+}
+
+/// An Ed448 public key, the point `A = s * B` together with its encoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ed448VerifyingKey {
+    point: Ed448Point,
+    bytes: [u8; PUBLIC_KEY_SIZE],
+}
+
+impl Ed448VerifyingKey {
+    /// Decodes a public key, which must be the canonical encoding of a point of the curve.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EddsaError> {
+        let point = Ed448Curve::decompress(bytes).map_err(|_| EddsaError::InvalidPublicKey)?;
+        Ok(Self {
+            point,
+            bytes: bytes.try_into().unwrap(),
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; PUBLIC_KEY_SIZE] {
+        self.bytes
+    }
+
+    pub fn point(&self) -> &Ed448Point {
+        &self.point
+    }
+
+    /// Verifies a signature of `message` with an empty context.
+    pub fn verify(&self, message: &[u8], signature: &Ed448Signature) -> bool {
+        self.verify_with_context(message, &[], signature)
+    }
+
+    /// Verifies a signature of `message` under `context` with the cofactored equation
+    /// `4 * S * B = 4 * R + 4 * k * A` of RFC 8032, section 5.2.7.
+    pub fn verify_with_context(
+        &self,
+        message: &[u8],
+        context: &[u8],
+        signature: &Ed448Signature,
+    ) -> bool {
+        if context.len() > MAX_CONTEXT_SIZE {
+            return false;
+        }
+        let Ok(r) = Ed448Curve::decompress(&signature.r) else {
+            return false;
+        };
+        let k = challenge(context, &signature.r, &self.bytes, message);
+        let lhs = Ed448Curve::generator().operate_with_self(signature.s.representative());
+        let rhs = r.operate_with(&self.point.operate_with_self(k.representative()));
+        lhs.operate_with(&rhs.neg())
+            .clear_cofactor()
+            .is_neutral_element()
+    }
+}
+
+/// An Ed448 private key: the 57-byte secret key and the values derived from it
+/// in RFC 8032, section 5.2.5.
+#[derive(Clone)]
+pub struct Ed448SigningKey {
+    secret_key: [u8; SECRET_KEY_SIZE],
+    scalar: FrElement,
+    prefix: [u8; 57],
+    verifying_key: Ed448VerifyingKey,
+}
+
+/// Only the verifying key is printed, so that the secret key and the values
+/// derived from it do not end up in logs.
+impl core::fmt::Debug for Ed448SigningKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Ed448SigningKey")
+            .field("verifying_key", &self.verifying_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Ed448SigningKey {
+    /// Derives the key from a 57-byte secret key: the first half of its 114-byte
+    /// SHAKE256 hash, pruned, is the scalar `s` and the second half is the prefix
+    /// for the nonces.
+    pub fn from_bytes(secret_key: &[u8; SECRET_KEY_SIZE]) -> Self {
+        let mut hasher = Shake256::default();
+        hasher.update(secret_key);
+        let mut digest = [0; DIGEST_SIZE];
+        hasher.finalize_xof().read(&mut digest);
+
+        let mut scalar_bytes = [0; 57];
+        scalar_bytes.copy_from_slice(&digest[..57]);
+        scalar_bytes[0] &= 0xfc;
+        scalar_bytes[55] |= 0x80;
+        scalar_bytes[56] = 0;
+        let scalar = scalar_from_bytes_mod_order(&scalar_bytes);
+
+        let point = Ed448Curve::generator().operate_with_self(scalar.representative());
+        let verifying_key = Ed448VerifyingKey {
+            bytes: Ed448Curve::compress(&point),
+            point,
+        };
+        Self {
+            secret_key: *secret_key,
+            scalar,
+            prefix: digest[57..].try_into().unwrap(),
+            verifying_key,
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; SECRET_KEY_SIZE] {
+        self.secret_key
+    }
+
+    pub fn verifying_key(&self) -> &Ed448VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Signs `message` with an empty context.
+    pub fn sign(&self, message: &[u8]) -> Ed448Signature {
+        self.sign_with_context(message, &[])
+            .expect("the empty context is valid")
+    }
+
+    /// Signs `message` under `context`, of at most 255 bytes, as in RFC 8032,
+    /// section 5.2.6. The nonce is `SHAKE256(dom4(0, context) || prefix || message, 114) mod l`,
+    /// so signatures are deterministic.
+    pub fn sign_with_context(
+        &self,
+        message: &[u8],
+        context: &[u8],
+    ) -> Result<Ed448Signature, EddsaError> {
+        if context.len() > MAX_CONTEXT_SIZE {
+            return Err(EddsaError::InvalidContext);
+        }
+        let nonce =
+            scalar_from_bytes_mod_order(&shake256_with_domain(context, &[&self.prefix, message]));
+        let r = Ed448Curve::compress(
+            &Ed448Curve::generator().operate_with_self(nonce.representative()),
+        );
+        let k = challenge(context, &r, &self.verifying_key.bytes, message);
+        Ok(Ed448Signature {
+            r,
+            s: nonce + k * &self.scalar,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    struct TestVector {
+        secret_key: &'static str,
+        public_key: &'static str,
+        message: &'static str,
+        context: &'static str,
+        signature: &'static str,
+    }
+
+    /// Test vectors from RFC 8032, section 7.4.
+    const RFC8032_VECTORS: [TestVector; 5] = [
+        TestVector {
+            secret_key: "6c82a562cb808d10d632be89c8513ebf6c929f34ddfa8c9f63c9960ef6e348a3528c8a3fcc2f044e39a3fc5b94492f8f032e7549a20098f95b",
+            public_key: "5fd7449b59b461fd2ce787ec616ad46a1da1342485a70e1f8a0ea75d80e96778edf124769b46c7061bd6783df1e50f6cd1fa1abeafe8256180",
+            message: "",
+            context: "",
+            signature: "533a37f6bbe457251f023c0d88f976ae2dfb504a843e34d2074fd823d41a591f2b233f034f628281f2fd7a22ddd47d7828c59bd0a21bfd3980ff0d2028d4b18a9df63e006c5d1c2d345b925d8dc00b4104852db99ac5c7cdda8530a113a0f4dbb61149f05a7363268c71d95808ff2e652600",
+        },
+        TestVector {
+            secret_key: "c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e",
+            public_key: "43ba28f430cdff456ae531545f7ecd0ac834a55d9358c0372bfa0c6c6798c0866aea01eb00742802b8438ea4cb82169c235160627b4c3a9480",
+            message: "03",
+            context: "",
+            signature: "26b8f91727bd62897af15e41eb43c377efb9c610d48f2335cb0bd0087810f4352541b143c4b981b7e18f62de8ccdf633fc1bf037ab7cd779805e0dbcc0aae1cbcee1afb2e027df36bc04dcecbf154336c19f0af7e0a6472905e799f1953d2a0ff3348ab21aa4adafd1d234441cf807c03a00",
+        },
+        TestVector {
+            secret_key: "c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e",
+            public_key: "43ba28f430cdff456ae531545f7ecd0ac834a55d9358c0372bfa0c6c6798c0866aea01eb00742802b8438ea4cb82169c235160627b4c3a9480",
+            message: "03",
+            context: "666f6f",
+            signature: "d4f8f6131770dd46f40867d6fd5d5055de43541f8c5e35abbcd001b32a89f7d2151f7647f11d8ca2ae279fb842d607217fce6e042f6815ea000c85741de5c8da1144a6a1aba7f96de42505d7a7298524fda538fccbbb754f578c1cad10d54d0d5428407e85dcbc98a49155c13764e66c3c00",
+        },
+        TestVector {
+            secret_key: "cd23d24f714274e744343237b93290f511f6425f98e64459ff203e8985083ffdf60500553abc0e05cd02184bdb89c4ccd67e187951267eb328",
+            public_key: "dcea9e78f35a1bf3499a831b10b86c90aac01cd84b67a0109b55a36e9328b1e365fce161d71ce7131a543ea4cb5f7e9f1d8b00696447001400",
+            message: "0c3e544074ec63b0265e0c",
+            context: "",
+            signature: "1f0a8888ce25e8d458a21130879b840a9089d999aaba039eaf3e3afa090a09d389dba82c4ff2ae8ac5cdfb7c55e94d5d961a29fe0109941e00b8dbdeea6d3b051068df7254c0cdc129cbe62db2dc957dbb47b51fd3f213fb8698f064774250a5028961c9bf8ffd973fe5d5c206492b140e00",
+        },
+        TestVector {
+            secret_key: "258cdd4ada32ed9c9ff54e63756ae582fb8fab2ac721f2c8e676a72768513d939f63dddb55609133f29adf86ec9929dccb52c1c5fd2ff7e21b",
+            public_key: "3ba16da0c6f2cc1f30187740756f5e798d6bc5fc015d7c63cc9510ee3fd44adc24d8e968b6e46e6f94d19b945361726bd75e149ef09817f580",
+            message: "64a65f3cdedcdd66811e2915",
+            context: "",
+            signature: "7eeeab7c4e50fb799b418ee5e3197ff6bf15d43a14c34389b59dd1a7b1b85b4ae90438aca634bea45e3a2695f1270f07fdcdf7c62b8efeaf00b45c2c96ba457eb1a8bf075a3db28e5c24f6b923ed4ad747c3c9e03c7079efb87cb110d3a99861e72003cbae6d6b8b827e4e6c143064ff3c00",
+        },
+    ];
+
+    fn signing_key(hex: &str) -> Ed448SigningKey {
+        Ed448SigningKey::from_bytes(&from_hex(hex).try_into().unwrap())
+    }
+
+    #[test]
+    fn public_keys_match_rfc8032_vectors() {
+        for vector in RFC8032_VECTORS {
+            let key = signing_key(vector.secret_key);
+            assert_eq!(
+                key.verifying_key().to_bytes().to_vec(),
+                from_hex(vector.public_key)
+            );
+        }
+    }
+
+    #[test]
+    fn signatures_match_rfc8032_vectors() {
+        for vector in RFC8032_VECTORS {
+            let key = signing_key(vector.secret_key);
+            let signature = key
+                .sign_with_context(&from_hex(vector.message), &from_hex(vector.context))
+                .unwrap();
+            assert_eq!(signature.to_bytes().to_vec(), from_hex(vector.signature));
+        }
+    }
+
+    #[test]
+    fn rfc8032_signatures_are_verified() {
+        for vector in RFC8032_VECTORS {
+            let key = Ed448VerifyingKey::from_bytes(&from_hex(vector.public_key)).unwrap();
+            let signature = Ed448Signature::from_bytes(&from_hex(vector.signature)).unwrap();
+            let message = from_hex(vector.message);
+            let context = from_hex(vector.context);
+            assert!(key.verify_with_context(&message, &context, &signature));
+            assert!(!key.verify_with_context(b"another message", &context, &signature));
+            assert!(!key.verify_with_context(&message, b"another context", &signature));
+        }
+    }
+
+    #[test]
+    fn signatures_with_a_modified_point_are_rejected() {
+        let vector = &RFC8032_VECTORS[1];
+        let key = Ed448VerifyingKey::from_bytes(&from_hex(vector.public_key)).unwrap();
+        let mut bytes = from_hex(vector.signature);
+        bytes[0] ^= 1;
+        let signature = Ed448Signature::from_bytes(&bytes).unwrap();
+        assert!(!key.verify(&from_hex(vector.message), &signature));
+    }
+
+    #[test]
+    fn signatures_with_s_not_smaller_than_l_are_rejected() {
+        let mut bytes = from_hex(RFC8032_VECTORS[0].signature);
+        // S + l, which is smaller than 2^448.
+        let s = U448::from_bytes_le(&bytes[57..113]).unwrap();
+        let (s_plus_l, _) = U448::add(&s, &FrConfig::MODULUS);
+        bytes[57..113].copy_from_slice(&s_plus_l.to_bytes_le());
+        assert_eq!(
+            Ed448Signature::from_bytes(&bytes),
+            Err(EddsaError::InvalidSignature)
+        );
+        let mut bytes = from_hex(RFC8032_VECTORS[0].signature);
+        bytes[113] = 1;
+        assert_eq!(
+            Ed448Signature::from_bytes(&bytes),
+            Err(EddsaError::InvalidSignature)
+        );
+        assert_eq!(
+            Ed448Signature::from_bytes(&bytes[1..]),
+            Err(EddsaError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn long_contexts_are_rejected() {
+        let key = signing_key(RFC8032_VECTORS[0].secret_key);
+        let context = [0; MAX_CONTEXT_SIZE + 1];
+        assert_eq!(
+            key.sign_with_context(b"message", &context),
+            Err(EddsaError::InvalidContext)
+        );
+        let signature = key
+            .sign_with_context(b"message", &context[..MAX_CONTEXT_SIZE])
+            .unwrap();
+        assert!(key.verifying_key().verify_with_context(
+            b"message",
+            &context[..MAX_CONTEXT_SIZE],
+            &signature
+        ));
+        assert!(!key
+            .verifying_key()
+            .verify_with_context(b"message", &context, &signature));
+    }
+
+    #[test]
+    fn invalid_public_keys_are_rejected() {
+        let mut bytes = [0; 57];
+        bytes[0] = 2;
+        assert_eq!(
+            Ed448VerifyingKey::from_bytes(&bytes),
+            Err(EddsaError::InvalidPublicKey)
+        );
+    }
+
+    #[test]
+    fn scalar_reduction_matches_the_field_arithmetic() {
+        let bytes = [0xff; 114];
+        // 2^912 - 1
+        let expected = FrElement::from(2).pow(912_u32) - FrElement::one();
+        assert_eq!(scalar_from_bytes_mod_order(&bytes), expected);
+    }
+
+    #[test]
+    fn debug_output_does_not_contain_the_secret_key() {
+        let key = signing_key(RFC8032_VECTORS[0].secret_key);
+        assert_eq!(
+            format!("{:?}", key),
+            format!(
+                "Ed448SigningKey {{ verifying_key: {:?}, .. }}",
+                key.verifying_key()
+            )
+        );
+    }
+}
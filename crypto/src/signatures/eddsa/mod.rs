@@ -0,0 +1,2 @@
+pub mod ed25519;
+pub mod ed448;
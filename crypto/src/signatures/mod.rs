@@ -0,0 +1,3 @@
+pub mod bls;
+pub mod ecdsa;
+pub mod eddsa;
@@ -2,14 +2,21 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use lambdaworks_math::{
     cyclic_group::IsGroup,
     elliptic_curve::{
+        fixed_base::FixedBaseTable,
         short_weierstrass::{
             curves::bls12_381::{
-                curve::BLS12381Curve, pairing::BLS12381AtePairing, twist::BLS12381TwistCurve,
+                curve::BLS12381Curve,
+                pairing::{
+                    final_exponentiation, multi_miller_loop, BLS12381AtePairing, G2Prepared,
+                },
+                twist::BLS12381TwistCurve,
             },
             point::ShortWeierstrassProjectivePoint,
         },
-        traits::{IsEllipticCurve, IsPairing},
+        traits::{HasEndomorphism, IsEllipticCurve, IsPairing},
+        wnaf::wnaf_mul,
     },
+    unsigned_integer::element::U256,
 };
 use rand::{rngs::StdRng, Rng, SeedableRng};
 
@@ -28,12 +35,104 @@ fn generate_points() -> (G1, G2) {
     (g1.operate_with_self(a), g2.operate_with_self(b))
 }
 
+fn generate_scalar() -> U256 {
+    let mut rng = StdRng::seed_from_u64(7);
+    // Clearing the two most significant bits keeps the scalar below the subgroup order.
+    let mut limbs: [u64; 4] = rng.gen();
+    limbs[0] >>= 2;
+    U256::from_limbs(limbs)
+}
+
 pub fn bls12381_elliptic_curve_benchmarks(c: &mut Criterion) {
     let (p, q) = generate_points();
     c.bench_function("BLS12381 Ate pairing", |b| {
         b.iter(|| BLS12381AtePairing::compute(black_box(&p), black_box(&q)))
     });
+    c.bench_function("BLS12381 G2 preparation", |b| {
+        b.iter(|| G2Prepared::new(black_box(&q)))
+    });
+    let prepared = G2Prepared::new(&q);
+    c.bench_function("BLS12381 Miller loop", |b| {
+        b.iter(|| multi_miller_loop(black_box(&[(&p, &prepared)])))
+    });
+    let f = multi_miller_loop(&[(&p, &prepared)]);
+    c.bench_function("BLS12381 final exponentiation", |b| {
+        b.iter(|| final_exponentiation(black_box(&f)))
+    });
+    let f = final_exponentiation(&f);
+    c.bench_function("BLS12381 cyclotomic squaring", |b| {
+        b.iter(|| black_box(&f).cyclotomic_square())
+    });
+    c.bench_function("BLS12381 Fp12 squaring", |b| {
+        b.iter(|| black_box(&f).square())
+    });
+    c.bench_function("BLS12381 cyclotomic exponentiation by x", |b| {
+        b.iter(|| black_box(&f).cyclotomic_pow_x())
+    });
+}
+
+pub fn bls12381_batch_pairing_benchmarks(c: &mut Criterion) {
+    let (p, q) = generate_points();
+    let ps: Vec<_> = (1..=8_u64).map(|i| p.operate_with_self(i)).collect();
+    let qs: Vec<_> = (1..=8_u64).map(|i| q.operate_with_self(i)).collect();
+    let prepared: Vec<_> = qs.iter().map(BLS12381AtePairing::prepare).collect();
+    let pairs: Vec<_> = ps.iter().zip(qs.iter()).collect();
+    let prepared_pairs: Vec<_> = ps.iter().zip(prepared.iter()).collect();
+
+    c.bench_function("BLS12381 8 Ate pairings one by one", |b| {
+        b.iter(|| {
+            pairs
+                .iter()
+                .map(|(p, q)| BLS12381AtePairing::compute(p, q))
+                .reduce(|a, b| a * b)
+        })
+    });
+    c.bench_function("BLS12381 8 Ate pairings batched", |b| {
+        b.iter(|| BLS12381AtePairing::compute_batch(black_box(&pairs)))
+    });
+    c.bench_function(
+        "BLS12381 8 Ate pairings batched with prepared G2 points",
+        |b| b.iter(|| BLS12381AtePairing::compute_batch_prepared(black_box(&prepared_pairs))),
+    );
+}
+
+pub fn bls12381_scalar_multiplication_benchmarks(c: &mut Criterion) {
+    let (p, q) = generate_points();
+    let k = generate_scalar();
+    c.bench_function("BLS12381 G1 double and add", |b| {
+        b.iter(|| black_box(&p).operate_with_self(black_box(k)))
+    });
+    c.bench_function("BLS12381 G1 GLV", |b| {
+        b.iter(|| black_box(&p).glv_mul(black_box(&k)))
+    });
+    c.bench_function("BLS12381 G2 double and add", |b| {
+        b.iter(|| black_box(&q).operate_with_self(black_box(k)))
+    });
+    c.bench_function("BLS12381 G2 GLS", |b| {
+        b.iter(|| black_box(&q).glv_mul(black_box(&k)))
+    });
+}
+
+pub fn bls12381_generator_multiplication_benchmarks(c: &mut Criterion) {
+    let g = BLS12381Curve::generator();
+    let k = generate_scalar();
+    c.bench_function("BLS12381 G1 generator double and add", |b| {
+        b.iter(|| black_box(&g).operate_with_self(black_box(k)))
+    });
+    c.bench_function("BLS12381 G1 generator wNAF", |b| {
+        b.iter(|| wnaf_mul(black_box(&g), black_box(&k), 5))
+    });
+    let table = FixedBaseTable::new(&g, 255, 8);
+    c.bench_function("BLS12381 G1 generator fixed base table", |b| {
+        b.iter(|| table.mul(black_box(&k)))
+    });
 }
 
-criterion_group!(bls12381, bls12381_elliptic_curve_benchmarks);
+criterion_group!(
+    bls12381,
+    bls12381_elliptic_curve_benchmarks,
+    bls12381_batch_pairing_benchmarks,
+    bls12381_scalar_multiplication_benchmarks,
+    bls12381_generator_multiplication_benchmarks
+);
 criterion_main!(bls12381);
@@ -5,7 +5,7 @@ use lambdaworks_math::{
         short_weierstrass::curves::bls12_381::curve::BLS12381Curve, traits::IsEllipticCurve,
     },
     field::traits::IsField,
-    msm::{naive, pippenger},
+    msm::{batch_affine, naive, pippenger},
     unsigned_integer::element::UnsignedInteger,
 };
 use rand::{rngs::StdRng, Rng, SeedableRng};
@@ -75,5 +75,66 @@ pub fn run_benchmarks(c: &mut Criterion) {
     }
 }
 
-criterion_group!(msm, run_benchmarks);
+/// Compares unsigned, signed-digit and batch affine Pippenger with the window size used by `msm`.
+pub fn run_signed_digit_benchmarks(c: &mut Criterion) {
+    let exponents = 10..=18;
+
+    for exp in exponents {
+        let msm_size = 1 << exp;
+        let (cs, points) = generate_cs_and_points(msm_size);
+        let window_size = pippenger::optimum_window_size(msm_size);
+
+        let mut group =
+            c.benchmark_group(format!("Signed digit MSM benchmarks with size {msm_size}"));
+        group.sample_size(10);
+
+        group.bench_function(
+            BenchmarkId::new("Sequential Pippenger", window_size),
+            |bench| {
+                bench.iter(|| black_box(pippenger::msm_with(&cs, &points, window_size)));
+            },
+        );
+
+        group.bench_function(
+            BenchmarkId::new("Sequential signed Pippenger", window_size),
+            |bench| {
+                bench.iter(|| black_box(pippenger::signed_msm_with(&cs, &points, window_size)));
+            },
+        );
+
+        let (affine_cs, bases): (Vec<_>, Vec<_>) = cs
+            .iter()
+            .zip(batch_affine::batch_to_affine(&points))
+            .filter_map(|(k, p)| p.map(|p| (*k, p)))
+            .unzip();
+        group.bench_function(
+            BenchmarkId::new("Sequential batch affine Pippenger", window_size),
+            |bench| {
+                bench.iter(|| black_box(batch_affine::msm_with(&affine_cs, &bases, window_size)));
+            },
+        );
+
+        group.bench_function(
+            BenchmarkId::new("Parallel Pippenger", window_size),
+            |bench| {
+                bench.iter(|| black_box(pippenger::parallel_msm_with(&cs, &points, window_size)));
+            },
+        );
+
+        group.bench_function(
+            BenchmarkId::new("Parallel signed Pippenger", window_size),
+            |bench| {
+                bench.iter(|| {
+                    black_box(pippenger::parallel_signed_msm_with(
+                        &cs,
+                        &points,
+                        window_size,
+                    ))
+                });
+            },
+        );
+    }
+}
+
+criterion_group!(msm, run_benchmarks, run_signed_digit_benchmarks);
 criterion_main!(msm);
@@ -0,0 +1,290 @@
+//! Birational equivalences between the twisted Edwards, Montgomery and short
+//! Weierstrass models of an elliptic curve. They commute with the group operations,
+//! so a point can be moved to the model with the fastest formulas for an operation,
+//! or to the short Weierstrass model to reuse algorithms written only for it.
+//! For more info, see "Montgomery curves and their arithmetic" by Costello and Smith
+//! (https://eprint.iacr.org/2017/212), and "Twisted Edwards Curves" by Bernstein,
+//! Birkner, Joye, Lange and Peters (https://eprint.iacr.org/2008/013).
+
+use crate::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        edwards::traits::IsEdwards,
+        montgomery::traits::IsMontgomery,
+        short_weierstrass::traits::IsShortWeierstrass,
+        traits::{EllipticCurveError, IsEllipticCurve, ToAffine},
+    },
+    field::element::FieldElement,
+};
+
+/// Trait for twisted Edwards curves `a * x^2 + y^2 = 1 + d * x^2 * y^2` that are
+/// birationally equivalent to the Montgomery curve `Self::Montgomery`, through
+/// `(u, v) = ((1 + y) / (1 - y), s * (1 + y) / ((1 - y) * x))`.
+/// The Montgomery curve must have `A = 2 * (a + d) / (a - d)` and `B` such that
+/// `B * s^2 = 4 / (a - d)`, where `s` is `montgomery_scaling_factor`.
+pub trait HasMontgomeryModel: IsEdwards {
+    type Montgomery: IsMontgomery<BaseField = Self::BaseField>;
+
+    /// Returns the factor `s` by which `v` is scaled, which is one when the
+    /// Montgomery curve has `B = 4 / (a - d)`.
+    fn montgomery_scaling_factor() -> FieldElement<Self::BaseField> {
+        FieldElement::one()
+    }
+
+    /// Maps a point to the Montgomery model. The neutral element `(0, 1)` is mapped
+    /// to the point at infinity and `(0, -1)` to `(0, 0)`.
+    fn to_montgomery(
+        point: &Self::PointRepresentation,
+    ) -> <Self::Montgomery as IsEllipticCurve>::PointRepresentation
+    where
+        Self::PointRepresentation: ToAffine<Self::BaseField>,
+    {
+        let [x, y] = point
+            .to_affine_coordinates()
+            .expect("Edwards curves have no points at infinity");
+        let one = FieldElement::<Self::BaseField>::one();
+        if x == FieldElement::zero() {
+            if y == one {
+                return IsGroup::neutral_element();
+            }
+            return Self::Montgomery::create_point_from_affine(
+                FieldElement::zero(),
+                FieldElement::zero(),
+            )
+            .expect("(0, 0) belongs to every Montgomery curve");
+        }
+        let u = (&one + &y) / (one - y);
+        let v = Self::montgomery_scaling_factor() * &u / x;
+        Self::Montgomery::create_point_from_affine(u, v)
+            .expect("the Montgomery curve has the parameters of the map")
+    }
+
+    /// Maps a point of the Montgomery model back to this curve, through
+    /// `(x, y) = (s * u / v, (u - 1) / (u + 1))`. Returns an error for the points with
+    /// `v = 0` or `u = -1` other than `(0, 0)`, whose images are points at infinity
+    /// that the affine model cannot represent. They only exist when `d` is a square.
+    fn from_montgomery(
+        point: &<Self::Montgomery as IsEllipticCurve>::PointRepresentation,
+    ) -> Result<Self::PointRepresentation, EllipticCurveError>
+    where
+        <Self::Montgomery as IsEllipticCurve>::PointRepresentation: ToAffine<Self::BaseField>,
+    {
+        let Some([u, v]) = point.to_affine_coordinates() else {
+            return Ok(IsGroup::neutral_element());
+        };
+        let one = FieldElement::<Self::BaseField>::one();
+        if u == FieldElement::zero() && v == FieldElement::zero() {
+            return Self::create_point_from_affine(FieldElement::zero(), -one);
+        }
+        if v == FieldElement::zero() || &u + &one == FieldElement::zero() {
+            return Err(EllipticCurveError::InvalidPoint);
+        }
+        let x = Self::montgomery_scaling_factor() * &u / v;
+        let y = (&u - &one) / (u + one);
+        Self::create_point_from_affine(x, y)
+    }
+}
+
+/// Trait for Montgomery curves `B * v^2 = u^3 + A * u^2 + u` that are isomorphic to
+/// the short Weierstrass curve `Self::Weierstrass`, through
+/// `(x, y) = (u / B + A / (3 * B), v / B)`.
+/// The short Weierstrass curve must have `a = (3 - A^2) / (3 * B^2)` and
+/// `b = (2 * A^3 - 9 * A) / (27 * B^3)`.
+pub trait HasWeierstrassModel: IsMontgomery {
+    type Weierstrass: IsShortWeierstrass<BaseField = Self::BaseField>;
+
+    /// Maps a point to the short Weierstrass model.
+    fn to_weierstrass(
+        point: &Self::PointRepresentation,
+    ) -> <Self::Weierstrass as IsEllipticCurve>::PointRepresentation
+    where
+        Self::PointRepresentation: ToAffine<Self::BaseField>,
+    {
+        let Some([u, v]) = point.to_affine_coordinates() else {
+            return IsGroup::neutral_element();
+        };
+        let b_inv = Self::b().inv();
+        let x = (u + Self::a() / FieldElement::from(3)) * &b_inv;
+        let y = v * b_inv;
+        Self::Weierstrass::create_point_from_affine(x, y)
+            .expect("the short Weierstrass curve has the parameters of the map")
+    }
+
+    /// Maps a point of the short Weierstrass model back to this curve, through
+    /// `(u, v) = (B * x - A / 3, B * y)`.
+    fn from_weierstrass(
+        point: &<Self::Weierstrass as IsEllipticCurve>::PointRepresentation,
+    ) -> Self::PointRepresentation
+    where
+        <Self::Weierstrass as IsEllipticCurve>::PointRepresentation: ToAffine<Self::BaseField>,
+    {
+        let Some([x, y]) = point.to_affine_coordinates() else {
+            return IsGroup::neutral_element();
+        };
+        let b = Self::b();
+        let u = &b * x - Self::a() / FieldElement::from(3);
+        let v = b * y;
+        Self::create_point_from_affine(u, v)
+            .expect("the short Weierstrass curve has the parameters of the map")
+    }
+}
+
+/// Maps a point of a twisted Edwards curve to the short Weierstrass model of its
+/// Montgomery model.
+pub fn edwards_to_weierstrass<E>(
+    point: &E::PointRepresentation,
+) -> <<E::Montgomery as HasWeierstrassModel>::Weierstrass as IsEllipticCurve>::PointRepresentation
+where
+    E: HasMontgomeryModel,
+    E::Montgomery: HasWeierstrassModel,
+    E::PointRepresentation: ToAffine<E::BaseField>,
+    <E::Montgomery as IsEllipticCurve>::PointRepresentation: ToAffine<E::BaseField>,
+{
+    E::Montgomery::to_weierstrass(&E::to_montgomery(point))
+}
+
+/// Maps a point of the short Weierstrass model of the Montgomery model of a twisted
+/// Edwards curve back to the Edwards curve. Fails for the same points as
+/// `HasMontgomeryModel::from_montgomery`.
+pub fn weierstrass_to_edwards<E>(
+    point: &<<E::Montgomery as HasWeierstrassModel>::Weierstrass as IsEllipticCurve>::PointRepresentation,
+) -> Result<E::PointRepresentation, EllipticCurveError>
+where
+    E: HasMontgomeryModel,
+    E::Montgomery: HasWeierstrassModel,
+    <E::Montgomery as IsEllipticCurve>::PointRepresentation: ToAffine<E::BaseField>,
+    <<E::Montgomery as HasWeierstrassModel>::Weierstrass as IsEllipticCurve>::PointRepresentation:
+        ToAffine<E::BaseField>,
+{
+    E::from_montgomery(&E::Montgomery::from_weierstrass(point))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::{
+        edwards::curves::{ed25519::curve::Ed25519Curve, tiny_jub_jub::TinyJubJubEdwards},
+        montgomery::curves::{curve25519::Curve25519, tiny_jub_jub::TinyJubJubMontgomery},
+        short_weierstrass::curves::{tiny_jub_jub::TinyJubJubWeierstrass, wei25519::Wei25519Curve},
+    };
+
+    fn check_montgomery_parameters<E: HasMontgomeryModel>() {
+        let (a, d) = (E::a(), E::d());
+        let s = E::montgomery_scaling_factor();
+        assert_eq!(
+            E::Montgomery::a(),
+            FieldElement::from(2) * (&a + &d) / (&a - &d)
+        );
+        assert_eq!(
+            E::Montgomery::b() * s.square(),
+            FieldElement::from(4) / (a - d)
+        );
+    }
+
+    fn check_weierstrass_parameters<M: HasWeierstrassModel>() {
+        let (a, b) = (M::a(), M::b());
+        assert_eq!(
+            M::Weierstrass::a(),
+            (FieldElement::from(3) - a.square()) / (FieldElement::from(3) * b.square())
+        );
+        assert_eq!(
+            M::Weierstrass::b(),
+            (FieldElement::from(2) * a.pow(3_u16) - FieldElement::from(9) * &a)
+                / (FieldElement::from(27) * b.pow(3_u16))
+        );
+    }
+
+    #[test]
+    fn curve_parameters_are_compatible() {
+        check_montgomery_parameters::<TinyJubJubEdwards>();
+        check_montgomery_parameters::<Ed25519Curve>();
+        check_weierstrass_parameters::<TinyJubJubMontgomery>();
+        check_weierstrass_parameters::<Curve25519>();
+    }
+
+    #[test]
+    fn tiny_jub_jub_maps_are_group_isomorphisms() {
+        let g = TinyJubJubEdwards::generator();
+        let g_montgomery = TinyJubJubEdwards::to_montgomery(&g);
+        let g_weierstrass = edwards_to_weierstrass::<TinyJubJubEdwards>(&g);
+        // The group of points has order 20.
+        for k in 0_u16..20 {
+            let p = g.operate_with_self(k);
+            let p_montgomery = TinyJubJubEdwards::to_montgomery(&p);
+            let p_weierstrass = edwards_to_weierstrass::<TinyJubJubEdwards>(&p);
+            assert_eq!(p_montgomery, g_montgomery.operate_with_self(k));
+            assert_eq!(p_weierstrass, g_weierstrass.operate_with_self(k));
+            assert_eq!(
+                TinyJubJubEdwards::from_montgomery(&p_montgomery),
+                Ok(p.clone())
+            );
+            assert_eq!(
+                weierstrass_to_edwards::<TinyJubJubEdwards>(&p_weierstrass),
+                Ok(p)
+            );
+        }
+    }
+
+    #[test]
+    fn tiny_jub_jub_maps_send_special_points_to_special_points() {
+        let neutral =
+            TinyJubJubEdwards::create_point_from_affine(FieldElement::zero(), FieldElement::one())
+                .unwrap();
+        assert!(TinyJubJubEdwards::to_montgomery(&neutral).is_neutral_element());
+        let order_two =
+            TinyJubJubEdwards::create_point_from_affine(FieldElement::zero(), -FieldElement::one())
+                .unwrap();
+        assert_eq!(
+            TinyJubJubEdwards::to_montgomery(&order_two),
+            TinyJubJubMontgomery::create_point_from_affine(
+                FieldElement::zero(),
+                FieldElement::zero()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn tiny_jub_jub_montgomery_generator_is_mapped_to_the_weierstrass_generator() {
+        let g = TinyJubJubMontgomery::generator();
+        assert_eq!(
+            TinyJubJubMontgomery::to_weierstrass(&g),
+            TinyJubJubWeierstrass::generator()
+        );
+        assert_eq!(
+            TinyJubJubMontgomery::from_weierstrass(&TinyJubJubWeierstrass::generator()),
+            g
+        );
+    }
+
+    #[test]
+    fn ed25519_generator_is_mapped_to_the_curve25519_and_wei25519_generators() {
+        let g = Ed25519Curve::generator();
+        assert_eq!(Ed25519Curve::to_montgomery(&g), Curve25519::generator());
+        assert_eq!(
+            edwards_to_weierstrass::<Ed25519Curve>(&g),
+            Wei25519Curve::generator()
+        );
+        assert_eq!(
+            weierstrass_to_edwards::<Ed25519Curve>(&Wei25519Curve::generator()),
+            Ok(g)
+        );
+    }
+
+    #[test]
+    fn ed25519_maps_commute_with_the_group_operations() {
+        let g = Ed25519Curve::generator();
+        let p = g.operate_with_self(1234567_u64);
+        let q = g.operate_with_self(7654321_u64);
+        let sum = p.operate_with(&q);
+        assert_eq!(
+            Ed25519Curve::to_montgomery(&sum),
+            Ed25519Curve::to_montgomery(&p).operate_with(&Ed25519Curve::to_montgomery(&q))
+        );
+        assert_eq!(
+            edwards_to_weierstrass::<Ed25519Curve>(&sum),
+            edwards_to_weierstrass::<Ed25519Curve>(&p)
+                .operate_with(&edwards_to_weierstrass::<Ed25519Curve>(&q))
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use super::curve::BandersnatchCurve;
+use crate::{
+    elliptic_curve::{
+        edwards::{extended_point::EdwardsExtendedPoint, traits::IsEdwards},
+        short_weierstrass::{
+            curves::bls12_381::default_types::{FrConfig, FrElement},
+            errors::DeserializationError,
+        },
+        traits::HasCofactor,
+    },
+    field::{
+        element::FieldElement, fields::montgomery_backed_prime_fields::IsModulus,
+        traits::LegendreSymbol,
+    },
+    traits::ByteConversion,
+    unsigned_integer::element::U256,
+};
+
+/// Number of bytes of the encoding of a point.
+pub const BANDERSNATCH_ENCODING_SIZE: usize = 32;
+
+/// Returns true if `y` is larger than `-y`, that is, if it is larger than `(r - 1) / 2`.
+fn is_lexicographically_largest(y: &FrElement) -> bool {
+    y.representative() > FrConfig::MODULUS >> 1
+}
+
+impl BandersnatchCurve {
+    /// Returns the serialization of `point` used by Verkle trees, which work in the
+    /// quotient group Banderwagon, where `P` and `P + (0, -1) = (-x, -y)` are identified:
+    /// the big-endian encoding of `x` if `y` is lexicographically largest and of `-x` otherwise.
+    /// For more info, see https://hackmd.io/@6iQDuIePQjyYBqDChYw_jg/BJ2-L6Nzc
+    pub fn compress(point: &EdwardsExtendedPoint<Self>) -> [u8; BANDERSNATCH_ENCODING_SIZE] {
+        let point = point.to_affine();
+        let x = if is_lexicographically_largest(point.y()) {
+            point.x().clone()
+        } else {
+            -point.x()
+        };
+        let mut bytes = [0; BANDERSNATCH_ENCODING_SIZE];
+        bytes.copy_from_slice(&x.to_bytes_be());
+        bytes
+    }
+
+    /// Decodes a point serialized with `compress`, returning the one of the two
+    /// points with the given encoding that belongs to the prime order subgroup.
+    /// The encoding is rejected if `x` is not canonical, if it is not the abscissa of a
+    /// point, or if `1 - a * x^2` is not a square, which is the Banderwagon subgroup check.
+    pub fn decompress(bytes: &[u8]) -> Result<EdwardsExtendedPoint<Self>, DeserializationError> {
+        if bytes.len() != BANDERSNATCH_ENCODING_SIZE {
+            return Err(DeserializationError::InvalidAmountOfBytes);
+        }
+        let x =
+            U256::from_bytes_be(bytes).map_err(|_| DeserializationError::FieldFromBytesError)?;
+        if x >= FrConfig::MODULUS {
+            return Err(DeserializationError::FieldFromBytesError);
+        }
+        let x = FrElement::new(x);
+
+        let x_squared = x.square();
+        let numerator = FieldElement::one() - Self::a() * &x_squared;
+        if numerator.legendre_symbol() != LegendreSymbol::One {
+            return Err(DeserializationError::NotInSubgroup);
+        }
+        // y^2 = (1 - a * x^2) / (1 - d * x^2)
+        let denominator = FieldElement::one() - Self::d() * x_squared;
+        let (y, minus_y) = (numerator * denominator.inv())
+            .sqrt()
+            .ok_or(DeserializationError::FieldFromBytesError)?;
+        let y = if is_lexicographically_largest(&y) {
+            y
+        } else {
+            minus_y
+        };
+
+        let t = &x * &y;
+        let point = EdwardsExtendedPoint::new([x, y, FieldElement::one(), t]);
+        if point.is_in_subgroup() {
+            Ok(point)
+        } else {
+            let [x, y, z, t] = point.coordinates();
+            Ok(EdwardsExtendedPoint::new([-x, -y, z.clone(), t.clone()]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cyclic_group::IsGroup, elliptic_curve::traits::IsEllipticCurve};
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// Serializations of `2^i * G` from the Banderwagon test vectors of rust-verkle.
+    const DOUBLINGS_OF_THE_GENERATOR: [&str; 4] = [
+        "4a2c7486fd924882bf02c6908de395122843e3e05264d7991e18e7985dad51e9",
+        "43aa74ef706605705989e8fd38df46873b7eae5921fbed115ac9d937399ce4d5",
+        "5e5f550494159f38aa54d2ed7f11a7e93e4968617990445cc93ac8e59808c126",
+        "0e7e3748db7c5c999a7bcd93d71d671f1f40090423792266f94cb27ca43fce5c",
+    ];
+
+    #[test]
+    fn serialization_matches_verkle_vectors() {
+        let mut point = BandersnatchCurve::generator();
+        for expected in DOUBLINGS_OF_THE_GENERATOR {
+            let bytes = BandersnatchCurve::compress(&point);
+            assert_eq!(bytes.to_vec(), from_hex(expected));
+            assert_eq!(BandersnatchCurve::decompress(&bytes).unwrap(), point);
+            point = point.double();
+        }
+    }
+
+    #[test]
+    fn points_differing_by_the_point_of_order_two_have_the_same_encoding() {
+        let point_of_order_two =
+            BandersnatchCurve::create_point_from_affine(FieldElement::zero(), -FieldElement::one())
+                .unwrap();
+        let p = BandersnatchCurve::generator().operate_with_self(77_u64);
+        let q = p.operate_with(&point_of_order_two);
+        assert_eq!(
+            BandersnatchCurve::compress(&p),
+            BandersnatchCurve::compress(&q)
+        );
+        assert_eq!(
+            BandersnatchCurve::decompress(&BandersnatchCurve::compress(&q)).unwrap(),
+            p
+        );
+    }
+
+    #[test]
+    fn decompress_rejects_invalid_encodings() {
+        assert_eq!(
+            BandersnatchCurve::decompress(&[0; 31]),
+            Err(DeserializationError::InvalidAmountOfBytes)
+        );
+        let r = from_hex("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+        assert_eq!(
+            BandersnatchCurve::decompress(&r),
+            Err(DeserializationError::FieldFromBytesError)
+        );
+        // Points outside of 2 * E are rejected by the Banderwagon subgroup check.
+        let mut rejected = 0;
+        for i in 1..20_u8 {
+            let mut bytes = [0; 32];
+            bytes[31] = i;
+            if BandersnatchCurve::decompress(&bytes) == Err(DeserializationError::NotInSubgroup) {
+                rejected += 1;
+            }
+        }
+        assert!(rejected > 0);
+    }
+}
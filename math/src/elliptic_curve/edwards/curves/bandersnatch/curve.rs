@@ -0,0 +1,204 @@
+use super::default_types::FrConfig;
+use crate::cyclic_group::IsGroup;
+use crate::elliptic_curve::edwards::{extended_point::EdwardsExtendedPoint, traits::IsEdwards};
+use crate::elliptic_curve::glv::{GlvLattice, SignedInteger};
+use crate::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::{
+    FrElement as BLS12381FrElement, FrField as BLS12381FrField,
+};
+use crate::elliptic_curve::traits::{HasCofactor, HasEndomorphism, IsEllipticCurve};
+use crate::field::element::FieldElement;
+use crate::field::fields::montgomery_backed_prime_fields::IsModulus;
+use crate::unsigned_integer::element::U256;
+
+/// The cofactor of Bandersnatch: its group of points has order `4 * n`.
+pub const BANDERSNATCH_COFACTOR: u64 = 4;
+
+/// Constants of the endomorphism `psi(x, y) = (x * (B + C * y^2) / (E * y), ...)`.
+const PSI_B: BLS12381FrElement = BLS12381FrElement::from_hex_unchecked(
+    "52c9f28b828426a561f00d3a63511a882ea712770d9af4d6ee0f014d172510b4",
+);
+const PSI_C: BLS12381FrElement = BLS12381FrElement::from_hex_unchecked(
+    "2123b4c7a71956a2d149cacda650bd7d2516918bf263672811f0feb1e8daef4b",
+);
+const PSI_E: BLS12381FrElement = BLS12381FrElement::from_hex_unchecked(
+    "478b192460db772c46c280cd3b5e3c4841ffb1f91bc406d8d260fbb5460d8fa8",
+);
+
+/// The twisted Edwards curve `-5 * x^2 + y^2 = 1 + d * x^2 * y^2` over the scalar field
+/// of BLS12-381, with `d = 138827208126141220649022263972958607803 / 171449701953573178309673572579671231137`.
+/// It has an endomorphism of degree 2 that acts on the prime order subgroup as the
+/// multiplication by a square root of -2, which speeds up scalar multiplications with GLV.
+/// The addition formulas have no exceptions on the prime order subgroup.
+/// For more info, see "Bandersnatch: a fast elliptic curve built over the BLS12-381
+/// scalar field" by Masson, Sanso and Zhang (https://eprint.iacr.org/2021/1152).
+#[derive(Clone, Debug)]
+pub struct BandersnatchCurve;
+
+impl IsEllipticCurve for BandersnatchCurve {
+    type BaseField = BLS12381FrField;
+    type PointRepresentation = EdwardsExtendedPoint<Self>;
+
+    /// Returns the generator of the prime order subgroup used by arkworks and Verkle trees.
+    fn generator() -> Self::PointRepresentation {
+        let x = BLS12381FrElement::from_hex_unchecked(
+            "29c132cc2c0b34c5743711777bbe42f32b79c022ad998465e1e71866a252ae18",
+        );
+        let y = BLS12381FrElement::from_hex_unchecked(
+            "2a6c669eda123e0f157d8b50badcd586358cad81eee464605e3167b6cc974166",
+        );
+        let t = &x * &y;
+        Self::PointRepresentation::new([x, y, FieldElement::one(), t])
+    }
+}
+
+impl IsEdwards for BandersnatchCurve {
+    fn a() -> FieldElement<Self::BaseField> {
+        -FieldElement::from(5)
+    }
+
+    fn d() -> FieldElement<Self::BaseField> {
+        BLS12381FrElement::from_hex_unchecked(
+            "6389c12633c267cbc66e3bf86be3b6d8cb66677177e54f92b369f2f5188d58e7",
+        )
+    }
+}
+
+impl EdwardsExtendedPoint<BandersnatchCurve> {
+    /// Returns the image of `self` under the endomorphism
+    /// `psi(x, y) = (x * (B + C * y^2) / (E * y), (B - 1 + (C + 1) * y^2) / (B + 1 + (C - 1) * y^2))`,
+    /// the 2-isogeny with kernel `(0, -1)` composed with an isomorphism back to the curve.
+    /// It is well defined for every point of odd order.
+    pub fn psi(&self) -> Self {
+        let [x, y, z, _] = self.coordinates();
+        let y_squared = y.square();
+        let z_squared = z.square();
+        // Homogenized numerator of the x coordinate over 1 - y^2.
+        let n = PSI_B * &z_squared + PSI_C * &y_squared;
+        let e = z_squared - y_squared;
+
+        let x_num = x * &n;
+        let x_den = PSI_E * y * z.square();
+        let y_num = &n - &e;
+        let y_den = n + e;
+        Self::new([
+            &x_num * &y_den,
+            &y_num * &x_den,
+            x_den * y_den,
+            x_num * y_num,
+        ])
+    }
+}
+
+impl HasCofactor for EdwardsExtendedPoint<BandersnatchCurve> {
+    /// Checks that `n * P` is the neutral element.
+    fn is_in_subgroup(&self) -> bool {
+        self.operate_with_self(FrConfig::MODULUS)
+            .is_neutral_element()
+    }
+
+    /// Multiplies `self` by the cofactor 4.
+    fn clear_cofactor(&self) -> Self {
+        self.double().double()
+    }
+}
+
+impl HasEndomorphism<4> for EdwardsExtendedPoint<BandersnatchCurve> {
+    /// `psi` acts on the prime order subgroup as the multiplication by
+    /// `lambda = 0x13b4f3dc4a39a493edf849562b38c72bcfc49db970a5056ed13d21408783df05`.
+    fn endomorphism(&self) -> Self {
+        self.psi()
+    }
+
+    /// Reduced basis of the lattice of determinant `n`, with vectors of about 127 bits.
+    fn glv_lattice() -> GlvLattice<4> {
+        GlvLattice {
+            order: FrConfig::MODULUS,
+            v1: [
+                SignedInteger::positive(U256::from_hex_unchecked(
+                    "102967ddcabd1ebbf1c4b23447ac3e88",
+                )),
+                SignedInteger::negative(U256::from_hex_unchecked(
+                    "555fe2004be6928e4b02f94a9789181f",
+                )),
+            ],
+            v2: [
+                SignedInteger::positive(U256::from_hex_unchecked(
+                    "555fe2004be6928e4b02f94a9789181f",
+                )),
+                SignedInteger::positive(U256::from_hex_unchecked(
+                    "814b3eee55e8f5df8e2591a23d61f44",
+                )),
+            ],
+            g1: SignedInteger::positive(U256::from_hex_unchecked(
+                "4760f127d8767bde993b75e7547768ab",
+            )),
+            g2: SignedInteger::positive(U256::from_hex_unchecked(
+                "2f21df5b0541cf632debac77a3f4747c2",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAMBDA: U256 = U256::from_hex_unchecked(
+        "13b4f3dc4a39a493edf849562b38c72bcfc49db970a5056ed13d21408783df05",
+    );
+
+    #[test]
+    fn generator_satisfies_defining_equation() {
+        let g = BandersnatchCurve::generator();
+        assert_eq!(
+            BandersnatchCurve::defining_equation(g.x(), g.y()),
+            FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn generator_has_order_n() {
+        let g = BandersnatchCurve::generator();
+        assert!(g.is_in_subgroup());
+        assert!(!g.is_neutral_element());
+    }
+
+    #[test]
+    fn points_outside_the_subgroup_are_detected() {
+        let point_of_order_two =
+            BandersnatchCurve::create_point_from_affine(FieldElement::zero(), -FieldElement::one())
+                .unwrap();
+        let p = BandersnatchCurve::generator().operate_with(&point_of_order_two);
+        assert!(!p.is_in_subgroup());
+        assert!(p.clear_cofactor().is_in_subgroup());
+    }
+
+    #[test]
+    fn psi_acts_as_the_multiplication_by_lambda() {
+        let g = BandersnatchCurve::generator();
+        for p in [g.clone(), g.operate_with_self(12345_u64), g.double().neg()] {
+            assert_eq!(p.psi(), p.operate_with_self(LAMBDA));
+            // psi^2 = -2
+            assert_eq!(p.psi().psi(), p.double().neg());
+        }
+        assert!(EdwardsExtendedPoint::<BandersnatchCurve>::neutral_element()
+            .psi()
+            .is_neutral_element());
+    }
+
+    #[test]
+    fn glv_mul_agrees_with_double_and_add() {
+        let g = BandersnatchCurve::generator();
+        for k in [
+            U256::from_u64(0),
+            U256::from_u64(1),
+            LAMBDA,
+            FrConfig::MODULUS - U256::from_u64(1),
+            U256::from_hex_unchecked(
+                "0bd4a8e2b6e4b9a1f1d1f3a43c0f2d6e9a5a7e8c3f1b2d4e6f708192a3b4c5d6",
+            ),
+        ] {
+            assert_eq!(g.glv_mul(&k), g.operate_with_self(k));
+        }
+    }
+}
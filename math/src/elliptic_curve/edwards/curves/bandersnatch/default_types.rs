@@ -0,0 +1,22 @@
+use crate::{
+    field::{
+        element::FieldElement,
+        fields::montgomery_backed_prime_fields::{IsModulus, MontgomeryBackendPrimeField},
+    },
+    unsigned_integer::element::U256,
+};
+
+#[derive(Clone, Debug)]
+pub struct FrConfig;
+
+/// Order of the prime order subgroup of Bandersnatch
+impl IsModulus<U256> for FrConfig {
+    const MODULUS: U256 = U256::from_hex_unchecked(
+        "1cfb69d4ca675f520cce760202687600ff8f87007419047174fd06b52876e7e1",
+    );
+}
+
+/// FrField using MontgomeryBackend for Bandersnatch
+pub type FrField = MontgomeryBackendPrimeField<FrConfig, 4>;
+/// FrElement using MontgomeryBackend for Bandersnatch
+pub type FrElement = FieldElement<FrField>;
@@ -0,0 +1,133 @@
+use super::curve::{Ed25519Curve, Ed25519FieldElement, Ed25519FieldModulus};
+use crate::{
+    elliptic_curve::{
+        edwards::{extended_point::EdwardsExtendedPoint, traits::IsEdwards},
+        short_weierstrass::errors::DeserializationError,
+    },
+    field::{element::FieldElement, fields::montgomery_backed_prime_fields::IsModulus},
+    traits::ByteConversion,
+    unsigned_integer::element::U256,
+};
+
+/// Number of bytes of the encoding of a point.
+pub const ED25519_ENCODING_SIZE: usize = 32;
+
+fn is_odd(x: &Ed25519FieldElement) -> bool {
+    x.representative().limbs[3] & 1 == 1
+}
+
+impl Ed25519Curve {
+    /// Returns the encoding of `point` from RFC 8032, section 5.1.2: the little-endian
+    /// encoding of `y`, with the most significant bit set to the least significant bit of `x`.
+    pub fn compress(point: &EdwardsExtendedPoint<Self>) -> [u8; ED25519_ENCODING_SIZE] {
+        let point = point.to_affine();
+        let mut bytes = [0; ED25519_ENCODING_SIZE];
+        bytes.copy_from_slice(&point.y().to_bytes_le());
+        if is_odd(point.x()) {
+            bytes[ED25519_ENCODING_SIZE - 1] |= 0x80;
+        }
+        bytes
+    }
+
+    /// Decodes a point as in RFC 8032, section 5.1.3, rejecting non-canonical encodings
+    /// of `y`. The point is not checked to belong to the prime order subgroup.
+    pub fn decompress(bytes: &[u8]) -> Result<EdwardsExtendedPoint<Self>, DeserializationError> {
+        if bytes.len() != ED25519_ENCODING_SIZE {
+            return Err(DeserializationError::InvalidAmountOfBytes);
+        }
+        let x_is_odd = bytes[ED25519_ENCODING_SIZE - 1] & 0x80 != 0;
+        let mut y_bytes = bytes.to_vec();
+        y_bytes[ED25519_ENCODING_SIZE - 1] &= 0x7f;
+        let y =
+            U256::from_bytes_le(&y_bytes).map_err(|_| DeserializationError::FieldFromBytesError)?;
+        if y >= Ed25519FieldModulus::MODULUS {
+            return Err(DeserializationError::FieldFromBytesError);
+        }
+        let y = Ed25519FieldElement::new(y);
+
+        // x^2 = (y^2 - 1) / (d * y^2 + 1), where the denominator is never zero
+        // because `d` is not a square.
+        let y_squared = y.square();
+        let u = &y_squared - FieldElement::one();
+        let v = Self::d() * y_squared + FieldElement::one();
+        let (x, minus_x) = (u * v.inv())
+            .sqrt()
+            .ok_or(DeserializationError::FieldFromBytesError)?;
+        if x == FieldElement::zero() && x_is_odd {
+            return Err(DeserializationError::FieldFromBytesError);
+        }
+        let x = if is_odd(&x) == x_is_odd { x } else { minus_x };
+        let t = &x * &y;
+        Ok(EdwardsExtendedPoint::new([x, y, FieldElement::one(), t]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cyclic_group::IsGroup, elliptic_curve::traits::IsEllipticCurve};
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn generator_encoding_matches_the_standard() {
+        let bytes = Ed25519Curve::compress(&Ed25519Curve::generator());
+        assert_eq!(
+            bytes.to_vec(),
+            from_hex("5866666666666666666666666666666666666666666666666666666666666666")
+        );
+        assert_eq!(
+            Ed25519Curve::decompress(&bytes).unwrap(),
+            Ed25519Curve::generator()
+        );
+    }
+
+    #[test]
+    fn compression_roundtrip() {
+        let g = Ed25519Curve::generator();
+        for k in [0_u64, 1, 2, 3, 5, 1 << 20, u64::MAX] {
+            let p = g.operate_with_self(k);
+            let bytes = Ed25519Curve::compress(&p);
+            assert_eq!(Ed25519Curve::decompress(&bytes).unwrap(), p);
+            let bytes = Ed25519Curve::compress(&p.neg());
+            assert_eq!(Ed25519Curve::decompress(&bytes).unwrap(), p.neg());
+        }
+    }
+
+    #[test]
+    fn decompress_rejects_invalid_encodings() {
+        // Wrong length.
+        assert_eq!(
+            Ed25519Curve::decompress(&[0; 31]),
+            Err(DeserializationError::InvalidAmountOfBytes)
+        );
+        // y = p is not canonical.
+        let p = from_hex("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f");
+        assert_eq!(
+            Ed25519Curve::decompress(&p),
+            Err(DeserializationError::FieldFromBytesError)
+        );
+        // There is no point with y = 2.
+        let mut bytes = [0; 32];
+        bytes[0] = 2;
+        assert_eq!(
+            Ed25519Curve::decompress(&bytes),
+            Err(DeserializationError::FieldFromBytesError)
+        );
+        // The only point with y = 1 has x = 0, which is even.
+        bytes[0] = 1;
+        assert!(Ed25519Curve::decompress(&bytes)
+            .unwrap()
+            .is_neutral_element());
+        bytes[31] = 0x80;
+        assert_eq!(
+            Ed25519Curve::decompress(&bytes),
+            Err(DeserializationError::FieldFromBytesError)
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use super::default_types::FrConfig;
+use crate::cyclic_group::IsGroup;
+use crate::elliptic_curve::birational::HasMontgomeryModel;
+use crate::elliptic_curve::edwards::{extended_point::EdwardsExtendedPoint, traits::IsEdwards};
+use crate::elliptic_curve::montgomery::curves::curve25519::Curve25519;
+use crate::elliptic_curve::traits::{HasCofactor, IsEllipticCurve};
+use crate::field::element::FieldElement;
+use crate::field::fields::montgomery_backed_prime_fields::{
+    IsModulus, MontgomeryBackendPrimeField,
+};
+use crate::unsigned_integer::element::U256;
+
+#[derive(Clone, Debug)]
+pub struct Ed25519FieldModulus;
+impl IsModulus<U256> for Ed25519FieldModulus {
+    const MODULUS: U256 = U256::from_hex_unchecked(
+        "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed",
+    );
+}
+
+pub type Ed25519PrimeField = MontgomeryBackendPrimeField<Ed25519FieldModulus, 4>;
+pub type Ed25519FieldElement = FieldElement<Ed25519PrimeField>;
+
+/// The cofactor of edwards25519: its group of points has order `8 * l`.
+pub const ED25519_COFACTOR: u64 = 8;
+
+/// The twisted Edwards curve `-x^2 + y^2 = 1 + d * x^2 * y^2` over the field of
+/// `2^255 - 19` elements, with `d = -121665 / 121666`, used by Ed25519 signatures.
+/// It is birationally equivalent to Curve25519.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc8032#section-5.1
+#[derive(Clone, Debug)]
+pub struct Ed25519Curve;
+
+impl IsEllipticCurve for Ed25519Curve {
+    type BaseField = Ed25519PrimeField;
+    type PointRepresentation = EdwardsExtendedPoint<Self>;
+
+    /// Returns the base point `B`, of order `l`.
+    fn generator() -> Self::PointRepresentation {
+        let x = Ed25519FieldElement::from_hex_unchecked(
+            "216936d3cd6e53fec0a4e231fdd6dc5c692cc7609525a7b2c9562d608f25d51a",
+        );
+        let y = Ed25519FieldElement::from_hex_unchecked(
+            "6666666666666666666666666666666666666666666666666666666666666658",
+        );
+        let t = &x * &y;
+        Self::PointRepresentation::new([x, y, FieldElement::one(), t])
+    }
+}
+
+impl IsEdwards for Ed25519Curve {
+    fn a() -> FieldElement<Self::BaseField> {
+        -FieldElement::one()
+    }
+
+    fn d() -> FieldElement<Self::BaseField> {
+        Ed25519FieldElement::from_hex_unchecked(
+            "52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3",
+        )
+    }
+}
+
+impl HasMontgomeryModel for Ed25519Curve {
+    type Montgomery = Curve25519;
+
+    /// Returns `sqrt(-486664)`, the root that maps the base point of edwards25519
+    /// to the base point of Curve25519.
+    /// See https://www.rfc-editor.org/rfc/rfc7748#section-4.1
+    fn montgomery_scaling_factor() -> FieldElement<Self::BaseField> {
+        Ed25519FieldElement::from_hex_unchecked(
+            "70d9120b9f5ff9442d84f723fc03b0813a5e2c2eb482e57d3391fb5500ba81e7",
+        )
+    }
+}
+
+impl HasCofactor for EdwardsExtendedPoint<Ed25519Curve> {
+    /// Checks that `l * P` is the neutral element.
+    fn is_in_subgroup(&self) -> bool {
+        self.operate_with_self(FrConfig::MODULUS)
+            .is_neutral_element()
+    }
+
+    /// Multiplies `self` by the cofactor 8.
+    fn clear_cofactor(&self) -> Self {
+        self.double().double().double()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::edwards::point::EdwardsProjectivePoint;
+
+    type Ed25519Point = EdwardsExtendedPoint<Ed25519Curve>;
+
+    fn point_of_order_two() -> Ed25519Point {
+        Ed25519Curve::create_point_from_affine(FieldElement::zero(), -FieldElement::one()).unwrap()
+    }
+
+    #[test]
+    fn generator_satisfies_defining_equation() {
+        let g = Ed25519Curve::generator();
+        assert_eq!(
+            Ed25519Curve::defining_equation(g.x(), g.y()),
+            FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn generator_has_order_l() {
+        let g = Ed25519Curve::generator();
+        assert!(g.is_in_subgroup());
+        assert!(!g.is_neutral_element());
+    }
+
+    #[test]
+    fn operations_agree_with_the_affine_formulas() {
+        let g = Ed25519Curve::generator();
+        let h = EdwardsProjectivePoint::<Ed25519Curve>::new([
+            g.x().clone(),
+            g.y().clone(),
+            FieldElement::one(),
+        ]);
+        for k in [2_u64, 3, 7, 1000, 123456789] {
+            let p = g.operate_with_self(k).to_affine();
+            let q = h.operate_with_self(k).to_affine();
+            assert_eq!(p.x(), q.x());
+            assert_eq!(p.y(), q.y());
+        }
+    }
+
+    #[test]
+    fn points_of_small_order_are_not_in_the_subgroup() {
+        let t = point_of_order_two();
+        assert!(!t.is_in_subgroup());
+        assert!(t.clear_cofactor().is_neutral_element());
+        let p = Ed25519Curve::generator().operate_with(&t);
+        assert!(!p.is_in_subgroup());
+        assert!(p.clear_cofactor().is_in_subgroup());
+    }
+
+    #[test]
+    fn clear_cofactor_multiplies_by_eight() {
+        let g = Ed25519Curve::generator();
+        assert_eq!(g.clear_cofactor(), g.operate_with_self(ED25519_COFACTOR));
+    }
+}
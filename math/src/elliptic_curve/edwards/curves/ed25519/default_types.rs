@@ -0,0 +1,23 @@
+use crate::{
+    field::{
+        element::FieldElement,
+        fields::montgomery_backed_prime_fields::{IsModulus, MontgomeryBackendPrimeField},
+    },
+    unsigned_integer::element::U256,
+};
+
+#[derive(Clone, Debug)]
+pub struct FrConfig;
+
+/// Order `l = 2^252 + 27742317777372353535851937790883648493` of the prime order
+/// subgroup of edwards25519
+impl IsModulus<U256> for FrConfig {
+    const MODULUS: U256 = U256::from_hex_unchecked(
+        "1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed",
+    );
+}
+
+/// FrField using MontgomeryBackend for edwards25519
+pub type FrField = MontgomeryBackendPrimeField<FrConfig, 4>;
+/// FrElement using MontgomeryBackend for edwards25519
+pub type FrElement = FieldElement<FrField>;
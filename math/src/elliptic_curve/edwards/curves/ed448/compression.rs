@@ -0,0 +1,145 @@
+use super::curve::{Ed448Curve, Ed448FieldElement};
+use crate::{
+    elliptic_curve::{
+        edwards::{extended_point::EdwardsExtendedPoint, traits::IsEdwards},
+        short_weierstrass::errors::DeserializationError,
+    },
+    field::{
+        element::FieldElement,
+        fields::p448_goldilocks_prime_field::{
+            P448GoldilocksPrimeField, P448_GOLDILOCKS_PRIME_FIELD_ORDER, U448,
+        },
+    },
+    traits::ByteConversion,
+};
+
+/// Number of bytes of the encoding of a point.
+pub const ED448_ENCODING_SIZE: usize = 57;
+
+fn is_odd(x: &Ed448FieldElement) -> bool {
+    x.representative().limbs[6] & 1 == 1
+}
+
+impl Ed448Curve {
+    /// Returns the encoding of `point` from RFC 8032, section 5.2.2: the little-endian
+    /// encoding of `y` in 57 bytes, with the most significant bit set to the least
+    /// significant bit of `x`.
+    pub fn compress(point: &EdwardsExtendedPoint<Self>) -> [u8; ED448_ENCODING_SIZE] {
+        let point = point.to_affine();
+        let mut bytes = [0; ED448_ENCODING_SIZE];
+        bytes[..ED448_ENCODING_SIZE - 1].copy_from_slice(&point.y().to_bytes_le());
+        if is_odd(point.x()) {
+            bytes[ED448_ENCODING_SIZE - 1] = 0x80;
+        }
+        bytes
+    }
+
+    /// Decodes a point as in RFC 8032, section 5.2.3, rejecting non-canonical encodings
+    /// of `y`. The point is not checked to belong to the prime order subgroup.
+    pub fn decompress(bytes: &[u8]) -> Result<EdwardsExtendedPoint<Self>, DeserializationError> {
+        if bytes.len() != ED448_ENCODING_SIZE {
+            return Err(DeserializationError::InvalidAmountOfBytes);
+        }
+        let x_is_odd = bytes[ED448_ENCODING_SIZE - 1] & 0x80 != 0;
+        // The remaining bits of the last byte are bits of `y`, so they must be zero.
+        if bytes[ED448_ENCODING_SIZE - 1] & 0x7f != 0 {
+            return Err(DeserializationError::FieldFromBytesError);
+        }
+        let y = U448::from_bytes_le(&bytes[..ED448_ENCODING_SIZE - 1])
+            .map_err(|_| DeserializationError::FieldFromBytesError)?;
+        if y >= P448_GOLDILOCKS_PRIME_FIELD_ORDER {
+            return Err(DeserializationError::FieldFromBytesError);
+        }
+        let y = Ed448FieldElement::from_raw(&P448GoldilocksPrimeField::from_integer(&y));
+
+        // x^2 = (y^2 - 1) / (d * y^2 - 1), where the denominator is never zero
+        // because `d` is not a square.
+        let y_squared = y.square();
+        let u = &y_squared - FieldElement::one();
+        let v = Self::d() * y_squared - FieldElement::one();
+        let (x, minus_x) = (u * v.inv())
+            .sqrt()
+            .ok_or(DeserializationError::FieldFromBytesError)?;
+        if x == FieldElement::zero() && x_is_odd {
+            return Err(DeserializationError::FieldFromBytesError);
+        }
+        let x = if is_odd(&x) == x_is_odd { x } else { minus_x };
+        let t = &x * &y;
+        Ok(EdwardsExtendedPoint::new([x, y, FieldElement::one(), t]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cyclic_group::IsGroup, elliptic_curve::traits::IsEllipticCurve};
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn generator_encoding_matches_the_standard() {
+        let bytes = Ed448Curve::compress(&Ed448Curve::generator());
+        assert_eq!(
+            bytes.to_vec(),
+            from_hex("14fa30f25b790898adc8d74e2c13bdfdc4397ce61cffd33ad7c2a0051e9c78874098a36c7373ea4b62c7c9563720768824bcb66e71463f6900")
+        );
+        assert_eq!(
+            Ed448Curve::decompress(&bytes).unwrap(),
+            Ed448Curve::generator()
+        );
+    }
+
+    #[test]
+    fn compression_roundtrip() {
+        let g = Ed448Curve::generator();
+        for k in [0_u64, 1, 2, 3, 5, 1 << 20, u64::MAX] {
+            let p = g.operate_with_self(k);
+            let bytes = Ed448Curve::compress(&p);
+            assert_eq!(Ed448Curve::decompress(&bytes).unwrap(), p);
+            let bytes = Ed448Curve::compress(&p.neg());
+            assert_eq!(Ed448Curve::decompress(&bytes).unwrap(), p.neg());
+        }
+    }
+
+    #[test]
+    fn decompress_rejects_invalid_encodings() {
+        // Wrong length.
+        assert_eq!(
+            Ed448Curve::decompress(&[0; 56]),
+            Err(DeserializationError::InvalidAmountOfBytes)
+        );
+        // y = p is not canonical.
+        let mut p = P448_GOLDILOCKS_PRIME_FIELD_ORDER.to_bytes_le();
+        p.push(0);
+        assert_eq!(
+            Ed448Curve::decompress(&p),
+            Err(DeserializationError::FieldFromBytesError)
+        );
+        // There is no point with y = 2.
+        let mut bytes = [0; 57];
+        bytes[0] = 2;
+        assert_eq!(
+            Ed448Curve::decompress(&bytes),
+            Err(DeserializationError::FieldFromBytesError)
+        );
+        // The only point with y = 1 has x = 0, which is even.
+        bytes[0] = 1;
+        assert!(Ed448Curve::decompress(&bytes).unwrap().is_neutral_element());
+        bytes[56] = 0x80;
+        assert_eq!(
+            Ed448Curve::decompress(&bytes),
+            Err(DeserializationError::FieldFromBytesError)
+        );
+        // Bits 448 to 454 must be zero.
+        bytes[56] = 0x01;
+        assert_eq!(
+            Ed448Curve::decompress(&bytes),
+            Err(DeserializationError::FieldFromBytesError)
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use super::default_types::FrConfig;
+use crate::cyclic_group::IsGroup;
+use crate::elliptic_curve::edwards::{extended_point::EdwardsExtendedPoint, traits::IsEdwards};
+use crate::elliptic_curve::traits::{HasCofactor, IsEllipticCurve};
+use crate::field::element::FieldElement;
+use crate::field::fields::montgomery_backed_prime_fields::IsModulus;
+use crate::field::fields::p448_goldilocks_prime_field::{P448GoldilocksPrimeField, U56x8};
+
+pub type Ed448FieldElement = FieldElement<P448GoldilocksPrimeField>;
+
+/// The cofactor of edwards448: its group of points has order `4 * l`.
+pub const ED448_COFACTOR: u64 = 4;
+
+/// The Edwards curve `x^2 + y^2 = 1 - 39081 * x^2 * y^2` over the field of
+/// `2^448 - 2^224 - 1` elements, used by Ed448 signatures. It is 4-isogenous to Curve448.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc8032#section-5.2
+#[derive(Clone, Debug)]
+pub struct Ed448Curve;
+
+impl IsEllipticCurve for Ed448Curve {
+    type BaseField = P448GoldilocksPrimeField;
+    type PointRepresentation = EdwardsExtendedPoint<Self>;
+
+    /// Returns the base point `B`, of order `l`.
+    fn generator() -> Self::PointRepresentation {
+        let x = Ed448FieldElement::new(U56x8::from(
+            "4f1970c66bed0ded221d15a622bf36da9e146570470f1767ea6de324a3d3a46412ae1af72ab66511433b80e18b00938e2626a82bc70cc05e",
+        ));
+        let y = Ed448FieldElement::new(U56x8::from(
+            "693f46716eb6bc248876203756c9c7624bea73736ca3984087789c1e05a0c2d73ad3ff1ce67c39c4fdbd132c4ed7c8ad9808795bf230fa14",
+        ));
+        let t = &x * &y;
+        Self::PointRepresentation::new([x, y, FieldElement::one(), t])
+    }
+}
+
+impl IsEdwards for Ed448Curve {
+    fn a() -> FieldElement<Self::BaseField> {
+        FieldElement::one()
+    }
+
+    fn d() -> FieldElement<Self::BaseField> {
+        -FieldElement::from(39081)
+    }
+}
+
+impl HasCofactor for EdwardsExtendedPoint<Ed448Curve> {
+    /// Checks that `l * P` is the neutral element.
+    fn is_in_subgroup(&self) -> bool {
+        self.operate_with_self(FrConfig::MODULUS)
+            .is_neutral_element()
+    }
+
+    /// Multiplies `self` by the cofactor 4.
+    fn clear_cofactor(&self) -> Self {
+        self.double().double()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generator_satisfies_defining_equation() {
+        let g = Ed448Curve::generator();
+        assert_eq!(
+            Ed448Curve::defining_equation(g.x(), g.y()),
+            FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn generator_has_order_l() {
+        let g = Ed448Curve::generator();
+        assert!(g.is_in_subgroup());
+        assert!(!g.is_neutral_element());
+    }
+
+    #[test]
+    fn points_of_small_order_are_not_in_the_subgroup() {
+        let point_of_order_two =
+            Ed448Curve::create_point_from_affine(FieldElement::zero(), -FieldElement::one())
+                .unwrap();
+        let p = Ed448Curve::generator().operate_with(&point_of_order_two);
+        assert!(!p.is_in_subgroup());
+        assert!(p.clear_cofactor().is_in_subgroup());
+        assert_eq!(
+            p.clear_cofactor(),
+            Ed448Curve::generator().operate_with_self(ED448_COFACTOR)
+        );
+    }
+}
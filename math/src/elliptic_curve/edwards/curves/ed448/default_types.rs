@@ -0,0 +1,23 @@
+use crate::field::{
+    element::FieldElement,
+    fields::{
+        montgomery_backed_prime_fields::{IsModulus, MontgomeryBackendPrimeField},
+        p448_goldilocks_prime_field::U448,
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct FrConfig;
+
+/// Order `l = 2^446 - 13818066809895115352007386748515426880336692474882178609894547503885`
+/// of the prime order subgroup of edwards448
+impl IsModulus<U448> for FrConfig {
+    const MODULUS: U448 = U448::from_hex_unchecked(
+        "3fffffffffffffffffffffffffffffffffffffffffffffffffffffff7cca23e9c44edb49aed63690216cc2728dc58f552378c292ab5844f3",
+    );
+}
+
+/// FrField using MontgomeryBackend for edwards448
+pub type FrField = MontgomeryBackendPrimeField<FrConfig, 7>;
+/// FrElement using MontgomeryBackend for edwards448
+pub type FrElement = FieldElement<FrField>;
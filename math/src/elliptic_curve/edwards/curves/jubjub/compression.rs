@@ -0,0 +1,135 @@
+use super::curve::JubjubCurve;
+use crate::{
+    elliptic_curve::{
+        edwards::{extended_point::EdwardsExtendedPoint, traits::IsEdwards},
+        short_weierstrass::{
+            curves::bls12_381::default_types::{FrConfig, FrElement},
+            errors::DeserializationError,
+        },
+    },
+    field::{element::FieldElement, fields::montgomery_backed_prime_fields::IsModulus},
+    traits::ByteConversion,
+    unsigned_integer::element::U256,
+};
+
+/// Number of bytes of the encoding of a point.
+pub const JUBJUB_ENCODING_SIZE: usize = 32;
+
+fn is_odd(x: &FrElement) -> bool {
+    x.representative().limbs[3] & 1 == 1
+}
+
+impl JubjubCurve {
+    /// Returns the encoding `repr_J` of `point` from the Zcash protocol specification,
+    /// section 5.4.9.3: the little-endian encoding of `v`, with the most significant bit
+    /// set to the least significant bit of `u`.
+    pub fn compress(point: &EdwardsExtendedPoint<Self>) -> [u8; JUBJUB_ENCODING_SIZE] {
+        let point = point.to_affine();
+        let mut bytes = [0; JUBJUB_ENCODING_SIZE];
+        bytes.copy_from_slice(&point.y().to_bytes_le());
+        if is_odd(point.x()) {
+            bytes[JUBJUB_ENCODING_SIZE - 1] |= 0x80;
+        }
+        bytes
+    }
+
+    /// Decodes a point, rejecting non-canonical encodings as required by ZIP 216
+    /// (https://zips.z.cash/zip-0216). The point is not checked to belong to the
+    /// prime order subgroup.
+    pub fn decompress(bytes: &[u8]) -> Result<EdwardsExtendedPoint<Self>, DeserializationError> {
+        if bytes.len() != JUBJUB_ENCODING_SIZE {
+            return Err(DeserializationError::InvalidAmountOfBytes);
+        }
+        let u_is_odd = bytes[JUBJUB_ENCODING_SIZE - 1] & 0x80 != 0;
+        let mut v_bytes = bytes.to_vec();
+        v_bytes[JUBJUB_ENCODING_SIZE - 1] &= 0x7f;
+        let v =
+            U256::from_bytes_le(&v_bytes).map_err(|_| DeserializationError::FieldFromBytesError)?;
+        if v >= FrConfig::MODULUS {
+            return Err(DeserializationError::FieldFromBytesError);
+        }
+        let v = FrElement::new(v);
+
+        // u^2 = (v^2 - 1) / (d * v^2 + 1), where the denominator is never zero
+        // because `d` is not a square.
+        let v_squared = v.square();
+        let numerator = &v_squared - FieldElement::one();
+        let denominator = Self::d() * v_squared + FieldElement::one();
+        let (u, minus_u) = (numerator * denominator.inv())
+            .sqrt()
+            .ok_or(DeserializationError::FieldFromBytesError)?;
+        if u == FieldElement::zero() && u_is_odd {
+            return Err(DeserializationError::FieldFromBytesError);
+        }
+        let u = if is_odd(&u) == u_is_odd { u } else { minus_u };
+        let t = &u * &v;
+        Ok(EdwardsExtendedPoint::new([u, v, FieldElement::one(), t]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cyclic_group::IsGroup,
+        elliptic_curve::traits::{HasCofactor, IsEllipticCurve},
+    };
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn generator_encoding() {
+        let bytes = JubjubCurve::compress(&JubjubCurve::generator());
+        assert_eq!(
+            bytes.to_vec(),
+            from_hex("aa92d2590e873fccd7fe20c25cba263ec3c066c8782e1393171aabddf13c529d")
+        );
+        assert_eq!(
+            JubjubCurve::decompress(&bytes).unwrap(),
+            JubjubCurve::generator()
+        );
+    }
+
+    #[test]
+    fn compression_roundtrip() {
+        let g = JubjubCurve::generator();
+        for k in [0_u64, 1, 2, 3, 5, 1 << 20, u64::MAX] {
+            let p = g.operate_with_self(k);
+            let q = JubjubCurve::decompress(&JubjubCurve::compress(&p)).unwrap();
+            assert_eq!(q, p);
+            assert!(q.is_in_subgroup());
+            let q = JubjubCurve::decompress(&JubjubCurve::compress(&p.neg())).unwrap();
+            assert_eq!(q, p.neg());
+        }
+    }
+
+    #[test]
+    fn decompress_rejects_non_canonical_encodings() {
+        // v = r is not canonical.
+        let r = from_hex("01000000fffffffffe5bfeff02a4bd5305d8a10908d83933487d9d2953a7ed73");
+        assert_eq!(
+            JubjubCurve::decompress(&r),
+            Err(DeserializationError::FieldFromBytesError)
+        );
+        // The encoding of (0, 1) with the sign bit set is rejected by ZIP 216.
+        let mut bytes = [0; 32];
+        bytes[0] = 1;
+        assert!(JubjubCurve::decompress(&bytes)
+            .unwrap()
+            .is_neutral_element());
+        bytes[31] = 0x80;
+        assert_eq!(
+            JubjubCurve::decompress(&bytes),
+            Err(DeserializationError::FieldFromBytesError)
+        );
+        assert_eq!(
+            JubjubCurve::decompress(&bytes[1..]),
+            Err(DeserializationError::InvalidAmountOfBytes)
+        );
+    }
+}
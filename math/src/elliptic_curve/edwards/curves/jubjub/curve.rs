@@ -0,0 +1,97 @@
+use super::default_types::FrConfig;
+use crate::cyclic_group::IsGroup;
+use crate::elliptic_curve::edwards::{extended_point::EdwardsExtendedPoint, traits::IsEdwards};
+use crate::elliptic_curve::short_weierstrass::curves::bls12_381::default_types::{
+    FrElement as BLS12381FrElement, FrField as BLS12381FrField,
+};
+use crate::elliptic_curve::traits::{HasCofactor, IsEllipticCurve};
+use crate::field::element::FieldElement;
+use crate::field::fields::montgomery_backed_prime_fields::IsModulus;
+
+/// The cofactor of Jubjub: its group of points has order `8 * s`.
+pub const JUBJUB_COFACTOR: u64 = 8;
+
+/// The twisted Edwards curve `-u^2 + v^2 = 1 + d * u^2 * v^2` over the scalar field
+/// of BLS12-381, with `d = -10240 / 10241`, used by Zcash to compute signatures and
+/// Pedersen hashes inside circuits proven over BLS12-381.
+/// For more info, see section 5.4.9.3 of the Zcash protocol specification
+/// (https://zips.z.cash/protocol/protocol.pdf).
+#[derive(Clone, Debug)]
+pub struct JubjubCurve;
+
+impl IsEllipticCurve for JubjubCurve {
+    type BaseField = BLS12381FrField;
+    type PointRepresentation = EdwardsExtendedPoint<Self>;
+
+    /// Returns the generator of the prime order subgroup used by the `jubjub` crate.
+    fn generator() -> Self::PointRepresentation {
+        let x = BLS12381FrElement::from_hex_unchecked(
+            "11dafe5d23e1218086a365b99fbf3d3be72f6afd7d1f72623e6b071492d1122b",
+        );
+        let y = BLS12381FrElement::from_hex_unchecked(
+            "1d523cf1ddab1a1793132e78c866c0c33e26ba5cc220fed7cc3f870e59d292aa",
+        );
+        let t = &x * &y;
+        Self::PointRepresentation::new([x, y, FieldElement::one(), t])
+    }
+}
+
+impl IsEdwards for JubjubCurve {
+    fn a() -> FieldElement<Self::BaseField> {
+        -FieldElement::one()
+    }
+
+    fn d() -> FieldElement<Self::BaseField> {
+        BLS12381FrElement::from_hex_unchecked(
+            "2a9318e74bfa2b48f5fd9207e6bd7fd4292d7f6d37579d2601065fd6d6343eb1",
+        )
+    }
+}
+
+impl HasCofactor for EdwardsExtendedPoint<JubjubCurve> {
+    /// Checks that `s * P` is the neutral element.
+    fn is_in_subgroup(&self) -> bool {
+        self.operate_with_self(FrConfig::MODULUS)
+            .is_neutral_element()
+    }
+
+    /// Multiplies `self` by the cofactor 8.
+    fn clear_cofactor(&self) -> Self {
+        self.double().double().double()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generator_satisfies_defining_equation() {
+        let g = JubjubCurve::generator();
+        assert_eq!(
+            JubjubCurve::defining_equation(g.x(), g.y()),
+            FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn generator_has_order_s() {
+        let g = JubjubCurve::generator();
+        assert!(g.is_in_subgroup());
+        assert!(!g.is_neutral_element());
+    }
+
+    #[test]
+    fn points_outside_the_subgroup_are_detected() {
+        let point_of_order_two =
+            JubjubCurve::create_point_from_affine(FieldElement::zero(), -FieldElement::one())
+                .unwrap();
+        let p = JubjubCurve::generator().operate_with(&point_of_order_two);
+        assert!(!p.is_in_subgroup());
+        assert!(p
+            .operate_with_self(FrConfig::MODULUS)
+            .operate_with_self(JUBJUB_COFACTOR)
+            .is_neutral_element());
+        assert!(p.clear_cofactor().is_in_subgroup());
+    }
+}
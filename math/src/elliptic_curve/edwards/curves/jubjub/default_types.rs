@@ -0,0 +1,22 @@
+use crate::{
+    field::{
+        element::FieldElement,
+        fields::montgomery_backed_prime_fields::{IsModulus, MontgomeryBackendPrimeField},
+    },
+    unsigned_integer::element::U256,
+};
+
+#[derive(Clone, Debug)]
+pub struct FrConfig;
+
+/// Order of the prime order subgroup of Jubjub
+impl IsModulus<U256> for FrConfig {
+    const MODULUS: U256 = U256::from_hex_unchecked(
+        "0e7db4ea6533afa906673b0101343b00a6682093ccc81082d0970e5ed6f72cb7",
+    );
+}
+
+/// FrField using MontgomeryBackend for Jubjub
+pub type FrField = MontgomeryBackendPrimeField<FrConfig, 4>;
+/// FrElement using MontgomeryBackend for Jubjub
+pub type FrElement = FieldElement<FrField>;
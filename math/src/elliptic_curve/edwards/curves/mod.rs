@@ -1 +1,5 @@
+pub mod bandersnatch;
+pub mod ed25519;
+pub mod ed448;
+pub mod jubjub;
 pub mod tiny_jub_jub;
@@ -1,6 +1,8 @@
 use crate::{
     elliptic_curve::{
+        birational::HasMontgomeryModel,
         edwards::{point::EdwardsProjectivePoint, traits::IsEdwards},
+        montgomery::curves::tiny_jub_jub::TinyJubJubMontgomery,
         traits::IsEllipticCurve,
     },
     field::{element::FieldElement, fields::u64_prime_field::U64PrimeField},
@@ -32,3 +34,7 @@ impl IsEdwards for TinyJubJubEdwards {
         FieldElement::from(8)
     }
 }
+
+impl HasMontgomeryModel for TinyJubJubEdwards {
+    type Montgomery = TinyJubJubMontgomery;
+}
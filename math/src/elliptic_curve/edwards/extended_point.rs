@@ -0,0 +1,225 @@
+use crate::{
+    cyclic_group::IsGroup,
+    elliptic_curve::traits::{EllipticCurveError, FromAffine, ToAffine},
+    field::element::FieldElement,
+    unsigned_integer::traits::IsUnsignedInteger,
+};
+
+use super::traits::IsEdwards;
+
+/// A point of a twisted Edwards curve in extended coordinates [X: Y: Z: T],
+/// which represent the affine point (X / Z, Y / Z) with T = X * Y / Z.
+/// Additions and doublings need no inversions and, when `a` is a square and `d`
+/// is not, the addition formulas are complete.
+/// For more info, see "Twisted Edwards Curves Revisited" by Hisil, Wong, Carter and Dawson
+/// (https://eprint.iacr.org/2008/522).
+#[derive(Clone, Debug)]
+pub struct EdwardsExtendedPoint<E: IsEdwards> {
+    coordinates: [FieldElement<E::BaseField>; 4],
+}
+
+impl<E: IsEdwards> EdwardsExtendedPoint<E> {
+    /// Creates an elliptic curve point giving the extended [X: Y: Z: T] coordinates,
+    /// which must satisfy X * Y = Z * T.
+    pub fn new(coordinates: [FieldElement<E::BaseField>; 4]) -> Self {
+        Self { coordinates }
+    }
+
+    /// Returns the `X` coordinate of the point.
+    pub fn x(&self) -> &FieldElement<E::BaseField> {
+        &self.coordinates[0]
+    }
+
+    /// Returns the `Y` coordinate of the point.
+    pub fn y(&self) -> &FieldElement<E::BaseField> {
+        &self.coordinates[1]
+    }
+
+    /// Returns the `Z` coordinate of the point.
+    pub fn z(&self) -> &FieldElement<E::BaseField> {
+        &self.coordinates[2]
+    }
+
+    /// Returns the `T` coordinate of the point.
+    pub fn t(&self) -> &FieldElement<E::BaseField> {
+        &self.coordinates[3]
+    }
+
+    /// Returns a tuple [X, Y, Z, T] with the coordinates of the point.
+    pub fn coordinates(&self) -> &[FieldElement<E::BaseField>; 4] {
+        &self.coordinates
+    }
+
+    /// Creates the same point in affine coordinates. That is,
+    /// returns [X / Z: Y / Z: 1: T / Z] where `self` is [X: Y: Z: T].
+    pub fn to_affine(&self) -> Self {
+        let [x, y, z, t] = self.coordinates();
+        let z_inv = z.inv();
+        Self::new([x * &z_inv, y * &z_inv, FieldElement::one(), t * z_inv])
+    }
+
+    /// Returns `2 * self`, with the dedicated doubling formulas.
+    /// See https://hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#doubling-dbl-2008-hwcd
+    pub fn double(&self) -> Self {
+        let [x1, y1, z1, _] = self.coordinates();
+        let a = x1.square();
+        let b = y1.square();
+        let z1_squared = z1.square();
+        let c = &z1_squared + &z1_squared;
+        let d = E::a() * &a;
+        let e = (x1 + y1).square() - &a - &b;
+        let g = &d + &b;
+        let f = &g - c;
+        let h = d - b;
+        Self::new([&e * &f, &g * &h, f * g, e * h])
+    }
+}
+
+impl<E: IsEdwards> PartialEq for EdwardsExtendedPoint<E> {
+    fn eq(&self, other: &Self) -> bool {
+        let [x1, y1, z1, _] = self.coordinates();
+        let [x2, y2, z2, _] = other.coordinates();
+        x1 * z2 == x2 * z1 && y1 * z2 == y2 * z1
+    }
+}
+
+impl<E: IsEdwards> Eq for EdwardsExtendedPoint<E> {}
+
+impl<E: IsEdwards> FromAffine<E::BaseField> for EdwardsExtendedPoint<E> {
+    fn from_affine(
+        x: FieldElement<E::BaseField>,
+        y: FieldElement<E::BaseField>,
+    ) -> Result<Self, EllipticCurveError> {
+        if E::defining_equation(&x, &y) != FieldElement::zero() {
+            Err(EllipticCurveError::InvalidPoint)
+        } else {
+            let t = &x * &y;
+            Ok(Self::new([x, y, FieldElement::one(), t]))
+        }
+    }
+}
+
+impl<E: IsEdwards> ToAffine<E::BaseField> for EdwardsExtendedPoint<E> {
+    /// Edwards curves have no points at infinity, so this always returns the coordinates.
+    fn to_affine_coordinates(&self) -> Option<[FieldElement<E::BaseField>; 2]> {
+        let z_inv = self.z().inv();
+        Some([self.x() * &z_inv, self.y() * z_inv])
+    }
+}
+
+impl<E: IsEdwards> IsGroup for EdwardsExtendedPoint<E> {
+    /// The point (0, 1).
+    fn neutral_element() -> Self {
+        Self::new([
+            FieldElement::zero(),
+            FieldElement::one(),
+            FieldElement::one(),
+            FieldElement::zero(),
+        ])
+    }
+
+    fn is_neutral_element(&self) -> bool {
+        let [x, y, z, _] = self.coordinates();
+        x == &FieldElement::zero() && y == z
+    }
+
+    /// Computes the addition of `self` and `other`, with the unified formulas,
+    /// which also work for doublings.
+    /// See https://hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#addition-add-2008-hwcd
+    fn operate_with(&self, other: &Self) -> Self {
+        let [x1, y1, z1, t1] = self.coordinates();
+        let [x2, y2, z2, t2] = other.coordinates();
+        let a = x1 * x2;
+        let b = y1 * y2;
+        let c = E::d() * t1 * t2;
+        let d = z1 * z2;
+        let e = (x1 + y1) * (x2 + y2) - &a - &b;
+        let f = &d - &c;
+        let g = d + c;
+        let h = b - E::a() * a;
+        Self::new([&e * &f, &g * &h, f * g, e * h])
+    }
+
+    /// Double and add, with the dedicated doubling formulas.
+    fn operate_with_self<T: IsUnsignedInteger>(&self, mut exponent: T) -> Self {
+        let mut result = Self::neutral_element();
+        let mut base = self.clone();
+
+        while exponent != T::from(0) {
+            if exponent & T::from(1) == T::from(1) {
+                result = result.operate_with(&base);
+            }
+            exponent = exponent >> 1;
+            base = base.double();
+        }
+        result
+    }
+
+    /// Returns the additive inverse of the point, [-X: Y: Z: -T].
+    fn neg(&self) -> Self {
+        let [x, y, z, t] = self.coordinates();
+        Self::new([-x, y.clone(), z.clone(), -t])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::{
+        edwards::curves::tiny_jub_jub::TinyJubJubEdwards, traits::IsEllipticCurve,
+    };
+
+    type TinyJubJubExtendedPoint = EdwardsExtendedPoint<TinyJubJubEdwards>;
+
+    fn create_point(x: u64, y: u64) -> TinyJubJubExtendedPoint {
+        TinyJubJubExtendedPoint::from_affine(FieldElement::from(x), FieldElement::from(y)).unwrap()
+    }
+
+    #[test]
+    fn create_invalid_point_returns_invalid_point_error() {
+        let result =
+            TinyJubJubExtendedPoint::from_affine(FieldElement::from(5), FieldElement::from(4));
+        assert_eq!(result.unwrap_err(), EllipticCurveError::InvalidPoint);
+    }
+
+    #[test]
+    fn operations_agree_with_the_projective_representation() {
+        let g = create_point(12, 11);
+        let h = TinyJubJubEdwards::create_point_from_affine(
+            FieldElement::from(12),
+            FieldElement::from(11),
+        )
+        .unwrap();
+        for k in 0..20_u16 {
+            let expected = h.operate_with_self(k).to_affine();
+            let point = g.operate_with_self(k).to_affine();
+            assert_eq!(point.x(), expected.x());
+            assert_eq!(point.y(), expected.y());
+            assert_eq!(point.t(), &(expected.x() * expected.y()));
+        }
+    }
+
+    #[test]
+    fn double_agrees_with_addition() {
+        let g = create_point(12, 11);
+        let p = g.operate_with_self(7_u16);
+        assert_eq!(p.double(), p.operate_with(&p));
+    }
+
+    #[test]
+    fn operating_with_the_negation_gives_the_neutral_element() {
+        let p = create_point(5, 5);
+        assert_eq!(p.neg(), create_point(13 - 5, 5));
+        assert!(p.operate_with(&p.neg()).is_neutral_element());
+    }
+
+    #[test]
+    fn neutral_element_is_the_identity() {
+        let p = create_point(12, 11);
+        assert_eq!(
+            p.operate_with(&TinyJubJubExtendedPoint::neutral_element()),
+            p
+        );
+        assert!(p.operate_with_self(20_u16).is_neutral_element());
+    }
+}
@@ -1,3 +1,4 @@
 pub mod curves;
+pub mod extended_point;
 pub mod point;
 pub mod traits;
@@ -2,7 +2,7 @@ use crate::{
     cyclic_group::IsGroup,
     elliptic_curve::{
         point::ProjectivePoint,
-        traits::{EllipticCurveError, FromAffine, IsEllipticCurve},
+        traits::{EllipticCurveError, FromAffine, IsEllipticCurve, ToAffine},
     },
     field::element::FieldElement,
 };
@@ -68,6 +68,14 @@ impl<E: IsEdwards> FromAffine<E::BaseField> for EdwardsProjectivePoint<E> {
 
 impl<E: IsEllipticCurve> Eq for EdwardsProjectivePoint<E> {}
 
+impl<E: IsEdwards> ToAffine<E::BaseField> for EdwardsProjectivePoint<E> {
+    /// Edwards curves have no points at infinity, so this always returns the coordinates.
+    fn to_affine_coordinates(&self) -> Option<[FieldElement<E::BaseField>; 2]> {
+        let z_inv = self.z().inv();
+        Some([self.x() * &z_inv, self.y() * z_inv])
+    }
+}
+
 impl<E: IsEdwards> IsGroup for EdwardsProjectivePoint<E> {
     /// The point at infinity.
     fn neutral_element() -> Self {
@@ -0,0 +1,125 @@
+use crate::cyclic_group::IsGroup;
+use crate::unsigned_integer::element::UnsignedInteger;
+
+/// Precomputed multiples of a fixed base, for repeated scalar multiplications by it,
+/// such as by the generator of a curve in commitments or signatures.
+/// The scalar is split in windows of `window_size` bits and the table stores
+/// `j * 2^(window_size * i) * base` for every window `i` and every digit `j`,
+/// so a multiplication needs one addition per window and no doublings.
+/// The table holds `ceil(num_bits / window_size) * (2^window_size - 1)` elements.
+///
+/// ```
+/// use lambdaworks_math::elliptic_curve::fixed_base::FixedBaseTable;
+/// use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve;
+/// use lambdaworks_math::elliptic_curve::traits::IsEllipticCurve;
+/// use lambdaworks_math::unsigned_integer::element::U256;
+///
+/// let table = FixedBaseTable::new(&BLS12381Curve::generator(), 255, 8);
+/// let point = table.mul(&U256::from_u64(42));
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable<G: IsGroup> {
+    window_size: usize,
+    num_bits: usize,
+    windows: Vec<Vec<G>>,
+}
+
+impl<G: IsGroup> FixedBaseTable<G> {
+    /// Builds the table for scalars of at most `num_bits` bits.
+    /// `window_size` must be between 1 and 16.
+    pub fn new(base: &G, num_bits: usize, window_size: usize) -> Self {
+        debug_assert!((1..=16).contains(&window_size));
+        let num_windows = num_bits.div_ceil(window_size);
+        let mut windows = Vec::with_capacity(num_windows);
+        let mut window_base = base.clone();
+        for _ in 0..num_windows {
+            let mut multiples = Vec::with_capacity((1 << window_size) - 1);
+            multiples.push(window_base.clone());
+            for j in 1..(1 << window_size) - 1 {
+                let next = multiples[j - 1].operate_with(&window_base);
+                multiples.push(next);
+            }
+            // The base of the next window is 2^window_size * window_base.
+            window_base = multiples[multiples.len() - 1].operate_with(&window_base);
+            windows.push(multiples);
+        }
+        Self {
+            window_size,
+            num_bits,
+            windows,
+        }
+    }
+
+    /// Maximum number of bits of the scalars supported by the table.
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    /// Computes `k * base`.
+    /// # Panics
+    /// Panics if `k` has more than `num_bits` bits.
+    pub fn mul<const NUM_LIMBS: usize>(&self, k: &UnsignedInteger<NUM_LIMBS>) -> G {
+        assert!(
+            k.bits() as usize <= self.num_bits,
+            "The scalar has more bits than the table supports"
+        );
+        let mut result = G::neutral_element();
+        for (i, multiples) in self.windows.iter().enumerate() {
+            let digit = (0..self.window_size).fold(0usize, |acc, j| {
+                acc | ((k.bit(i * self.window_size + j) as usize) << j)
+            });
+            if digit != 0 {
+                result = result.operate_with(&multiples[digit - 1]);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::{
+        short_weierstrass::curves::bls12_381::{curve::BLS12381Curve, twist::BLS12381TwistCurve},
+        traits::IsEllipticCurve,
+    };
+    use crate::unsigned_integer::element::U256;
+
+    fn scalars() -> [U256; 4] {
+        [
+            U256::from_u64(0),
+            U256::from_u64(5),
+            U256::from_u64(u64::MAX),
+            U256::from_hex_unchecked(
+                "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000",
+            ),
+        ]
+    }
+
+    #[test]
+    fn fixed_base_mul_matches_operate_with_self_in_g1() {
+        let g = BLS12381Curve::generator();
+        for window_size in [1, 3, 4, 8] {
+            let table = FixedBaseTable::new(&g, 255, window_size);
+            for k in scalars() {
+                assert_eq!(table.mul(&k), g.operate_with_self(k));
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_base_mul_matches_operate_with_self_in_g2() {
+        let g = BLS12381TwistCurve::generator();
+        let table = FixedBaseTable::new(&g, 255, 4);
+        for k in scalars() {
+            assert_eq!(table.mul(&k), g.operate_with_self(k));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn fixed_base_mul_panics_with_scalars_larger_than_the_table() {
+        let table = FixedBaseTable::new(&BLS12381Curve::generator(), 64, 4);
+        table.mul(&U256::from_u128(1 << 64));
+    }
+}
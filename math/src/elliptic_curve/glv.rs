@@ -0,0 +1,165 @@
+use crate::cyclic_group::IsGroup;
+use crate::unsigned_integer::element::UnsignedInteger;
+
+/// Integer given by its sign and its absolute value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignedInteger<const NUM_LIMBS: usize> {
+    pub is_negative: bool,
+    pub abs: UnsignedInteger<NUM_LIMBS>,
+}
+
+impl<const NUM_LIMBS: usize> SignedInteger<NUM_LIMBS> {
+    pub const fn positive(abs: UnsignedInteger<NUM_LIMBS>) -> Self {
+        Self {
+            is_negative: false,
+            abs,
+        }
+    }
+
+    pub const fn negative(abs: UnsignedInteger<NUM_LIMBS>) -> Self {
+        Self {
+            is_negative: true,
+            abs,
+        }
+    }
+
+    /// Returns the two's complement representation of `self` modulo `2^(64 * NUM_LIMBS)`.
+    fn to_twos_complement(self) -> UnsignedInteger<NUM_LIMBS> {
+        if self.is_negative {
+            wrapping_neg(&self.abs)
+        } else {
+            self.abs
+        }
+    }
+
+    /// Inverse of `to_twos_complement`, for values whose absolute value is smaller
+    /// than `2^(64 * NUM_LIMBS - 1)`.
+    fn from_twos_complement(value: UnsignedInteger<NUM_LIMBS>) -> Self {
+        if value.limbs[0] >> 63 == 1 {
+            Self::negative(wrapping_neg(&value))
+        } else {
+            Self::positive(value)
+        }
+    }
+}
+
+fn wrapping_neg<const NUM_LIMBS: usize>(
+    value: &UnsignedInteger<NUM_LIMBS>,
+) -> UnsignedInteger<NUM_LIMBS> {
+    UnsignedInteger::sub(&UnsignedInteger::from_u64(0), value).0
+}
+
+fn wrapping_mul<const NUM_LIMBS: usize>(
+    a: &UnsignedInteger<NUM_LIMBS>,
+    b: &UnsignedInteger<NUM_LIMBS>,
+) -> UnsignedInteger<NUM_LIMBS> {
+    UnsignedInteger::mul(a, b).1
+}
+
+fn wrapping_sub<const NUM_LIMBS: usize>(
+    a: &UnsignedInteger<NUM_LIMBS>,
+    b: &UnsignedInteger<NUM_LIMBS>,
+) -> UnsignedInteger<NUM_LIMBS> {
+    UnsignedInteger::sub(a, b).0
+}
+
+/// Reduced basis `{(a1, b1), (a2, b2)}` of the lattice of pairs `(a, b)` such that
+/// `a + b * lambda = 0` modulo `order`, where `lambda` is the eigenvalue of an endomorphism.
+/// Scalars are split by rounding `(k, 0)` to a close lattice vector, as in Babai's method.
+/// To avoid divisions, the rounding uses the precomputed values
+/// `g1 = round(b2 * 2^(64 * NUM_LIMBS) / order)` and `g2 = round(-b1 * 2^(64 * NUM_LIMBS) / order)`.
+/// For more info, see https://www.iacr.org/archive/crypto2001/21390189.pdf
+#[derive(Clone, Debug)]
+pub struct GlvLattice<const NUM_LIMBS: usize> {
+    pub order: UnsignedInteger<NUM_LIMBS>,
+    pub v1: [SignedInteger<NUM_LIMBS>; 2],
+    pub v2: [SignedInteger<NUM_LIMBS>; 2],
+    pub g1: SignedInteger<NUM_LIMBS>,
+    pub g2: SignedInteger<NUM_LIMBS>,
+}
+
+impl<const NUM_LIMBS: usize> GlvLattice<NUM_LIMBS> {
+    /// Splits `k` into `(k1, k2)` with `k = k1 + k2 * lambda` modulo `order`, where `k1` and
+    /// `k2` have about half the bits of `order`.
+    pub fn decompose(
+        &self,
+        k: &UnsignedInteger<NUM_LIMBS>,
+    ) -> (SignedInteger<NUM_LIMBS>, SignedInteger<NUM_LIMBS>) {
+        let mut k = *k;
+        while k >= self.order {
+            k = k - self.order;
+        }
+
+        let c1 = Self::round_product(&k, &self.g1);
+        let c2 = Self::round_product(&k, &self.g2);
+        let [a1, b1] = self.v1.map(SignedInteger::to_twos_complement);
+        let [a2, b2] = self.v2.map(SignedInteger::to_twos_complement);
+
+        // (k1, k2) = (k, 0) - c1 * v1 - c2 * v2
+        let k1 = wrapping_sub(
+            &wrapping_sub(&k, &wrapping_mul(&c1, &a1)),
+            &wrapping_mul(&c2, &a2),
+        );
+        let k2 = wrapping_sub(
+            &wrapping_neg(&wrapping_mul(&c1, &b1)),
+            &wrapping_mul(&c2, &b2),
+        );
+        (
+            SignedInteger::from_twos_complement(k1),
+            SignedInteger::from_twos_complement(k2),
+        )
+    }
+
+    /// Returns `round(k * g / 2^(64 * NUM_LIMBS))` in two's complement.
+    fn round_product(
+        k: &UnsignedInteger<NUM_LIMBS>,
+        g: &SignedInteger<NUM_LIMBS>,
+    ) -> UnsignedInteger<NUM_LIMBS> {
+        let (hi, lo) = UnsignedInteger::mul(k, &g.abs);
+        let rounded = if lo.limbs[0] >> 63 == 1 {
+            hi + UnsignedInteger::from_u64(1)
+        } else {
+            hi
+        };
+        if g.is_negative {
+            wrapping_neg(&rounded)
+        } else {
+            rounded
+        }
+    }
+}
+
+/// Computes `k * point` as `k1 * point + k2 * endomorphism(point)` with a joint
+/// double-and-add, where `(k1, k2)` is the decomposition of `k` given by `lattice`.
+pub fn glv_mul<G: IsGroup, const NUM_LIMBS: usize>(
+    point: &G,
+    endomorphism: &G,
+    lattice: &GlvLattice<NUM_LIMBS>,
+    k: &UnsignedInteger<NUM_LIMBS>,
+) -> G {
+    let (k1, k2) = lattice.decompose(k);
+    let p1 = if k1.is_negative {
+        point.neg()
+    } else {
+        point.clone()
+    };
+    let p2 = if k2.is_negative {
+        endomorphism.neg()
+    } else {
+        endomorphism.clone()
+    };
+    let p1_plus_p2 = p1.operate_with(&p2);
+
+    let bits = k1.abs.bits().max(k2.abs.bits()) as usize;
+    let mut result = G::neutral_element();
+    for i in (0..bits).rev() {
+        result = result.operate_with(&result);
+        match (k1.abs.bit(i), k2.abs.bit(i)) {
+            (true, true) => result = result.operate_with(&p1_plus_p2),
+            (true, false) => result = result.operate_with(&p1),
+            (false, true) => result = result.operate_with(&p2),
+            (false, false) => {}
+        }
+    }
+    result
+}
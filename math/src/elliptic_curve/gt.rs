@@ -0,0 +1,211 @@
+use crate::cyclic_group::IsGroup;
+use crate::elliptic_curve::short_weierstrass::errors::DeserializationError;
+use crate::elliptic_curve::traits::{HasTargetGroup, IsPairing};
+use crate::field::element::FieldElement;
+use crate::traits::{Deserializable, Serializable};
+use crate::unsigned_integer::traits::IsUnsignedInteger;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+/// An element of the target group GT of the pairing `P`.
+/// The group operation is the product in the output field, so `neg` is
+/// the inverse and `operate_with_self` is the exponentiation.
+pub struct Gt<P: IsPairing> {
+    value: FieldElement<P::OutputField>,
+    phantom: PhantomData<P>,
+}
+
+impl<P: HasTargetGroup> Gt<P> {
+    /// Returns the element with the given value, or `None` if it is not in GT.
+    pub fn new(value: FieldElement<P::OutputField>) -> Option<Self> {
+        if P::is_in_target_group(&value) {
+            Some(Self::new_unchecked(value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the element with the given value, which must belong to GT.
+    pub fn new_unchecked(value: FieldElement<P::OutputField>) -> Self {
+        Self {
+            value,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the pairing of `p` and `q`.
+    pub fn pairing(p: &P::G1Point, q: &P::G2Point) -> Self {
+        Self::new_unchecked(P::compute(p, q))
+    }
+
+    /// Returns the product of the pairings of all the pairs.
+    pub fn pairing_batch(pairs: &[(&P::G1Point, &P::G2Point)]) -> Self {
+        Self::new_unchecked(P::compute_batch(pairs))
+    }
+
+    pub fn value(&self) -> &FieldElement<P::OutputField> {
+        &self.value
+    }
+
+    /// Returns `self^2`.
+    pub fn square(&self) -> Self {
+        Self::new_unchecked(P::cyclotomic_square(&self.value))
+    }
+}
+
+impl<P: IsPairing> Clone for Gt<P> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<P: IsPairing> Debug for Gt<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Gt").field("value", &self.value).finish()
+    }
+}
+
+impl<P: IsPairing> PartialEq for Gt<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<P: IsPairing> Eq for Gt<P> {}
+
+impl<P: HasTargetGroup> IsGroup for Gt<P> {
+    fn neutral_element() -> Self {
+        Self::new_unchecked(FieldElement::one())
+    }
+
+    fn operate_with(&self, other: &Self) -> Self {
+        Self::new_unchecked(&self.value * &other.value)
+    }
+
+    fn neg(&self) -> Self {
+        Self::new_unchecked(P::cyclotomic_inverse(&self.value))
+    }
+
+    /// Exponentiation by square and multiply with cyclotomic squarings.
+    fn operate_with_self<T: IsUnsignedInteger>(&self, mut exponent: T) -> Self {
+        let mut result = Self::neutral_element();
+        let mut base = self.clone();
+
+        while exponent != T::from(0) {
+            if exponent & T::from(1) == T::from(1) {
+                result = result.operate_with(&base);
+            }
+            exponent = exponent >> 1;
+            base = base.square();
+        }
+        result
+    }
+}
+
+impl<P: HasTargetGroup> Serializable for Gt<P> {
+    /// Serializes the element in its compressed form.
+    fn serialize(&self) -> Vec<u8> {
+        P::compress(&self.value)
+    }
+}
+
+impl<P: HasTargetGroup> Deserializable for Gt<P> {
+    /// Deserializes a compressed element, checking that it belongs to GT.
+    fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        if bytes.len() != P::COMPRESSED_SIZE {
+            return Err(DeserializationError::InvalidAmountOfBytes);
+        }
+        let value = P::decompress(bytes)?;
+        Self::new(value).ok_or(DeserializationError::NotInSubgroup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::{
+        short_weierstrass::curves::bls12_381::{
+            curve::BLS12381Curve, default_types::FrConfig, pairing::BLS12381AtePairing,
+            twist::BLS12381TwistCurve,
+        },
+        traits::IsEllipticCurve,
+    };
+    use crate::field::fields::montgomery_backed_prime_fields::IsModulus;
+
+    type BLS12381Gt = Gt<BLS12381AtePairing>;
+
+    fn gt_element() -> BLS12381Gt {
+        BLS12381Gt::pairing(
+            &BLS12381Curve::generator().operate_with_self(5u64),
+            &BLS12381TwistCurve::generator().operate_with_self(7u64),
+        )
+    }
+
+    #[test]
+    fn pairing_outputs_are_in_the_target_group() {
+        let a = gt_element();
+        assert!(BLS12381Gt::new(a.value().clone()).is_some());
+    }
+
+    #[test]
+    fn elements_outside_the_target_group_are_rejected() {
+        let value = gt_element().value() + FieldElement::one();
+        assert!(BLS12381Gt::new(value).is_none());
+    }
+
+    #[test]
+    fn exponentiation_is_compatible_with_the_pairing() {
+        let p = BLS12381Curve::generator();
+        let q = BLS12381TwistCurve::generator();
+        let e = BLS12381Gt::pairing(&p, &q);
+        assert_eq!(
+            BLS12381Gt::pairing(&p.operate_with_self(6u64), &q.operate_with_self(11u64)),
+            e.operate_with_self(66u64)
+        );
+    }
+
+    #[test]
+    fn operating_with_the_negation_gives_the_neutral_element() {
+        let a = gt_element();
+        assert_eq!(a.operate_with(&a.neg()), BLS12381Gt::neutral_element());
+    }
+
+    #[test]
+    fn elements_have_order_r() {
+        assert_eq!(
+            gt_element().operate_with_self(FrConfig::MODULUS),
+            BLS12381Gt::neutral_element()
+        );
+    }
+
+    #[test]
+    fn serialization_roundtrip() {
+        for a in [gt_element(), BLS12381Gt::neutral_element()] {
+            let bytes = a.serialize();
+            assert_eq!(bytes.len(), BLS12381AtePairing::COMPRESSED_SIZE);
+            assert_eq!(BLS12381Gt::deserialize(&bytes).unwrap(), a);
+        }
+    }
+
+    #[test]
+    fn deserialize_rejects_a_wrong_amount_of_bytes() {
+        let bytes = gt_element().serialize();
+        assert_eq!(
+            BLS12381Gt::deserialize(&bytes[1..]),
+            Err(DeserializationError::InvalidAmountOfBytes)
+        );
+    }
+
+    #[test]
+    fn deserialize_rejects_elements_outside_the_target_group() {
+        let mut bytes = gt_element().serialize();
+        bytes[47] ^= 1;
+        assert_eq!(
+            BLS12381Gt::deserialize(&bytes),
+            Err(DeserializationError::NotInSubgroup)
+        );
+    }
+}
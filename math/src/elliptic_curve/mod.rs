@@ -1,6 +1,11 @@
+pub mod birational;
 pub mod edwards;
+pub mod fixed_base;
+pub mod glv;
+pub mod gt;
 pub mod montgomery;
 /// Implementation of ProjectivePoint, a generic projective point in a curve.
 pub mod point;
 pub mod short_weierstrass;
 pub mod traits;
+pub mod wnaf;
@@ -0,0 +1,85 @@
+use crate::{
+    elliptic_curve::{
+        birational::HasWeierstrassModel,
+        edwards::curves::ed25519::curve::{Ed25519FieldElement, Ed25519PrimeField},
+        montgomery::{point::MontgomeryProjectivePoint, traits::IsMontgomery},
+        short_weierstrass::curves::wei25519::Wei25519Curve,
+        traits::IsEllipticCurve,
+    },
+    field::element::FieldElement,
+};
+
+/// The Montgomery curve `v^2 = u^3 + 486662 * u^2 + u` over the field of `2^255 - 19`
+/// elements, used by the X25519 key agreement. It is birationally equivalent to
+/// edwards25519.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc7748#section-4.1
+#[derive(Clone, Debug)]
+pub struct Curve25519;
+
+impl IsEllipticCurve for Curve25519 {
+    type BaseField = Ed25519PrimeField;
+    type PointRepresentation = MontgomeryProjectivePoint<Self>;
+
+    /// Returns the base point, with `u = 9`, of order
+    /// `2^252 + 27742317777372353535851937790883648493`.
+    fn generator() -> Self::PointRepresentation {
+        Self::PointRepresentation::new([
+            FieldElement::from(9),
+            Ed25519FieldElement::from_hex_unchecked(
+                "20ae19a1b8a086b4e01edd2c7748d14c923d4d7e6d7c61b229e9c5a27eced3d9",
+            ),
+            FieldElement::one(),
+        ])
+    }
+}
+
+impl IsMontgomery for Curve25519 {
+    fn a() -> FieldElement<Self::BaseField> {
+        FieldElement::from(486662)
+    }
+
+    fn b() -> FieldElement<Self::BaseField> {
+        FieldElement::one()
+    }
+}
+
+impl HasWeierstrassModel for Curve25519 {
+    type Weierstrass = Wei25519Curve;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cyclic_group::IsGroup, elliptic_curve::edwards::curves::ed25519::default_types::FrConfig,
+        field::fields::montgomery_backed_prime_fields::IsModulus, unsigned_integer::element::U256,
+    };
+
+    #[test]
+    fn generator_satisfies_defining_equation() {
+        let g = Curve25519::generator();
+        assert_eq!(
+            Curve25519::defining_equation(g.x(), g.y()),
+            FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn generator_has_order_l() {
+        let g = Curve25519::generator();
+        assert_eq!(g.x_only_operate_with_self(FrConfig::MODULUS, 253), None);
+        assert!(g.operate_with_self(FrConfig::MODULUS).is_neutral_element());
+    }
+
+    #[test]
+    fn x_only_ladder_matches_scalar_multiplication() {
+        let g = Curve25519::generator();
+        let k = U256::from_hex_unchecked(
+            "0a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac",
+        );
+        assert_eq!(
+            g.x_only_operate_with_self(k, 256).as_ref(),
+            Some(g.operate_with_self(k).to_affine().x())
+        );
+    }
+}
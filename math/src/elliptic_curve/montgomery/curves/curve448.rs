@@ -0,0 +1,69 @@
+use crate::{
+    elliptic_curve::{
+        montgomery::{point::MontgomeryProjectivePoint, traits::IsMontgomery},
+        traits::IsEllipticCurve,
+    },
+    field::{
+        element::FieldElement,
+        fields::p448_goldilocks_prime_field::{P448GoldilocksPrimeField, U56x8},
+    },
+};
+
+/// The Montgomery curve `v^2 = u^3 + 156326 * u^2 + u` over the field of
+/// `2^448 - 2^224 - 1` elements, used by the X448 key agreement. It is 4-isogenous
+/// to edwards448.
+/// For more info, see https://www.rfc-editor.org/rfc/rfc7748#section-4.2
+#[derive(Clone, Debug)]
+pub struct Curve448;
+
+impl IsEllipticCurve for Curve448 {
+    type BaseField = P448GoldilocksPrimeField;
+    type PointRepresentation = MontgomeryProjectivePoint<Self>;
+
+    /// Returns the base point, with `u = 5`, of order
+    /// `2^446 - 13818066809895115352007386748515426880336692474882178609894547503885`.
+    fn generator() -> Self::PointRepresentation {
+        Self::PointRepresentation::new([
+            FieldElement::from(5),
+            FieldElement::new(U56x8::from(
+                "7d235d1295f5b1f66c98ab6e58326fcecbae5d34f55545d060f75dc28df3f6edb8027e2346430d211312c4b150677af76fd7223d457b5b1a",
+            )),
+            FieldElement::one(),
+        ])
+    }
+}
+
+impl IsMontgomery for Curve448 {
+    fn a() -> FieldElement<Self::BaseField> {
+        FieldElement::from(156326)
+    }
+
+    fn b() -> FieldElement<Self::BaseField> {
+        FieldElement::one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elliptic_curve::edwards::curves::ed448::default_types::FrConfig,
+        field::fields::montgomery_backed_prime_fields::IsModulus,
+    };
+
+    #[test]
+    fn generator_satisfies_defining_equation() {
+        let g = Curve448::generator();
+        assert_eq!(
+            Curve448::defining_equation(g.x(), g.y()),
+            FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn generator_has_order_l() {
+        let g = Curve448::generator();
+        assert_eq!(g.x_only_operate_with_self(FrConfig::MODULUS, 446), None);
+        assert!(g.x_only_operate_with_self(FrConfig::MODULUS, 445).is_some());
+    }
+}
@@ -1 +1,3 @@
+pub mod curve25519;
+pub mod curve448;
 pub mod tiny_jub_jub;
@@ -1,6 +1,8 @@
 use crate::{
     elliptic_curve::{
+        birational::HasWeierstrassModel,
         montgomery::{point::MontgomeryProjectivePoint, traits::IsMontgomery},
+        short_weierstrass::curves::tiny_jub_jub::TinyJubJubWeierstrass,
         traits::IsEllipticCurve,
     },
     field::{element::FieldElement, fields::u64_prime_field::U64PrimeField},
@@ -32,3 +34,7 @@ impl IsMontgomery for TinyJubJubMontgomery {
         FieldElement::from(7)
     }
 }
+
+impl HasWeierstrassModel for TinyJubJubMontgomery {
+    type Weierstrass = TinyJubJubWeierstrass;
+}
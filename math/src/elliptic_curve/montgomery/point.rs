@@ -2,9 +2,10 @@ use crate::{
     cyclic_group::IsGroup,
     elliptic_curve::{
         point::ProjectivePoint,
-        traits::{EllipticCurveError, FromAffine, IsEllipticCurve},
+        traits::{EllipticCurveError, FromAffine, IsEllipticCurve, ToAffine},
     },
-    field::element::FieldElement,
+    field::{element::FieldElement, traits::HasConditionalSwap},
+    unsigned_integer::traits::IsUnsignedInteger,
 };
 
 use super::traits::IsMontgomery;
@@ -46,6 +47,78 @@ impl<E: IsEllipticCurve> MontgomeryProjectivePoint<E> {
     }
 }
 
+impl<E> MontgomeryProjectivePoint<E>
+where
+    E: IsMontgomery,
+    E::BaseField: HasConditionalSwap,
+{
+    /// Computes the x-coordinate of `scalar * P` from the x-coordinate `x` of `P` with
+    /// the x-only Montgomery ladder of RFC 7748, section 5, and returns it in projective
+    /// form `[X: Z]`, where `Z` is zero if the result is the point at infinity.
+    /// Only the `num_bits` least significant bits of `scalar` are used, and the same
+    /// sequence of field operations is performed for every scalar of that length.
+    /// If `x` is zero, the x-coordinate of the point `(0, 0)` of order two, either `X`
+    /// or `Z` is zero, so the result is encoded as zero as in RFC 7748.
+    pub fn x_only_ladder<T: IsUnsignedInteger>(
+        x: &FieldElement<E::BaseField>,
+        scalar: T,
+        num_bits: usize,
+    ) -> [FieldElement<E::BaseField>; 2] {
+        // (A - 2) / 4
+        let a24 = (E::a() - FieldElement::from(2)) * FieldElement::from(4).inv();
+        let (mut x2, mut z2) = (FieldElement::one(), FieldElement::zero());
+        let (mut x3, mut z3) = (x.clone(), FieldElement::one());
+        let mut swap = 0_u64;
+        for i in (0..num_bits).rev() {
+            let bit = u64::from((scalar >> i) & T::from(1) == T::from(1));
+            // Swaps the points if the bit differs from the previous one. The swap is done
+            // with a mask, so that there is no branch on the bits of the scalar.
+            let mask = 0_u64.wrapping_sub(swap ^ bit);
+            FieldElement::conditional_swap(mask, &mut x2, &mut x3);
+            FieldElement::conditional_swap(mask, &mut z2, &mut z3);
+            swap = bit;
+
+            let a = &x2 + &z2;
+            let b = &x2 - &z2;
+            let c = &x3 + &z3;
+            let d = &x3 - &z3;
+            let aa = a.square();
+            let bb = b.square();
+            let e = &aa - &bb;
+            let da = d * a;
+            let cb = c * b;
+            x3 = (&da + &cb).square();
+            z3 = x * (da - cb).square();
+            z2 = &e * (&aa + &a24 * &e);
+            x2 = aa * bb;
+        }
+        let mask = 0_u64.wrapping_sub(swap);
+        FieldElement::conditional_swap(mask, &mut x2, &mut x3);
+        FieldElement::conditional_swap(mask, &mut z2, &mut z3);
+        [x2, z2]
+    }
+
+    /// Returns the affine x-coordinate of `scalar * self`, computed with the x-only
+    /// Montgomery ladder over the `num_bits` least significant bits of `scalar`, or
+    /// `None` if the result is the point at infinity.
+    /// `self` must not be the point `(0, 0)` of order two.
+    pub fn x_only_operate_with_self<T: IsUnsignedInteger>(
+        &self,
+        scalar: T,
+        num_bits: usize,
+    ) -> Option<FieldElement<E::BaseField>> {
+        if self.is_neutral_element() {
+            return None;
+        }
+        let [x, z] = Self::x_only_ladder(self.to_affine().x(), scalar, num_bits);
+        if z == FieldElement::zero() {
+            None
+        } else {
+            Some(x / z)
+        }
+    }
+}
+
 impl<E: IsEllipticCurve> PartialEq for MontgomeryProjectivePoint<E> {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0
@@ -68,6 +141,17 @@ impl<E: IsMontgomery> FromAffine<E::BaseField> for MontgomeryProjectivePoint<E>
 
 impl<E: IsEllipticCurve> Eq for MontgomeryProjectivePoint<E> {}
 
+impl<E: IsMontgomery> ToAffine<E::BaseField> for MontgomeryProjectivePoint<E> {
+    fn to_affine_coordinates(&self) -> Option<[FieldElement<E::BaseField>; 2]> {
+        if self.is_neutral_element() {
+            None
+        } else {
+            let z_inv = self.z().inv();
+            Some([self.x() * &z_inv, self.y() * z_inv])
+        }
+    }
+}
+
 impl<E: IsMontgomery> IsGroup for MontgomeryProjectivePoint<E> {
     /// The point at infinity.
     fn neutral_element() -> Self {
@@ -236,4 +320,27 @@ mod tests {
             MontgomeryProjectivePoint::neutral_element()
         );
     }
+
+    #[test]
+    fn x_only_ladder_matches_scalar_multiplication_in_tiny_jub_jub() {
+        let g = create_point(9, 2);
+        for k in 0_u16..40 {
+            let expected = g.operate_with_self(k);
+            let x = g.x_only_operate_with_self(k, 6);
+            if expected.is_neutral_element() {
+                assert_eq!(x, None);
+            } else {
+                assert_eq!(x.as_ref(), Some(expected.to_affine().x()));
+            }
+        }
+    }
+
+    #[test]
+    fn x_only_ladder_only_uses_the_given_number_of_bits() {
+        let g = create_point(9, 2);
+        assert_eq!(
+            g.x_only_operate_with_self(0b1000011_u16, 3),
+            g.x_only_operate_with_self(3_u16, 3)
+        );
+    }
 }
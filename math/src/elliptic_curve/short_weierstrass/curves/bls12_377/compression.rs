@@ -0,0 +1,95 @@
+use super::curve::BLS12377Curve;
+use crate::elliptic_curve::short_weierstrass::{
+    errors::DeserializationError, point::ShortWeierstrassProjectivePoint, traits::Compress, zcash,
+};
+
+/// The base field elements take 377 bits, so the three most significant bits of
+/// a serialized point are free and used as flags, with the same layout as the
+/// Zcash encoding of BLS12-381 points.
+impl Compress for BLS12377Curve {
+    /// Encodes `point` in 48 bytes as the big-endian `x` coordinate with the
+    /// compression, infinity and sort flags in the most significant bits.
+    fn compress(point: &ShortWeierstrassProjectivePoint<Self>) -> Vec<u8> {
+        zcash::compress(point)
+    }
+
+    fn decompress(
+        bytes: &[u8],
+    ) -> Result<ShortWeierstrassProjectivePoint<Self>, DeserializationError> {
+        zcash::decompress(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cyclic_group::IsGroup,
+        elliptic_curve::{
+            short_weierstrass::{
+                point::{Endianness, PointFormat},
+                zcash::{COMPRESSION_FLAG, INFINITY_FLAG},
+            },
+            traits::IsEllipticCurve,
+        },
+    };
+
+    type G1Point = ShortWeierstrassProjectivePoint<BLS12377Curve>;
+
+    #[test]
+    fn compress_and_decompress_multiples_of_the_generator() {
+        let g = BLS12377Curve::generator();
+        for i in 1..10_u16 {
+            let p = g.operate_with_self(i);
+            let bytes = p.serialize_compressed();
+            assert_eq!(bytes.len(), 48);
+            let point = G1Point::deserialize_compressed(&bytes);
+            assert_eq!(point.unwrap(), p);
+        }
+    }
+
+    #[test]
+    fn compress_and_decompress_point_at_infinity() {
+        let bytes = BLS12377Curve::compress(&G1Point::neutral_element());
+        assert_eq!(bytes[0], COMPRESSION_FLAG | INFINITY_FLAG);
+        assert!(BLS12377Curve::decompress(&bytes)
+            .unwrap()
+            .is_neutral_element());
+    }
+
+    #[test]
+    fn serialize_and_deserialize_uncompressed_point() {
+        let p = BLS12377Curve::generator().operate_with_self(5_u16);
+        let bytes = p.serialize(PointFormat::Uncompressed, Endianness::LittleEndian);
+        assert_eq!(bytes.len(), 96);
+        let point =
+            G1Point::deserialize(&bytes, PointFormat::Uncompressed, Endianness::LittleEndian);
+        assert_eq!(point.unwrap(), p);
+    }
+
+    #[test]
+    fn serialize_and_deserialize_uncompressed_point_at_infinity() {
+        let bytes =
+            G1Point::neutral_element().serialize(PointFormat::Uncompressed, Endianness::BigEndian);
+        let mut expected = vec![0; 96];
+        expected[0] = INFINITY_FLAG;
+        assert_eq!(bytes, expected);
+        let point = G1Point::deserialize(&bytes, PointFormat::Uncompressed, Endianness::BigEndian);
+        assert!(point.unwrap().is_neutral_element());
+        assert_eq!(
+            G1Point::deserialize(&[0; 96], PointFormat::Uncompressed, Endianness::BigEndian)
+                .unwrap_err(),
+            DeserializationError::FieldFromBytesError
+        );
+    }
+
+    #[test]
+    fn decompress_without_compression_flag_fails() {
+        let mut bytes = BLS12377Curve::compress(&BLS12377Curve::generator());
+        bytes[0] &= !COMPRESSION_FLAG;
+        assert_eq!(
+            BLS12377Curve::decompress(&bytes).unwrap_err(),
+            DeserializationError::InvalidFlags
+        );
+    }
+}
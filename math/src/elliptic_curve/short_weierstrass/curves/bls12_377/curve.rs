@@ -23,6 +23,8 @@ impl IsEllipticCurve for BLS12377Curve {
 }
 
 impl IsShortWeierstrass for BLS12377Curve {
+    const HAS_ZCASH_FLAGS: bool = true;
+
     fn a() -> FieldElement<Self::BaseField> {
         FieldElement::from(0)
     }
@@ -1,5 +1,9 @@
 use crate::field::{
     element::FieldElement,
+    extensions::{
+        cubic::{CubicExtensionField, HasCubicNonResidue},
+        quadratic::{HasQuadraticNonResidue, QuadraticExtensionField},
+    },
     fields::montgomery_backed_prime_fields::{IsModulus, MontgomeryBackendPrimeField},
 };
 use crate::unsigned_integer::element::U384;
@@ -15,8 +19,114 @@ impl IsModulus<U384> for BLS12377FieldModulus {
 
 pub type BLS12377PrimeField = MontgomeryBackendPrimeField<BLS12377FieldModulus, 6>;
 
+//////////////////
+/// 𝔽p² = 𝔽p[u] / (u² + 5)
+#[derive(Debug, Clone)]
+pub struct LevelOneResidue;
+impl HasQuadraticNonResidue for LevelOneResidue {
+    type BaseField = BLS12377PrimeField;
+
+    fn residue() -> FieldElement<BLS12377PrimeField> {
+        -FieldElement::from(5)
+    }
+}
+
+pub type Degree2ExtensionField = QuadraticExtensionField<LevelOneResidue>;
+
+///////////////
+/// 𝔽p⁶ = 𝔽p²[v] / (v³ - u)
+#[derive(Debug, Clone)]
+pub struct LevelTwoResidue;
+impl HasCubicNonResidue for LevelTwoResidue {
+    type BaseField = Degree2ExtensionField;
+
+    fn residue() -> FieldElement<Degree2ExtensionField> {
+        FieldElement::new([FieldElement::zero(), FieldElement::one()])
+    }
+}
+
+pub type Degree6ExtensionField = CubicExtensionField<LevelTwoResidue>;
+
+/// 𝔽p¹² = 𝔽p⁶[w] / (w² - v)
+#[derive(Debug, Clone)]
+pub struct LevelThreeResidue;
+impl HasQuadraticNonResidue for LevelThreeResidue {
+    type BaseField = Degree6ExtensionField;
+
+    fn residue() -> FieldElement<Degree6ExtensionField> {
+        FieldElement::new([
+            FieldElement::zero(),
+            FieldElement::one(),
+            FieldElement::zero(),
+        ])
+    }
+}
+
+pub type Degree12ExtensionField = QuadraticExtensionField<LevelThreeResidue>;
+
 impl FieldElement<BLS12377PrimeField> {
     pub fn new_base(a_hex: &str) -> Self {
         Self::new(U384::from_hex_unchecked(a_hex))
     }
 }
+
+impl FieldElement<Degree2ExtensionField> {
+    pub fn new_base(a_hex: &str) -> Self {
+        Self::new([
+            FieldElement::new(U384::from_hex_unchecked(a_hex)),
+            FieldElement::zero(),
+        ])
+    }
+}
+
+impl FieldElement<Degree6ExtensionField> {
+    pub fn new_base(a_hex: &str) -> Self {
+        Self::new([
+            FieldElement::<Degree2ExtensionField>::new_base(a_hex),
+            FieldElement::zero(),
+            FieldElement::zero(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Fp2E = FieldElement<Degree2ExtensionField>;
+    type Fp6E = FieldElement<Degree6ExtensionField>;
+    type Fp12E = FieldElement<Degree12ExtensionField>;
+
+    #[test]
+    fn u_squared_is_minus_five() {
+        let u = Fp2E::new([FieldElement::zero(), FieldElement::one()]);
+        assert_eq!(u.square(), -Fp2E::from(5));
+    }
+
+    #[test]
+    fn v_cubed_is_u() {
+        let v = Fp6E::new([Fp2E::zero(), Fp2E::one(), Fp2E::zero()]);
+        assert_eq!(
+            v.pow(3_u64),
+            Fp6E::new([LevelTwoResidue::residue(), Fp2E::zero(), Fp2E::zero()])
+        );
+    }
+
+    #[test]
+    fn w_squared_is_v() {
+        let w = Fp12E::new([Fp6E::zero(), Fp6E::one()]);
+        assert_eq!(
+            w.square(),
+            Fp12E::new([LevelThreeResidue::residue(), Fp6E::zero()])
+        );
+    }
+
+    #[test]
+    fn inverse_in_degree_12_extension_works() {
+        let a = Fp12E::new([
+            Fp6E::new([Fp2E::from(3), Fp2E::from(7), Fp2E::from(11)]),
+            Fp6E::new([Fp2E::from(13), Fp2E::from(17), Fp2E::from(19)]),
+        ]);
+        assert_eq!(&a * a.inv(), Fp12E::one());
+    }
+}
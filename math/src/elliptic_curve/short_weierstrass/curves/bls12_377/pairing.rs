@@ -0,0 +1,477 @@
+use super::{
+    curve::BLS12377Curve,
+    default_types::FrConfig,
+    field_extension::{
+        BLS12377PrimeField, Degree12ExtensionField, Degree2ExtensionField, Degree6ExtensionField,
+        LevelTwoResidue,
+    },
+    twist::BLS12377TwistCurve,
+};
+use crate::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{
+            pairing::IsPairingFriendly, point::ShortWeierstrassProjectivePoint,
+            traits::IsShortWeierstrass,
+        },
+        traits::IsPairing,
+    },
+    field::{
+        element::FieldElement, extensions::cubic::HasCubicNonResidue,
+        fields::montgomery_backed_prime_fields::IsModulus,
+    },
+    unsigned_integer::element::UnsignedInteger,
+};
+
+#[derive(Clone)]
+pub struct BLS12377AtePairing;
+impl IsPairing for BLS12377AtePairing {
+    type G1Point = ShortWeierstrassProjectivePoint<BLS12377Curve>;
+    type G2Point = ShortWeierstrassProjectivePoint<BLS12377TwistCurve>;
+    type G2Prepared = G2Prepared;
+    type OutputField = Degree12ExtensionField;
+
+    /// Compute the product of the ate pairings for a list of point pairs.
+    fn compute_batch(
+        pairs: &[(&Self::G1Point, &Self::G2Point)],
+    ) -> FieldElement<Self::OutputField> {
+        let prepared: Vec<_> = pairs.iter().map(|(_, q)| G2Prepared::new(q)).collect();
+        let pairs: Vec<_> = pairs
+            .iter()
+            .zip(prepared.iter())
+            .map(|((p, _), q)| (*p, q))
+            .collect();
+        Self::compute_batch_prepared(&pairs)
+    }
+
+    fn prepare(q: &Self::G2Point) -> Self::G2Prepared {
+        G2Prepared::new(q)
+    }
+
+    fn compute_batch_prepared(
+        pairs: &[(&Self::G1Point, &Self::G2Prepared)],
+    ) -> FieldElement<Self::OutputField> {
+        final_exponentiation(&multi_miller_loop(pairs))
+    }
+}
+
+/// This is equal to the frobenius trace of the BLS12 377 curve minus one.
+const MILLER_LOOP_CONSTANT: u64 = 0x8508c00000000001;
+
+/// Returns the bits of `MILLER_LOOP_CONSTANT` from the most significant one, skipping it.
+fn miller_loop_bits() -> impl Iterator<Item = bool> {
+    let bits = u64::BITS - MILLER_LOOP_CONSTANT.leading_zeros();
+    (0..bits - 1)
+        .rev()
+        .map(|i| (MILLER_LOOP_CONSTANT >> i) & 1 == 1)
+}
+
+/// A point of G2 together with the lines of the Miller loop, which only depend on it.
+/// A line `b0 + b1 w + b3 w^3` is stored as `[b0 / py, b1 / px, b3]`, where
+/// `(px, py)` is the point of G1 at which it is evaluated.
+#[derive(Clone, Debug)]
+pub struct G2Prepared {
+    lines: Vec<[FieldElement<Degree2ExtensionField>; 3]>,
+}
+
+impl G2Prepared {
+    pub fn new(q: &ShortWeierstrassProjectivePoint<BLS12377TwistCurve>) -> Self {
+        if q.is_neutral_element() {
+            return Self { lines: Vec::new() };
+        }
+        let q = q.to_affine();
+        let mut t = q.clone();
+        let mut lines = Vec::new();
+        for bit in miller_loop_bits() {
+            lines.push(double_step(&mut t));
+            if bit {
+                lines.push(add_step(&mut t, &q));
+            }
+        }
+        Self { lines }
+    }
+
+    /// Returns true if the prepared point is the neutral element.
+    pub fn is_neutral_element(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+/// Multiplies `accumulator` by the line `b0 + b1 * w + b3 * w^3`.
+/// With `w^6 = u`, the product of `a0 + a1 w + ... + a5 w^5` and the line is
+/// (a0b0 + u (a5b1 + a3b3)) w0 + (a1b0 + a0b1 + u a4b3) w1
+/// (a2b0 + a1b1 + u a5b3  ) w2 + (a3b0 + a2b1 + a0b3  ) w3
+/// (a4b0 + a3b1 + a1b3    ) w4 + (a5b0 + a4b1 + a2b3  ) w5
+fn mul_by_line(
+    accumulator: &FieldElement<Degree12ExtensionField>,
+    b0: &FieldElement<Degree2ExtensionField>,
+    b1: &FieldElement<Degree2ExtensionField>,
+    b3: &FieldElement<Degree2ExtensionField>,
+) -> FieldElement<Degree12ExtensionField> {
+    let residue = LevelTwoResidue::residue();
+    let [x, y] = accumulator.value();
+    let [a0, a2, a4] = x.value();
+    let [a1, a3, a5] = y.value();
+    FieldElement::new([
+        FieldElement::new([
+            a0 * b0 + &residue * (a5 * b1 + a3 * b3), // w0
+            a2 * b0 + a1 * b1 + &residue * a5 * b3,   // w2
+            a4 * b0 + a3 * b1 + a1 * b3,              // w4
+        ]),
+        FieldElement::new([
+            a1 * b0 + a0 * b1 + &residue * a4 * b3, // w1
+            a3 * b0 + a2 * b1 + a0 * b3,            // w3
+            a5 * b0 + a4 * b1 + a2 * b3,            // w5
+        ]),
+    ])
+}
+
+/// Doubles `t` and returns the tangent line at `t`.
+/// Since the twist is of type D, the line through points of the twist
+/// evaluated at `p = (px, py)` is `py - lambda * px * w + (lambda * x - y) * w^3`,
+/// up to a factor in 𝔽p² that is killed by the final exponentiation.
+fn double_step(
+    t: &mut ShortWeierstrassProjectivePoint<BLS12377TwistCurve>,
+) -> [FieldElement<Degree2ExtensionField>; 3] {
+    let [x1, y1, z1] = t.coordinates();
+    let two_inv = FieldElement::<Degree2ExtensionField>::new_base("d71d230be28875631d82e03650a49d8d116cf9807a89c78f79b117dd04a4000b85aea2180000004284600000000001");
+
+    let a = &two_inv * x1 * y1;
+    let b = y1.square();
+    let c = z1.square();
+    let d = FieldElement::from(3) * &c;
+    let e = BLS12377TwistCurve::b() * d;
+    let f = FieldElement::from(3) * &e;
+    let g = two_inv * (&b + &f);
+    let h = (y1 + z1).square() - (&b + &c);
+
+    let x3 = &a * (&b - &f);
+    let y3 = g.square() - (FieldElement::from(3) * e.square());
+    let z3 = &b * &h;
+
+    let x1_sq_3 = FieldElement::from(3) * x1.square();
+
+    t.0.value = [x3, y3, z3];
+
+    [-h, x1_sq_3, e - b]
+}
+
+/// Adds `q` to `t` and returns the line through them. `q` must be in affine form.
+fn add_step(
+    t: &mut ShortWeierstrassProjectivePoint<BLS12377TwistCurve>,
+    q: &ShortWeierstrassProjectivePoint<BLS12377TwistCurve>,
+) -> [FieldElement<Degree2ExtensionField>; 3] {
+    let [x1, y1, z1] = t.coordinates();
+    let [x2, y2, _] = q.coordinates();
+
+    let a = y2 * z1;
+    let b = x2 * z1;
+    let theta = y1 - a;
+    let lambda = x1 - b;
+    let c = theta.square();
+    let d = lambda.square();
+    let e = &lambda * &d;
+    let f = z1 * c;
+    let g = x1 * d;
+    let h = &e + f - FieldElement::from(2) * &g;
+    let i = y1 * &e;
+
+    let x3 = &lambda * &h;
+    let y3 = &theta * (g - h) - i;
+    let z3 = z1 * e;
+
+    t.0.value = [x3, y3, z3];
+
+    let b3 = &theta * x2 - &lambda * y2;
+    [lambda, -theta, b3]
+}
+
+/// Multiplies `accumulator` by a prepared line evaluated at `(px, py)`.
+fn mul_by_prepared_line(
+    accumulator: &FieldElement<Degree12ExtensionField>,
+    line: &[FieldElement<Degree2ExtensionField>; 3],
+    px: &FieldElement<BLS12377PrimeField>,
+    py: &FieldElement<BLS12377PrimeField>,
+) -> FieldElement<Degree12ExtensionField> {
+    let [b0, b1, b3] = line;
+    let [b00, b01] = b0.value();
+    let [b10, b11] = b1.value();
+    let b0 = FieldElement::new([b00 * py, b01 * py]);
+    let b1 = FieldElement::new([b10 * px, b11 * px]);
+    mul_by_line(accumulator, &b0, &b1, b3)
+}
+
+/// Implements the miller loop for the ate pairing of the BLS12 377 curve
+/// for many pairs at once, sharing the squarings of the accumulator among them.
+/// Based on algorithm 9.2, page 212 of the book
+/// "Topics in computational number theory" by W. Bons and K. Lenstra
+/// The parameter of the curve is positive, so unlike BLS12 381
+/// the result does not need to be inverted.
+fn sequential_multi_miller_loop(
+    pairs: &[(&ShortWeierstrassProjectivePoint<BLS12377Curve>, &G2Prepared)],
+) -> FieldElement<Degree12ExtensionField> {
+    let pairs: Vec<_> = pairs
+        .iter()
+        .filter(|(p, q)| !p.is_neutral_element() && !q.is_neutral_element())
+        .map(|(p, q)| (p.to_affine(), *q))
+        .collect();
+
+    let mut f = FieldElement::<Degree12ExtensionField>::one();
+    let mut line_index = 0;
+    for bit in miller_loop_bits() {
+        f = f.square();
+        for (p, q) in pairs.iter() {
+            let [px, py, _] = p.coordinates();
+            f = mul_by_prepared_line(&f, &q.lines[line_index], px, py);
+        }
+        line_index += 1;
+        if bit {
+            for (p, q) in pairs.iter() {
+                let [px, py, _] = p.coordinates();
+                f = mul_by_prepared_line(&f, &q.lines[line_index], px, py);
+            }
+            line_index += 1;
+        }
+    }
+    f
+}
+
+/// Computes the product of the Miller loops of all the pairs.
+/// With the `rayon` feature, the pairs are split among the available threads.
+pub fn multi_miller_loop(
+    pairs: &[(&ShortWeierstrassProjectivePoint<BLS12377Curve>, &G2Prepared)],
+) -> FieldElement<Degree12ExtensionField> {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+
+        if pairs.len() > 1 {
+            let chunk_size = pairs.len().div_ceil(rayon::current_num_threads());
+            return pairs
+                .par_chunks(chunk_size)
+                .map(sequential_multi_miller_loop)
+                .reduce(FieldElement::one, |a, b| a * b);
+        }
+    }
+    sequential_multi_miller_loop(pairs)
+}
+
+/// Auxiliary function for the final exponentiation of the ate pairing.
+/// Since `w^(p^2 - 1) = u^((p^2 - 1) / 6)` lies in 𝔽p, the coefficient
+/// of `w^i` is just multiplied by its `i`-th power.
+fn frobenius_square(
+    f: &FieldElement<Degree12ExtensionField>,
+) -> FieldElement<Degree12ExtensionField> {
+    let [a, b] = f.value();
+    let w_raised_to_p_squared_minus_one = FieldElement::<Degree6ExtensionField>::new_base(
+        "9b3af05dd14f6ec619aaf7d34594aabc5ed1347970dec00452217cc900000008508c00000000002",
+    );
+    let omega_3 = FieldElement::<Degree2ExtensionField>::new_base(
+        "9b3af05dd14f6ec619aaf7d34594aabc5ed1347970dec00452217cc900000008508c00000000001",
+    );
+    let omega_3_squared = FieldElement::<Degree2ExtensionField>::new_base("1ae3a4617c510eabc8756ba8f8c524eb8882a75cc9bc8e359064ee822fb5bffd1e945779fffffffffffffffffffffff");
+
+    let [a0, a1, a2] = a.value();
+    let [b0, b1, b2] = b.value();
+
+    let f0 = FieldElement::new([a0.clone(), a1 * &omega_3, a2 * &omega_3_squared]);
+    let f1 = FieldElement::new([b0.clone(), b1 * omega_3, b2 * omega_3_squared]);
+
+    FieldElement::new([f0, f1 * w_raised_to_p_squared_minus_one])
+}
+
+// To understand more about how to reduce the final exponentiation
+// read "Efficient Final Exponentiation via Cyclotomic Structure for
+// Pairings over Families of Elliptic Curves" (https://eprint.iacr.org/2020/875.pdf)
+fn final_exponentiation(
+    base: &FieldElement<Degree12ExtensionField>,
+) -> FieldElement<Degree12ExtensionField> {
+    const PHI_DIVIDED_BY_R: UnsignedInteger<20> = UnsignedInteger::from_hex_unchecked("6d616e43720774d7d810d5cbdf0576728e56efc3bf3b4074a5448da5cfbef98d9c2cce3b25c548afd84225b34ccc65eca9c9678a845497a9781d8129911a8d889828282015fcd1c3fa1470f8b2d1eefd89535f9b5aaae0551dffcf72fb0bd948d5f4548283abcaf63f0a34fcb827dc8f4db069bf65f4f6974b4ff0fa27719b834b6904468768c0eaeea22e68002e16ba88600000000000000000000001");
+
+    let f1 = base.conjugate() * base.inv();
+    let f2 = frobenius_square(&f1) * f1;
+    f2.pow(PHI_DIVIDED_BY_R)
+}
+
+/// BLS12-377 with G2 untwisted to the curve over 𝔽p¹², so that the generic pairings of
+/// `short_weierstrass::pairing` can be computed on it. `BLS12377AtePairing` computes the same
+/// optimal ate pairing with a faster Miller loop, which evaluates the lines in the twist
+/// and multiplies by them with sparse products specialized to the tower of extensions,
+/// so it should be preferred.
+impl IsPairingFriendly for BLS12377Curve {
+    type G2Curve = BLS12377TwistCurve;
+    type OutputField = Degree12ExtensionField;
+
+    const SUBGROUP_ORDER: &'static [u64] = &FrConfig::MODULUS.limbs;
+    const ATE_LOOP_PARAMETER: &'static [u64] = &[MILLER_LOOP_CONSTANT];
+    const ATE_LOOP_PARAMETER_IS_NEGATIVE: bool = false;
+
+    fn embed(a: &FieldElement<BLS12377PrimeField>) -> FieldElement<Degree12ExtensionField> {
+        FieldElement::new([
+            FieldElement::new([
+                FieldElement::new([a.clone(), FieldElement::zero()]),
+                FieldElement::zero(),
+                FieldElement::zero(),
+            ]),
+            FieldElement::zero(),
+        ])
+    }
+
+    /// The untwisting isomorphism `(x, y) -> (x * w^2, y * w^3)` of the D-type twist.
+    fn g2_to_output(
+        q: &ShortWeierstrassProjectivePoint<BLS12377TwistCurve>,
+    ) -> [FieldElement<Degree12ExtensionField>; 2] {
+        let q = q.to_affine();
+        let embed = |a: &FieldElement<Degree2ExtensionField>| {
+            FieldElement::<Degree12ExtensionField>::new([
+                FieldElement::new([a.clone(), FieldElement::zero(), FieldElement::zero()]),
+                FieldElement::zero(),
+            ])
+        };
+        let w = FieldElement::<Degree12ExtensionField>::new([
+            FieldElement::zero(),
+            FieldElement::one(),
+        ]);
+        let w_squared = w.square();
+        [embed(q.x()) * &w_squared, embed(q.y()) * w_squared * w]
+    }
+
+    fn final_exponentiation(
+        f: &FieldElement<Degree12ExtensionField>,
+    ) -> FieldElement<Degree12ExtensionField> {
+        final_exponentiation(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        elliptic_curve::{
+            short_weierstrass::curves::bls12_377::default_types::FrConfig, traits::IsEllipticCurve,
+        },
+        field::fields::montgomery_backed_prime_fields::IsModulus,
+        unsigned_integer::element::U384,
+    };
+
+    use super::*;
+
+    #[test]
+    fn generic_ate_pairing_matches_bls12_377_ate_pairing() {
+        use crate::elliptic_curve::short_weierstrass::pairing::AtePairing;
+
+        let p = BLS12377Curve::generator().operate_with_self(3u64);
+        let q = BLS12377TwistCurve::generator().operate_with_self(5u64);
+        assert_eq!(
+            AtePairing::<BLS12377Curve>::compute(&p, &q),
+            BLS12377AtePairing::compute(&p, &q)
+        );
+    }
+
+    #[test]
+    fn test_double_step_doubles_point_correctly() {
+        let g2 = BLS12377TwistCurve::generator();
+        let mut r = g2.clone();
+        double_step(&mut r);
+        assert_eq!(r, g2.operate_with(&g2));
+    }
+
+    #[test]
+    fn test_add_step_adds_points_correctly() {
+        let g = BLS12377TwistCurve::generator();
+        let a: u64 = 12;
+        let b: u64 = 23;
+        let g2 = g.operate_with_self(a).to_affine();
+        let g3 = g.operate_with_self(b).to_affine();
+        let expected = g.operate_with_self(a + b);
+        let mut r = g2;
+        add_step(&mut r, &g3);
+        assert_eq!(r, expected);
+    }
+
+    #[test]
+    fn frobenius_square_matches_raising_to_p_squared() {
+        let f = FieldElement::<Degree12ExtensionField>::new([
+            FieldElement::new([
+                FieldElement::from(3),
+                FieldElement::from(5),
+                FieldElement::from(7),
+            ]),
+            FieldElement::new([
+                FieldElement::from(11),
+                FieldElement::from(13),
+                FieldElement::from(17),
+            ]),
+        ]);
+        let p = UnsignedInteger::<12>::from_hex_unchecked("1ae3a4617c510eac63b05c06ca1493b1a22d9f300f5138f1ef3622fba094800170b5d44300000008508c00000000001");
+        assert_eq!(frobenius_square(&f), f.pow(p).pow(p));
+    }
+
+    #[test]
+    fn batch_ate_pairing_bilinearity() {
+        let p = BLS12377Curve::generator();
+        let q = BLS12377TwistCurve::generator();
+        let a = U384::from_u64(11);
+        let b = U384::from_u64(93);
+
+        let result = BLS12377AtePairing::compute_batch(&[
+            (
+                &p.operate_with_self(a).to_affine(),
+                &q.operate_with_self(b).to_affine(),
+            ),
+            (
+                &p.operate_with_self(a * b).to_affine(),
+                &q.neg().to_affine(),
+            ),
+        ]);
+        assert_eq!(result, FieldElement::one());
+    }
+
+    #[test]
+    fn ate_pairing_is_non_degenerate_and_has_order_r() {
+        let p = BLS12377Curve::generator();
+        let q = BLS12377TwistCurve::generator();
+        let result = BLS12377AtePairing::compute(&p, &q);
+        assert_ne!(result, FieldElement::one());
+        assert_eq!(result.pow(FrConfig::MODULUS), FieldElement::one());
+    }
+
+    #[test]
+    fn compute_batch_prepared_matches_compute_batch() {
+        let p = BLS12377Curve::generator();
+        let q = BLS12377TwistCurve::generator();
+        let pairs: Vec<_> = (1..6_u64)
+            .map(|i| (p.operate_with_self(i), q.operate_with_self(i * i + 3)))
+            .collect();
+        let prepared: Vec<_> = pairs
+            .iter()
+            .map(|(_, q)| BLS12377AtePairing::prepare(q))
+            .collect();
+
+        let expected = pairs
+            .iter()
+            .map(|(p, q)| BLS12377AtePairing::compute(p, q))
+            .fold(FieldElement::one(), |acc, e| acc * e);
+        let batch_prepared: Vec<_> = pairs
+            .iter()
+            .zip(prepared.iter())
+            .map(|((p, _), q)| (p, q))
+            .collect();
+        assert_eq!(
+            BLS12377AtePairing::compute_batch_prepared(&batch_prepared),
+            expected
+        );
+    }
+
+    #[test]
+    fn ate_pairing_returns_one_when_one_element_is_the_neutral_element() {
+        let p = BLS12377Curve::generator().to_affine();
+        let q = ShortWeierstrassProjectivePoint::neutral_element();
+        let result = BLS12377AtePairing::compute_batch(&[(&p.to_affine(), &q)]);
+        assert_eq!(result, FieldElement::one());
+
+        let p = ShortWeierstrassProjectivePoint::neutral_element();
+        let q = BLS12377TwistCurve::generator();
+        let result = BLS12377AtePairing::compute_batch(&[(&p, &q.to_affine())]);
+        assert_eq!(result, FieldElement::one());
+    }
+}
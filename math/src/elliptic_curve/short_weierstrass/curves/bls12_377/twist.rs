@@ -0,0 +1,93 @@
+use super::field_extension::{BLS12377PrimeField, Degree2ExtensionField};
+use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
+use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::unsigned_integer::element::U384;
+use crate::{
+    elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
+};
+
+const GENERATOR_X_0: U384 = U384::from_hex_unchecked("018480be71c785fec89630a2a3841d01c565f071203e50317ea501f557db6b9b71889f52bb53540274e3e48f7c005196");
+const GENERATOR_X_1: U384 = U384::from_hex_unchecked("00ea6040e700403170dc5a51b1b140d5532777ee6651cecbe7223ece0799c9de5cf89984bff76fe6b26bfefa6ea16afe");
+const GENERATOR_Y_0: U384 = U384::from_hex_unchecked("00690d665d446f7bd960736bcbb2efb4de03ed7274b49a58e458c282f832d204f2cf88886d8c7c2ef094094409fd4ddf");
+const GENERATOR_Y_1: U384 = U384::from_hex_unchecked("00f8169fd28355189e549da3151a70aa61ef11ac3d591bf12463b01acee304c24279b83f5e52270bd9a1cdd185eb8f93");
+
+/// The coefficient `b / u = -1/5 * u` of the twist, where `b = 1`.
+const TWIST_B_1: U384 = U384::from_hex_unchecked("010222f6db0fd6f343bd03737460c589dc7b4f91cd5fd889129207b63c6bf8000dd39e5c1ccccccd1c9ed9999999999a");
+
+/// The description of the sextic D-type twist `y^2 = x^3 + 1/u` of the curve,
+/// whose points over 𝔽p² of order `r` form the group G2.
+#[derive(Clone, Debug)]
+pub struct BLS12377TwistCurve;
+
+impl IsEllipticCurve for BLS12377TwistCurve {
+    type BaseField = Degree2ExtensionField;
+    type PointRepresentation = ShortWeierstrassProjectivePoint<Self>;
+
+    fn generator() -> Self::PointRepresentation {
+        Self::PointRepresentation::new([
+            FieldElement::new([
+                FieldElement::new(GENERATOR_X_0),
+                FieldElement::new(GENERATOR_X_1),
+            ]),
+            FieldElement::new([
+                FieldElement::new(GENERATOR_Y_0),
+                FieldElement::new(GENERATOR_Y_1),
+            ]),
+            FieldElement::one(),
+        ])
+    }
+}
+
+impl IsShortWeierstrass for BLS12377TwistCurve {
+    fn a() -> FieldElement<Self::BaseField> {
+        FieldElement::zero()
+    }
+
+    fn b() -> FieldElement<Self::BaseField> {
+        FieldElement::new([
+            FieldElement::<BLS12377PrimeField>::zero(),
+            FieldElement::new(TWIST_B_1),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cyclic_group::IsGroup,
+        elliptic_curve::short_weierstrass::curves::bls12_377::default_types::FrConfig,
+        field::fields::montgomery_backed_prime_fields::IsModulus,
+    };
+
+    type Fp2E = FieldElement<Degree2ExtensionField>;
+
+    #[test]
+    fn b_times_u_is_one() {
+        let u = Fp2E::new([FieldElement::zero(), FieldElement::one()]);
+        assert_eq!(BLS12377TwistCurve::b() * u, Fp2E::one());
+    }
+
+    #[test]
+    fn generator_satisfies_the_twist_equation() {
+        let g = BLS12377TwistCurve::generator();
+        let [x, y, _] = g.coordinates();
+        assert_eq!(y.square(), x.pow(3_u64) + BLS12377TwistCurve::b());
+    }
+
+    #[test]
+    fn generator_has_order_r() {
+        let g = BLS12377TwistCurve::generator();
+        assert!(g.operate_with_self(FrConfig::MODULUS).is_neutral_element());
+        assert!(!g.is_neutral_element());
+    }
+
+    #[test]
+    fn operate_with_self_works() {
+        let g = BLS12377TwistCurve::generator();
+        assert_eq!(
+            g.operate_with(&g).operate_with(&g),
+            g.operate_with_self(3_u16)
+        );
+    }
+}
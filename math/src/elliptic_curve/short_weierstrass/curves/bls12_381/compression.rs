@@ -0,0 +1,311 @@
+use super::{
+    curve::{BLS12381Curve, BLS12381FieldElement, BLS12381TwistCurveFieldElement},
+    field_extension::{BLS12381PrimeField, Degree2ExtensionField},
+    sqrt::sqrt_qfe,
+    twist::BLS12381TwistCurve,
+};
+use crate::elliptic_curve::short_weierstrass::{
+    errors::DeserializationError,
+    point::ShortWeierstrassProjectivePoint,
+    traits::Compress,
+    zcash::{self, HasZcashEncoding},
+};
+
+/// Number of bytes of the big-endian encoding of an element of the base field.
+const BLS12381_FIELD_BYTES: usize = 48;
+
+/// Elements `x0 + x1 * u` of the quadratic extension are encoded as `x1 || x0` and
+/// compared first by the `u` coefficient and then by the constant one, as done by
+/// the Zcash encoding.
+impl HasZcashEncoding for Degree2ExtensionField {
+    fn to_bytes(a: &BLS12381TwistCurveFieldElement) -> Vec<u8> {
+        let [a0, a1] = a.value();
+        let mut bytes = BLS12381PrimeField::to_bytes(a1);
+        bytes.extend(BLS12381PrimeField::to_bytes(a0));
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<BLS12381TwistCurveFieldElement, DeserializationError> {
+        if bytes.len() != 2 * BLS12381_FIELD_BYTES {
+            return Err(DeserializationError::InvalidAmountOfBytes);
+        }
+        let a1 = BLS12381PrimeField::from_bytes(&bytes[..BLS12381_FIELD_BYTES])?;
+        let a0 = BLS12381PrimeField::from_bytes(&bytes[BLS12381_FIELD_BYTES..])?;
+        Ok(BLS12381TwistCurveFieldElement::new([a0, a1]))
+    }
+
+    fn is_lexicographically_largest(a: &BLS12381TwistCurveFieldElement) -> bool {
+        let [a0, a1] = a.value();
+        if *a1 == BLS12381FieldElement::zero() {
+            BLS12381PrimeField::is_lexicographically_largest(a0)
+        } else {
+            BLS12381PrimeField::is_lexicographically_largest(a1)
+        }
+    }
+
+    fn square_root(a: &BLS12381TwistCurveFieldElement) -> Option<BLS12381TwistCurveFieldElement> {
+        sqrt_qfe(a, 0)
+    }
+}
+
+impl Compress for BLS12381Curve {
+    /// Encodes `point` in 48 bytes as the big-endian `x` coordinate with the
+    /// compression, infinity and sort flags in the most significant bits.
+    fn compress(point: &ShortWeierstrassProjectivePoint<Self>) -> Vec<u8> {
+        zcash::compress(point)
+    }
+
+    fn decompress(
+        bytes: &[u8],
+    ) -> Result<ShortWeierstrassProjectivePoint<Self>, DeserializationError> {
+        zcash::decompress(bytes)
+    }
+}
+
+impl Compress for BLS12381TwistCurve {
+    /// Encodes `point` in 96 bytes as the big-endian coefficients of `x = x0 + x1 * u`
+    /// in the order `x1 || x0`, with the compression, infinity and sort flags in the
+    /// most significant bits.
+    fn compress(point: &ShortWeierstrassProjectivePoint<Self>) -> Vec<u8> {
+        zcash::compress(point)
+    }
+
+    fn decompress(
+        bytes: &[u8],
+    ) -> Result<ShortWeierstrassProjectivePoint<Self>, DeserializationError> {
+        zcash::decompress(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cyclic_group::IsGroup,
+        elliptic_curve::{
+            short_weierstrass::{
+                point::{Endianness, PointFormat},
+                zcash::{COMPRESSION_FLAG, INFINITY_FLAG},
+            },
+            traits::IsEllipticCurve,
+        },
+    };
+
+    type G1Point = ShortWeierstrassProjectivePoint<BLS12381Curve>;
+    type G2Point = ShortWeierstrassProjectivePoint<BLS12381TwistCurve>;
+
+    fn bytes_from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // Test vectors from the Zcash implementation of BLS12-381.
+    const G1_GENERATOR_COMPRESSED: &str = "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+    const G1_GENERATOR_UNCOMPRESSED: &str = "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1";
+    const G1_GENERATOR_NEG_COMPRESSED: &str = "b7f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+    const G2_GENERATOR_COMPRESSED: &str = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+    const G2_GENERATOR_NEG_COMPRESSED: &str = "b3e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+
+    #[test]
+    fn compress_g1_generator_matches_zcash_vector() {
+        let g = BLS12381Curve::generator();
+        assert_eq!(
+            g.serialize_compressed(),
+            bytes_from_hex(G1_GENERATOR_COMPRESSED)
+        );
+        assert_eq!(
+            g.neg().serialize_compressed(),
+            bytes_from_hex(G1_GENERATOR_NEG_COMPRESSED)
+        );
+    }
+
+    #[test]
+    fn serialize_g1_generator_uncompressed_matches_zcash_vector() {
+        let g = BLS12381Curve::generator();
+        let bytes = g.serialize(PointFormat::Uncompressed, Endianness::BigEndian);
+        assert_eq!(bytes, bytes_from_hex(G1_GENERATOR_UNCOMPRESSED));
+
+        let deserialized =
+            G1Point::deserialize(&bytes, PointFormat::Uncompressed, Endianness::BigEndian);
+        assert_eq!(deserialized.unwrap(), g);
+    }
+
+    #[test]
+    fn decompress_g1_generator_from_zcash_vector() {
+        let bytes = bytes_from_hex(G1_GENERATOR_COMPRESSED);
+        let point = G1Point::deserialize_compressed(&bytes);
+        assert_eq!(point.unwrap(), BLS12381Curve::generator());
+
+        let bytes = bytes_from_hex(G1_GENERATOR_NEG_COMPRESSED);
+        let point = G1Point::deserialize_compressed(&bytes);
+        assert_eq!(point.unwrap(), BLS12381Curve::generator().neg());
+    }
+
+    #[test]
+    fn compress_g2_generator_matches_zcash_vector() {
+        let g = BLS12381TwistCurve::generator();
+        assert_eq!(
+            g.serialize_compressed(),
+            bytes_from_hex(G2_GENERATOR_COMPRESSED)
+        );
+        assert_eq!(
+            g.neg().serialize_compressed(),
+            bytes_from_hex(G2_GENERATOR_NEG_COMPRESSED)
+        );
+    }
+
+    #[test]
+    fn decompress_g2_generator_from_zcash_vector() {
+        let bytes = bytes_from_hex(G2_GENERATOR_COMPRESSED);
+        let point = G2Point::deserialize_compressed(&bytes);
+        assert_eq!(point.unwrap(), BLS12381TwistCurve::generator());
+
+        let bytes = bytes_from_hex(G2_GENERATOR_NEG_COMPRESSED);
+        let point = G2Point::deserialize_compressed(&bytes);
+        assert_eq!(point.unwrap(), BLS12381TwistCurve::generator().neg());
+    }
+
+    #[test]
+    fn compress_and_decompress_multiples_of_the_generators() {
+        let g1 = BLS12381Curve::generator();
+        let g2 = BLS12381TwistCurve::generator();
+        for i in 1..10_u16 {
+            let p = g1.operate_with_self(i);
+            let bytes = BLS12381Curve::compress(&p);
+            assert_eq!(BLS12381Curve::decompress(&bytes).unwrap(), p);
+
+            let q = g2.operate_with_self(i);
+            let bytes = BLS12381TwistCurve::compress(&q);
+            assert_eq!(BLS12381TwistCurve::decompress(&bytes).unwrap(), q);
+        }
+    }
+
+    #[test]
+    fn compress_and_decompress_point_at_infinity() {
+        let bytes = BLS12381Curve::compress(&G1Point::neutral_element());
+        let mut expected = vec![0; 48];
+        expected[0] = 0xc0;
+        assert_eq!(bytes, expected);
+        assert!(BLS12381Curve::decompress(&bytes)
+            .unwrap()
+            .is_neutral_element());
+
+        let bytes = BLS12381TwistCurve::compress(&G2Point::neutral_element());
+        let mut expected = vec![0; 96];
+        expected[0] = 0xc0;
+        assert_eq!(bytes, expected);
+        assert!(BLS12381TwistCurve::decompress(&bytes)
+            .unwrap()
+            .is_neutral_element());
+    }
+
+    #[test]
+    fn serialize_and_deserialize_uncompressed_point_at_infinity() {
+        let bytes =
+            G1Point::neutral_element().serialize(PointFormat::Uncompressed, Endianness::BigEndian);
+        let mut expected = vec![0; 96];
+        expected[0] = INFINITY_FLAG;
+        assert_eq!(bytes, expected);
+        let point = G1Point::deserialize(&bytes, PointFormat::Uncompressed, Endianness::BigEndian);
+        assert!(point.unwrap().is_neutral_element());
+
+        let bytes = G1Point::neutral_element()
+            .serialize(PointFormat::Uncompressed, Endianness::LittleEndian);
+        let mut expected = vec![0; 96];
+        expected[47] = INFINITY_FLAG;
+        assert_eq!(bytes, expected);
+        let point =
+            G1Point::deserialize(&bytes, PointFormat::Uncompressed, Endianness::LittleEndian);
+        assert!(point.unwrap().is_neutral_element());
+
+        let bytes =
+            G2Point::neutral_element().serialize(PointFormat::Uncompressed, Endianness::BigEndian);
+        let mut expected = vec![0; 192];
+        expected[0] = INFINITY_FLAG;
+        assert_eq!(bytes, expected);
+        let point = G2Point::deserialize(&bytes, PointFormat::Uncompressed, Endianness::BigEndian);
+        assert!(point.unwrap().is_neutral_element());
+    }
+
+    #[test]
+    fn deserialize_uncompressed_rejects_zeros_and_invalid_flags() {
+        for endianness in [Endianness::BigEndian, Endianness::LittleEndian] {
+            assert_eq!(
+                G1Point::deserialize(&[0; 96], PointFormat::Uncompressed, endianness).unwrap_err(),
+                DeserializationError::FieldFromBytesError
+            );
+        }
+
+        let mut bytes = vec![0; 96];
+        bytes[0] = INFINITY_FLAG;
+        bytes[95] = 1;
+        assert_eq!(
+            G1Point::deserialize(&bytes, PointFormat::Uncompressed, Endianness::BigEndian)
+                .unwrap_err(),
+            DeserializationError::InvalidFlags
+        );
+
+        let mut bytes = bytes_from_hex(G1_GENERATOR_UNCOMPRESSED);
+        bytes[0] |= COMPRESSION_FLAG;
+        assert_eq!(
+            G1Point::deserialize(&bytes, PointFormat::Uncompressed, Endianness::BigEndian)
+                .unwrap_err(),
+            DeserializationError::InvalidFlags
+        );
+    }
+
+    #[test]
+    fn serialize_and_deserialize_uncompressed_g2_point() {
+        let q = BLS12381TwistCurve::generator().operate_with_self(3_u16);
+        let bytes = q.serialize(PointFormat::Uncompressed, Endianness::LittleEndian);
+        assert_eq!(bytes.len(), 192);
+        let point =
+            G2Point::deserialize(&bytes, PointFormat::Uncompressed, Endianness::LittleEndian);
+        assert_eq!(point.unwrap(), q);
+    }
+
+    #[test]
+    fn decompress_without_compression_flag_fails() {
+        let mut bytes = bytes_from_hex(G1_GENERATOR_COMPRESSED);
+        bytes[0] &= !COMPRESSION_FLAG;
+        assert_eq!(
+            BLS12381Curve::decompress(&bytes).unwrap_err(),
+            DeserializationError::InvalidFlags
+        );
+    }
+
+    #[test]
+    fn decompress_infinity_with_nonzero_bits_fails() {
+        let mut bytes = vec![0; 48];
+        bytes[0] = COMPRESSION_FLAG | INFINITY_FLAG;
+        bytes[47] = 1;
+        assert_eq!(
+            BLS12381Curve::decompress(&bytes).unwrap_err(),
+            DeserializationError::InvalidFlags
+        );
+    }
+
+    #[test]
+    fn decompress_x_not_on_curve_fails() {
+        // x = 0 gives y^2 = 4, which has a square root, so use x = 1
+        // where y^2 = 5 is not a square in the base field.
+        let mut bytes = vec![0; 48];
+        bytes[0] = COMPRESSION_FLAG;
+        bytes[47] = 1;
+        assert_eq!(
+            BLS12381Curve::decompress(&bytes).unwrap_err(),
+            DeserializationError::FieldFromBytesError
+        );
+    }
+
+    #[test]
+    fn decompress_wrong_number_of_bytes_fails() {
+        let bytes = bytes_from_hex(G1_GENERATOR_COMPRESSED);
+        assert_eq!(
+            BLS12381TwistCurve::decompress(&bytes).unwrap_err(),
+            DeserializationError::InvalidAmountOfBytes
+        );
+    }
+}
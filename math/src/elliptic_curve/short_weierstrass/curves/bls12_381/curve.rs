@@ -1,6 +1,11 @@
+use super::default_types::FrConfig;
 use super::field_extension::{BLS12381PrimeField, Degree2ExtensionField};
+use crate::cyclic_group::IsGroup;
+use crate::elliptic_curve::glv::{GlvLattice, SignedInteger};
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
-use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::elliptic_curve::traits::{HasCofactor, HasEndomorphism, IsEllipticCurve};
+use crate::field::fields::montgomery_backed_prime_fields::IsModulus;
+use crate::unsigned_integer::element::U256;
 use crate::{
     elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
 };
@@ -8,6 +13,27 @@ use crate::{
 pub type BLS12381FieldElement = FieldElement<BLS12381PrimeField>;
 pub type BLS12381TwistCurveFieldElement = FieldElement<Degree2ExtensionField>;
 
+/// Absolute value of the parameter `x` of the BLS family that generates
+/// the BLS12-381 curve. The parameter itself is negative.
+pub const BLS12381_X: u64 = 0xd201000000010000;
+pub const BLS12381_X_IS_NEGATIVE: bool = true;
+
+/// A primitive cube root of unity in the base field. The map
+/// (x, y) -> (BETA * x, y) acts on G1 as the multiplication by `-x^2`.
+pub const BETA: BLS12381FieldElement = BLS12381FieldElement::from_hex_unchecked(
+    "5f19672fdf76ce51ba69c6076a0f77eaddb3a93be6f89688de17d813620a00022e01fffffffefffe",
+);
+
+/// `x^2`, where `x` is the parameter of the curve.
+pub(crate) const BLS12381_X_SQUARED: U256 =
+    U256::from_hex_unchecked("ac45a4010001a4020000000100000000");
+/// `x^2 - 1`, where `x` is the parameter of the curve.
+pub(crate) const BLS12381_X_SQUARED_MINUS_ONE: U256 =
+    U256::from_hex_unchecked("ac45a4010001a40200000000ffffffff");
+/// `round(x^2 * 2^256 / r)`, where `r` is the order of the subgroup.
+pub(crate) const GLV_ROUNDING_CONSTANT: U256 =
+    U256::from_hex_unchecked("17c6becf1e01faadd63f6e522f6cfee30");
+
 /// The description of the curve.
 #[derive(Clone, Debug)]
 pub struct BLS12381Curve;
@@ -26,6 +52,8 @@ impl IsEllipticCurve for BLS12381Curve {
 }
 
 impl IsShortWeierstrass for BLS12381Curve {
+    const HAS_ZCASH_FLAGS: bool = true;
+
     fn a() -> FieldElement<Self::BaseField> {
         FieldElement::from(0)
     }
@@ -35,12 +63,64 @@ impl IsShortWeierstrass for BLS12381Curve {
     }
 }
 
+impl ShortWeierstrassProjectivePoint<BLS12381Curve> {
+    /// Returns the image of `self` under the endomorphism (x, y) -> (BETA * x, y).
+    pub fn phi(&self) -> Self {
+        let [x, y, z] = self.coordinates();
+        Self::new([x * BETA, y.clone(), z.clone()])
+    }
+}
+
+impl HasCofactor for ShortWeierstrassProjectivePoint<BLS12381Curve> {
+    /// Checks that `phi(P) == -x^2 * P`, which only holds for points of the
+    /// prime order subgroup. See "Faster Subgroup Checks for BLS12-381" by
+    /// S. Bowe (https://eprint.iacr.org/2019/814.pdf).
+    fn is_in_subgroup(&self) -> bool {
+        let x_squared = (BLS12381_X as u128) * (BLS12381_X as u128);
+        self.phi() == self.operate_with_self(x_squared).neg()
+    }
+
+    /// Multiplies `self` by the effective cofactor `1 - x` from
+    /// RFC 9380, section 8.8.1.
+    fn clear_cofactor(&self) -> Self {
+        self.operate_with_self(BLS12381_X + 1)
+    }
+}
+
+impl HasEndomorphism<4> for ShortWeierstrassProjectivePoint<BLS12381Curve> {
+    /// `phi` acts on G1 as the multiplication by `lambda = -x^2`.
+    fn endomorphism(&self) -> Self {
+        self.phi()
+    }
+
+    /// Since `r = x^4 - x^2 + 1`, the vectors `(x^2, 1)` and `(x^2 - 1, x^2)` form
+    /// a basis of the lattice of determinant `r`.
+    fn glv_lattice() -> GlvLattice<4> {
+        GlvLattice {
+            order: FrConfig::MODULUS,
+            v1: [
+                SignedInteger::positive(BLS12381_X_SQUARED),
+                SignedInteger::positive(U256::from_u64(1)),
+            ],
+            v2: [
+                SignedInteger::positive(BLS12381_X_SQUARED_MINUS_ONE),
+                SignedInteger::positive(BLS12381_X_SQUARED),
+            ],
+            g1: SignedInteger::positive(GLV_ROUNDING_CONSTANT),
+            g2: SignedInteger::negative(U256::from_u64(2)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        cyclic_group::IsGroup, elliptic_curve::traits::EllipticCurveError,
-        field::element::FieldElement,
+        elliptic_curve::{
+            short_weierstrass::curves::bls12_381::default_types::{FrConfig, FrElement},
+            traits::EllipticCurveError,
+        },
+        field::{element::FieldElement, fields::montgomery_backed_prime_fields::IsModulus},
     };
 
     use super::BLS12381Curve;
@@ -117,4 +197,83 @@ mod tests {
             g.operate_with_self(3_u16)
         );
     }
+
+    /// A point of the curve outside the prime order subgroup.
+    fn point_not_in_subgroup() -> ShortWeierstrassProjectivePoint<BLS12381Curve> {
+        let x = FEE::from(4);
+        let y = FEE::new_base("a989badd40d6212b33cffc3f3763e9bc760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c");
+        BLS12381Curve::create_point_from_affine(x, y).unwrap()
+    }
+
+    #[test]
+    fn generator_and_its_multiples_are_in_subgroup() {
+        let g = BLS12381Curve::generator();
+        assert!(g.is_in_subgroup());
+        assert!(g.operate_with_self(1234_u16).is_in_subgroup());
+        assert!(
+            ShortWeierstrassProjectivePoint::<BLS12381Curve>::neutral_element().is_in_subgroup()
+        );
+    }
+
+    #[test]
+    fn point_not_in_subgroup_fails_subgroup_check() {
+        let p = point_not_in_subgroup();
+        assert!(!p.is_in_subgroup());
+        assert!(!p.operate_with(&BLS12381Curve::generator()).is_in_subgroup());
+    }
+
+    #[test]
+    fn clear_cofactor_maps_into_subgroup() {
+        let p = point_not_in_subgroup().clear_cofactor();
+        assert!(!p.is_neutral_element());
+        assert!(p.is_in_subgroup());
+        assert!(p.operate_with_self(FrConfig::MODULUS).is_neutral_element());
+    }
+
+    fn scalars() -> Vec<U256> {
+        vec![
+            U256::from_u64(0),
+            U256::from_u64(1),
+            U256::from_hex_unchecked("ac45a4010001a4020000000100000000"),
+            U256::from_hex_unchecked(
+                "3d0ef8a3d4cf5e36a3d8e4f0c67a12b1e0b1d42f04bc9a4d7c2f2e55d9a8c6a1",
+            ),
+            U256::from_hex_unchecked(
+                "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000",
+            ),
+        ]
+    }
+
+    #[test]
+    fn glv_decomposition_splits_scalars_in_halves() {
+        let lattice = ShortWeierstrassProjectivePoint::<BLS12381Curve>::glv_lattice();
+        let lambda = -FrElement::new(BLS12381_X_SQUARED);
+        let to_fr = |k: SignedInteger<4>| {
+            if k.is_negative {
+                -FrElement::new(k.abs)
+            } else {
+                FrElement::new(k.abs)
+            }
+        };
+        for k in scalars() {
+            let (k1, k2) = lattice.decompose(&k);
+            assert!(k1.abs.bits() <= 128 && k2.abs.bits() <= 128);
+            assert_eq!(to_fr(k1) + to_fr(k2) * &lambda, FrElement::new(k));
+        }
+    }
+
+    #[test]
+    fn glv_mul_matches_operate_with_self() {
+        let g = BLS12381Curve::generator().operate_with_self(7_u64);
+        for k in scalars() {
+            assert_eq!(g.glv_mul(&k), g.operate_with_self(k));
+        }
+    }
+
+    #[test]
+    fn glv_mul_reduces_scalars_modulo_the_order() {
+        let g = BLS12381Curve::generator();
+        let k = FrConfig::MODULUS + U256::from_u64(5);
+        assert_eq!(g.glv_mul(&k), g.operate_with_self(5_u64));
+    }
 }
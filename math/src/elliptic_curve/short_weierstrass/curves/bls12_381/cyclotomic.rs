@@ -0,0 +1,356 @@
+use super::curve::{BLS12381_X, BLS12381_X_IS_NEGATIVE};
+use super::field_extension::{
+    Degree12ExtensionField, Degree2ExtensionField, Degree6ExtensionField,
+};
+use crate::field::element::FieldElement;
+use crate::unsigned_integer::element::U384;
+
+type Fp2E = FieldElement<Degree2ExtensionField>;
+type Fp6E = FieldElement<Degree6ExtensionField>;
+type Fp12E = FieldElement<Degree12ExtensionField>;
+
+/// Coefficients `(1 + u)^(i * (p - 1) / 6)` for `i = 1..5` of the Frobenius map `a -> a^p`.
+const FROBENIUS_COEFFS: [[U384; 2]; 5] = [
+    [
+        U384::from_hex_unchecked("1904d3bf02bb0667c231beb4202c0d1f0fd603fd3cbd5f4f7b2443d784bab9c4f67ea53d63e7813d8d0775ed92235fb8"),
+        U384::from_hex_unchecked("00fc3e2b36c4e03288e9e902231f9fb854a14787b6c7b36fec0c8ec971f63c5f282d5ac14d6c7ec22cf78a126ddc4af3"),
+    ],
+    [
+        U384::from_hex_unchecked("0"),
+        U384::from_hex_unchecked("1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaac"),
+    ],
+    [
+        U384::from_hex_unchecked("06af0e0437ff400b6831e36d6bd17ffe48395dabc2d3435e77f76e17009241c5ee67992f72ec05f4c81084fbede3cc09"),
+        U384::from_hex_unchecked("06af0e0437ff400b6831e36d6bd17ffe48395dabc2d3435e77f76e17009241c5ee67992f72ec05f4c81084fbede3cc09"),
+    ],
+    [
+        U384::from_hex_unchecked("1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaad"),
+        U384::from_hex_unchecked("0"),
+    ],
+    [
+        U384::from_hex_unchecked("05b2cfd9013a5fd8df47fa6b48b1e045f39816240c0b8fee8beadf4d8e9c0566c63a3e6e257f87329b18fae980078116"),
+        U384::from_hex_unchecked("144e4211384586c16bd3ad4afa99cc9170df3560e77982d0db45f3536814f0bd5871c1908bd478cd1ee605167ff82995"),
+    ],
+];
+
+/// Positions of the bits set in `BLS12381_X`.
+const X_SET_BITS: [usize; 6] = [16, 48, 57, 60, 62, 63];
+
+/// Returns `(a + b * v)^2` in `𝔽p⁴ = 𝔽p²[v] / (v^2 - (1 + u))`.
+fn fp4_square(a: &Fp2E, b: &Fp2E) -> (Fp2E, Fp2E) {
+    let ab = a * b;
+    let c0 = (a + b) * (b.mul_by_nonresidue() + a) - &ab - ab.mul_by_nonresidue();
+    let c1 = &ab + &ab;
+    (c0, c1)
+}
+
+/// An element of the cyclotomic subgroup in the compressed representation of Karabina,
+/// which drops the coefficients of `w^0` and `w^3`. Both can be recovered from the
+/// other four, and squaring only needs the four kept ones.
+/// See "Squaring in cyclotomic subgroups" by K. Karabina (https://eprint.iacr.org/2010/542.pdf).
+#[derive(Clone, Debug)]
+struct CompressedCyclotomicElement {
+    c0_b1: Fp2E,
+    c0_b2: Fp2E,
+    c1_b0: Fp2E,
+    c1_b2: Fp2E,
+}
+
+impl CompressedCyclotomicElement {
+    fn compress(a: &Fp12E) -> Self {
+        let [c0, c1] = a.value();
+        let [_, c0_b1, c0_b2] = c0.value();
+        let [c1_b0, _, c1_b2] = c1.value();
+        Self {
+            c0_b1: c0_b1.clone(),
+            c0_b2: c0_b2.clone(),
+            c1_b0: c1_b0.clone(),
+            c1_b2: c1_b2.clone(),
+        }
+    }
+
+    /// Squares the element with 6 squarings in 𝔽p².
+    fn square(&self) -> Self {
+        let t0 = self.c0_b1.square();
+        let t1 = self.c1_b2.square();
+        let t2 = self.c1_b0.square();
+        let t3 = self.c0_b2.square();
+        // 2 * c0_b1 * c1_b2 and 2 * c1_b0 * c0_b2
+        let t4 = (&self.c0_b1 + &self.c1_b2).square() - (&t0 + &t1);
+        let t5 = (&self.c1_b0 + &self.c0_b2).square() - (&t2 + &t3);
+
+        let t4 = t4.mul_by_nonresidue();
+        let c1_b0 = FieldElement::from(3) * &t4 + (&self.c1_b0 + &self.c1_b0);
+        let t6 = &t0 + t1.mul_by_nonresidue();
+        let c0_b2 = FieldElement::from(3) * &t6 - (&self.c0_b2 + &self.c0_b2);
+        let t7 = &t2 + t3.mul_by_nonresidue();
+        let c0_b1 = FieldElement::from(3) * &t7 - (&self.c0_b1 + &self.c0_b1);
+        let c1_b2 = FieldElement::from(3) * &t5 + (&self.c1_b2 + &self.c1_b2);
+        Self {
+            c0_b1,
+            c0_b2,
+            c1_b0,
+            c1_b2,
+        }
+    }
+
+    /// Returns the numerator and the denominator of the coefficient `c1_b1`.
+    fn c1_b1_fraction(&self) -> (Fp2E, Fp2E) {
+        if self.c1_b0 == Fp2E::zero() {
+            let c0_b1_c1_b2 = &self.c0_b1 * &self.c1_b2;
+            (&c0_b1_c1_b2 + &c0_b1_c1_b2, self.c0_b2.clone())
+        } else {
+            let t0 = self.c0_b1.square();
+            let t1 = FieldElement::from(3) * &t0 - (&self.c0_b2 + &self.c0_b2);
+            let numerator = self.c1_b2.square().mul_by_nonresidue() + t1;
+            let denominator = FieldElement::from(4) * &self.c1_b0;
+            (numerator, denominator)
+        }
+    }
+
+    /// Recovers the full element given the coefficient `c1_b1`.
+    fn decompress_with(&self, c1_b1: Fp2E) -> Fp12E {
+        let t1 = &self.c0_b2 * &self.c0_b1;
+        let t2 = FieldElement::from(2) * c1_b1.square() - FieldElement::from(3) * &t1;
+        let c0_b0 = (t2 + &self.c1_b0 * &self.c1_b2).mul_by_nonresidue() + Fp2E::one();
+        Fp12E::new([
+            Fp6E::new([c0_b0, self.c0_b1.clone(), self.c0_b2.clone()]),
+            Fp6E::new([self.c1_b0.clone(), c1_b1, self.c1_b2.clone()]),
+        ])
+    }
+
+    /// Decompresses all the elements with a single inversion.
+    /// Returns `None` if some element cannot be recovered from its compressed form.
+    fn batch_decompress(elements: &[Self]) -> Option<Vec<Fp12E>> {
+        let (numerators, mut denominators): (Vec<_>, Vec<_>) =
+            elements.iter().map(Self::c1_b1_fraction).unzip();
+        if denominators.iter().any(|d| *d == Fp2E::zero()) {
+            return None;
+        }
+        FieldElement::inplace_batch_inverse(&mut denominators);
+        Some(
+            elements
+                .iter()
+                .zip(numerators.iter().zip(denominators.iter()))
+                .map(|(element, (numerator, inverse))| element.decompress_with(numerator * inverse))
+                .collect(),
+        )
+    }
+}
+
+impl FieldElement<Degree12ExtensionField> {
+    /// Returns `self^p`.
+    pub fn frobenius(&self) -> Self {
+        let [c0, c1] = self.value();
+        let [a0, a2, a4] = c0.value();
+        let [a1, a3, a5] = c1.value();
+        let coeff = |i: usize| {
+            let [x, y] = &FROBENIUS_COEFFS[i - 1];
+            Fp2E::new([FieldElement::new(*x), FieldElement::new(*y)])
+        };
+        Self::new([
+            Fp6E::new([
+                a0.conjugate(),
+                a2.conjugate() * coeff(2),
+                a4.conjugate() * coeff(4),
+            ]),
+            Fp6E::new([
+                a1.conjugate() * coeff(1),
+                a3.conjugate() * coeff(3),
+                a5.conjugate() * coeff(5),
+            ]),
+        ])
+    }
+
+    /// Squares an element of the cyclotomic subgroup, that is, an element whose
+    /// norm to `𝔽p⁶` and to `𝔽p⁴` is one, such as the output of the easy part
+    /// of the final exponentiation. The result is meaningless for other elements.
+    /// It costs 6 multiplications in 𝔽p², a fraction of a generic squaring.
+    /// See "Faster Squaring in the Cyclotomic Subgroup of Sixth Degree Extensions"
+    /// by R. Granger and M. Scott (https://eprint.iacr.org/2009/565.pdf).
+    pub fn cyclotomic_square(&self) -> Self {
+        let [c0, c1] = self.value();
+        let [z0, z4, z3] = c0.value();
+        let [z2, z1, z5] = c1.value();
+
+        let (t0, t1) = fp4_square(z0, z1);
+        let (t2, t3) = fp4_square(z2, z3);
+        let (t4, t5) = fp4_square(z4, z5);
+
+        let t5 = t5.mul_by_nonresidue();
+        let z0 = FieldElement::from(3) * &t0 - (z0 + z0);
+        let z1 = FieldElement::from(3) * &t1 + (z1 + z1);
+        let z2 = FieldElement::from(3) * &t5 + (z2 + z2);
+        let z3 = FieldElement::from(3) * &t4 - (z3 + z3);
+        let z4 = FieldElement::from(3) * &t2 - (z4 + z4);
+        let z5 = FieldElement::from(3) * &t3 + (z5 + z5);
+
+        Self::new([Fp6E::new([z0, z4, z3]), Fp6E::new([z2, z1, z5])])
+    }
+
+    /// Raises an element of the cyclotomic subgroup to the power `x`, the parameter of the curve.
+    /// The powers `2^i` for the bits set in `x` are computed with compressed squarings
+    /// and then decompressed with a single inversion.
+    pub fn cyclotomic_pow_x(&self) -> Self {
+        let mut compressed = CompressedCyclotomicElement::compress(self);
+        let mut powers = Vec::with_capacity(X_SET_BITS.len());
+        for i in 1..=X_SET_BITS[X_SET_BITS.len() - 1] {
+            compressed = compressed.square();
+            if X_SET_BITS.contains(&i) {
+                powers.push(compressed.clone());
+            }
+        }
+
+        let result = match CompressedCyclotomicElement::batch_decompress(&powers) {
+            Some(powers) => powers
+                .iter()
+                .fold(Self::one(), |accumulator, power| accumulator * power),
+            None => self.cyclotomic_pow(BLS12381_X),
+        };
+        if BLS12381_X_IS_NEGATIVE {
+            result.conjugate()
+        } else {
+            result
+        }
+    }
+
+    /// Checks whether `self` belongs to the cyclotomic subgroup, the subgroup of
+    /// order `p^4 - p^2 + 1` of the multiplicative group.
+    pub fn is_in_cyclotomic_subgroup(&self) -> bool {
+        let f_p2 = self.frobenius().frobenius();
+        f_p2.frobenius().frobenius() * self == f_p2
+    }
+
+    /// Checks whether `self` belongs to the subgroup of order `r`, the target group
+    /// of the pairing. An element of the cyclotomic subgroup is in it if and only if
+    /// `self^p = self^x`, since `p = x` modulo `r`. See "A note on group membership
+    /// tests for G1, G2 and GT on BLS pairing-friendly curves" by M. Scott
+    /// (https://eprint.iacr.org/2021/1130.pdf).
+    pub fn is_in_target_group(&self) -> bool {
+        self.is_in_cyclotomic_subgroup() && self.frobenius() == self.cyclotomic_pow_x()
+    }
+
+    /// Compresses an element `g + h w` of the cyclotomic subgroup to `(1 + g) / h` in 𝔽p⁶,
+    /// using that the subgroup lies in the algebraic torus `T2(𝔽p⁶)` of the elements
+    /// of norm one. Returns `None` for `1` and `-1`, the only elements with `h = 0`.
+    /// See "Torus-based cryptography" by K. Rubin and A. Silverberg.
+    pub fn torus_compress(&self) -> Option<Fp6E> {
+        let [g, h] = self.value();
+        if *h == Fp6E::zero() {
+            None
+        } else {
+            Some((Fp6E::one() + g) / h)
+        }
+    }
+
+    /// Inverse of `torus_compress`. Returns `(c + w) / (c - w)`.
+    pub fn torus_decompress(c: &Fp6E) -> Self {
+        // v = w^2 is not a square in 𝔽p⁶, so c^2 - v is never zero.
+        let v = Fp6E::new([Fp2E::zero(), Fp2E::one(), Fp2E::zero()]);
+        let c_squared = c.square();
+        let denominator_inv = (&c_squared - &v).inv();
+        Self::new([
+            (c_squared + v) * &denominator_inv,
+            (c + c) * denominator_inv,
+        ])
+    }
+
+    /// Raises an element of the cyclotomic subgroup to the power `exponent`
+    /// with a square and multiply using cyclotomic squarings.
+    pub fn cyclotomic_pow(&self, exponent: u64) -> Self {
+        let mut result = Self::one();
+        for i in (0..u64::BITS - exponent.leading_zeros()).rev() {
+            result = result.cyclotomic_square();
+            if (exponent >> i) & 1 == 1 {
+                result = result * self;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::element::FieldElement;
+    use crate::unsigned_integer::element::UnsignedInteger;
+
+    fn element() -> Fp12E {
+        Fp12E::new([
+            Fp6E::new([Fp2E::from(3), Fp2E::from(5), Fp2E::from(7)]),
+            Fp6E::new([
+                Fp2E::new([FieldElement::from(11), FieldElement::from(2)]),
+                Fp2E::from(13),
+                Fp2E::from(17),
+            ]),
+        ])
+    }
+
+    /// Maps `element()` into the cyclotomic subgroup with the easy part of the final exponentiation.
+    fn cyclotomic_element() -> Fp12E {
+        let f = element();
+        let f1 = f.conjugate() * f.inv();
+        f1.frobenius().frobenius() * f1
+    }
+
+    #[test]
+    fn frobenius_matches_raising_to_p() {
+        let p = UnsignedInteger::<6>::from_hex_unchecked("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab");
+        let f = element();
+        assert_eq!(f.frobenius(), f.pow(p));
+    }
+
+    #[test]
+    fn cyclotomic_square_matches_square_in_the_cyclotomic_subgroup() {
+        let f = cyclotomic_element();
+        assert_eq!(f.cyclotomic_square(), f.square());
+    }
+
+    #[test]
+    fn compressed_square_matches_square_in_the_cyclotomic_subgroup() {
+        let f = cyclotomic_element();
+        let compressed = CompressedCyclotomicElement::compress(&f).square().square();
+        let decompressed = CompressedCyclotomicElement::batch_decompress(&[compressed]).unwrap();
+        assert_eq!(decompressed[0], f.pow(4_u64));
+    }
+
+    #[test]
+    fn cyclotomic_pow_x_matches_pow() {
+        let f = cyclotomic_element();
+        assert_eq!(f.cyclotomic_pow_x(), f.pow(BLS12381_X).inv());
+    }
+
+    #[test]
+    fn cyclotomic_pow_x_of_one_is_one() {
+        assert_eq!(Fp12E::one().cyclotomic_pow_x(), Fp12E::one());
+    }
+
+    #[test]
+    fn cyclotomic_elements_are_in_the_cyclotomic_subgroup() {
+        assert!(cyclotomic_element().is_in_cyclotomic_subgroup());
+        assert!(!element().is_in_cyclotomic_subgroup());
+    }
+
+    #[test]
+    fn cyclotomic_elements_outside_the_target_group_fail_the_membership_test() {
+        let f = cyclotomic_element();
+        assert!(!f.is_in_target_group());
+        assert!(!element().is_in_target_group());
+        assert!(Fp12E::one().is_in_target_group());
+        assert!(!(-Fp12E::one()).is_in_target_group());
+    }
+
+    #[test]
+    fn torus_decompress_inverts_torus_compress() {
+        let f = cyclotomic_element();
+        let c = f.torus_compress().unwrap();
+        assert_eq!(Fp12E::torus_decompress(&c), f);
+        assert!(Fp12E::one().torus_compress().is_none());
+    }
+
+    #[test]
+    fn cyclotomic_pow_matches_pow() {
+        let f = cyclotomic_element();
+        assert_eq!(f.cyclotomic_pow(0xd20100), f.pow(0xd20100_u64));
+    }
+}
@@ -180,6 +180,20 @@ impl FieldElement<Degree2ExtensionField> {
     pub fn new_base(a_hex: &str) -> Self {
         Self::new([FieldElement::new(U384::from(a_hex)), FieldElement::zero()])
     }
+
+    /// Returns `a0 - a1 * u` where `self` is `a0 + a1 * u`.
+    /// This is the Frobenius map `a -> a^p`.
+    pub fn conjugate(&self) -> Self {
+        let [a0, a1] = self.value();
+        Self::new([a0.clone(), -a1])
+    }
+
+    /// Returns `(1 + u) * self`, where `1 + u` is the non residue
+    /// defining the degree 6 extension.
+    pub fn mul_by_nonresidue(&self) -> Self {
+        let [a0, a1] = self.value();
+        Self::new([a0 - a1, a0 + a1])
+    }
 }
 
 impl FieldElement<Degree6ExtensionField> {
@@ -33,13 +33,25 @@ pub fn sqrt_qfe(
         let b = input.value()[1].clone();
         if b == BLS12381FieldElement::zero() {
             // second part is zero
-            let (y_sqrt_1, y_sqrt_2) = a.sqrt()?;
-            let y_aux = select_sqrt_value_from_third_bit(y_sqrt_1, y_sqrt_2, third_bit);
-
-            Some(BLS12381TwistCurveFieldElement::new([
-                y_aux,
-                BLS12381FieldElement::zero(),
-            ]))
+            match a.sqrt() {
+                Some((y_sqrt_1, y_sqrt_2)) => {
+                    let y_aux = select_sqrt_value_from_third_bit(y_sqrt_1, y_sqrt_2, third_bit);
+                    Some(BLS12381TwistCurveFieldElement::new([
+                        y_aux,
+                        BLS12381FieldElement::zero(),
+                    ]))
+                }
+                None => {
+                    // `a` is not a square in the base field, but since -1 isn't either,
+                    // `-a` is. Then the square roots are `sqrt(-a) * u` with `u^2 = -1`.
+                    let (y_sqrt_1, y_sqrt_2) = (-a).sqrt()?;
+                    let y_aux = select_sqrt_value_from_third_bit(y_sqrt_1, y_sqrt_2, third_bit);
+                    Some(BLS12381TwistCurveFieldElement::new([
+                        BLS12381FieldElement::zero(),
+                        y_aux,
+                    ]))
+                }
+            }
         } else {
             // second part of the input field number is non-zero
             // instead of "sum" is: -beta
@@ -103,6 +115,20 @@ mod tests {
         assert_eq!(value_root[1].clone(), value_qfe_expected[1].clone());
     }
 
+    #[test]
+    fn test_sqrt_qfe_of_non_square_in_base_field() {
+        // 5 is not a square in the base field, so its square roots
+        // in the quadratic extension have zero constant coefficient.
+        let c0 = BLS12381FieldElement::from(5);
+        let c1 = BLS12381FieldElement::zero();
+        let qfe = super::BLS12381TwistCurveFieldElement::new([c0, c1]);
+
+        let root = super::sqrt_qfe(&qfe, 0).unwrap();
+
+        assert_eq!(root.value()[0], BLS12381FieldElement::zero());
+        assert_eq!(root.pow(2_u64), qfe);
+    }
+
     #[test]
     fn test_sqrt_qfe_2() {
         let c0 = BLS12381FieldElement::from_hex("0x02").unwrap();
@@ -1,11 +1,17 @@
 use crate::cyclic_group::IsGroup;
+use crate::elliptic_curve::glv::{GlvLattice, SignedInteger};
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
-use crate::elliptic_curve::traits::IsEllipticCurve;
-use crate::unsigned_integer::element::U384;
+use crate::elliptic_curve::traits::{HasCofactor, HasEndomorphism, IsEllipticCurve};
+use crate::field::fields::montgomery_backed_prime_fields::IsModulus;
+use crate::unsigned_integer::element::{U256, U384};
 use crate::{
     elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
 };
 
+use super::curve::{
+    BLS12381_X, BLS12381_X_SQUARED, BLS12381_X_SQUARED_MINUS_ONE, GLV_ROUNDING_CONSTANT,
+};
+use super::default_types::FrConfig;
 use super::field_extension::{Degree12ExtensionField, Degree2ExtensionField};
 
 const GENERATOR_X_0: U384 = U384::from_hex_unchecked("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8");
@@ -13,6 +19,12 @@ const GENERATOR_X_1: U384 = U384::from_hex_unchecked("13e02b6052719f607dacd3a088
 const GENERATOR_Y_0: U384 = U384::from_hex_unchecked("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801");
 const GENERATOR_Y_1: U384 = U384::from_hex_unchecked("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be");
 
+/// Coefficients of the endomorphism ψ: (x, y) -> (PSI_X * x^p, PSI_Y * y^p), where
+/// PSI_X = 1 / (1 + u)^((p - 1) / 3) and PSI_Y = 1 / (1 + u)^((p - 1) / 2).
+const PSI_X_1: U384 = U384::from_hex_unchecked("1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaad");
+const PSI_Y_0: U384 = U384::from_hex_unchecked("135203e60180a68ee2e9c448d77a2cd91c3dedd930b1cf60ef396489f61eb45e304466cf3e67fa0af1ee7b04121bdea2");
+const PSI_Y_1: U384 = U384::from_hex_unchecked("06af0e0437ff400b6831e36d6bd17ffe48395dabc2d3435e77f76e17009241c5ee67992f72ec05f4c81084fbede3cc09");
+
 /// The description of the curve.
 #[derive(Clone, Debug)]
 pub struct BLS12381TwistCurve;
@@ -37,6 +49,8 @@ impl IsEllipticCurve for BLS12381TwistCurve {
 }
 
 impl IsShortWeierstrass for BLS12381TwistCurve {
+    const HAS_ZCASH_FLAGS: bool = true;
+
     fn a() -> FieldElement<Self::BaseField> {
         FieldElement::zero()
     }
@@ -76,6 +90,67 @@ impl ShortWeierstrassProjectivePoint<BLS12381TwistCurve> {
             [result_x, result_y]
         }
     }
+
+    /// Returns the image of `self` under the untwist-Frobenius-twist endomorphism ψ.
+    /// On the prime order subgroup ψ acts as the multiplication by the BLS parameter `x`.
+    pub fn psi(&self) -> Self {
+        let [x, y, z] = self.coordinates();
+        let psi_x = FieldElement::new([FieldElement::zero(), FieldElement::new(PSI_X_1)]);
+        let psi_y = FieldElement::new([FieldElement::new(PSI_Y_0), FieldElement::new(PSI_Y_1)]);
+        Self::new([x.conjugate() * psi_x, y.conjugate() * psi_y, z.conjugate()])
+    }
+
+    /// Returns `x * self` where `x` is the (negative) BLS parameter.
+    fn mul_by_x(&self) -> Self {
+        self.operate_with_self(BLS12381_X).neg()
+    }
+}
+
+impl HasCofactor for ShortWeierstrassProjectivePoint<BLS12381TwistCurve> {
+    /// Checks that `psi(P) == x * P`, which only holds for points of the
+    /// prime order subgroup. See "Co-factor clearing and subgroup membership
+    /// testing on pairing-friendly curves" (https://eprint.iacr.org/2022/352.pdf).
+    fn is_in_subgroup(&self) -> bool {
+        self.psi() == self.mul_by_x()
+    }
+
+    /// Multiplies `self` by the effective cofactor of RFC 9380, section 8.8.2,
+    /// using the method of Budroni and Pintore (https://eprint.iacr.org/2017/419.pdf).
+    fn clear_cofactor(&self) -> Self {
+        let t1 = self.mul_by_x();
+        let t2 = self.psi();
+        let t3 = self.operate_with(self).psi().psi();
+        let t3 = t3.operate_with(&t2.neg());
+        let t2 = t1.operate_with(&t2).mul_by_x();
+        t3.operate_with(&t2)
+            .operate_with(&t1.neg())
+            .operate_with(&self.neg())
+    }
+}
+
+impl HasEndomorphism<4> for ShortWeierstrassProjectivePoint<BLS12381TwistCurve> {
+    /// `psi^2` acts on G2 as the multiplication by `lambda = x^2`.
+    fn endomorphism(&self) -> Self {
+        self.psi().psi()
+    }
+
+    /// Since `r = x^4 - x^2 + 1`, the vectors `(x^2, -1)` and `(1 - x^2, x^2)` form
+    /// a basis of the lattice of determinant `r`.
+    fn glv_lattice() -> GlvLattice<4> {
+        GlvLattice {
+            order: FrConfig::MODULUS,
+            v1: [
+                SignedInteger::positive(BLS12381_X_SQUARED),
+                SignedInteger::negative(U256::from_u64(1)),
+            ],
+            v2: [
+                SignedInteger::negative(BLS12381_X_SQUARED_MINUS_ONE),
+                SignedInteger::positive(BLS12381_X_SQUARED),
+            ],
+            g1: SignedInteger::positive(GLV_ROUNDING_CONSTANT),
+            g2: SignedInteger::positive(U256::from_u64(2)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -84,14 +159,17 @@ mod tests {
         cyclic_group::IsGroup,
         elliptic_curve::{
             short_weierstrass::{
-                curves::bls12_381::field_extension::{BLS12381PrimeField, Degree2ExtensionField},
+                curves::bls12_381::{
+                    default_types::FrConfig,
+                    field_extension::{BLS12381PrimeField, Degree2ExtensionField},
+                },
                 point::{Endianness, PointFormat, ShortWeierstrassProjectivePoint},
                 traits::IsShortWeierstrass,
             },
-            traits::IsEllipticCurve,
+            traits::{HasCofactor, HasEndomorphism, IsEllipticCurve},
         },
-        field::element::FieldElement,
-        unsigned_integer::element::U384,
+        field::{element::FieldElement, fields::montgomery_backed_prime_fields::IsModulus},
+        unsigned_integer::element::{U256, U384},
     };
 
     use super::BLS12381TwistCurve;
@@ -154,4 +232,65 @@ mod tests {
         let expected = BLS12381TwistCurve::create_point_from_affine(expectedx, expectedy).unwrap();
         assert_eq!(p.operate_with(&q), expected);
     }
+
+    /// A point of the twist outside the prime order subgroup.
+    fn point_not_in_subgroup() -> ShortWeierstrassProjectivePoint<BLS12381TwistCurve> {
+        let x = Level1FE::new([Level0FE::zero(), Level0FE::one()]);
+        let y = Level1FE::new([
+            Level0FE::new(U384::from_hex_unchecked("6af0e0437ff400b6831e36d6bd17ffe48395dabc2d3435e77f76e17009241c5ee67992f72ec05f4c81084fbede3cc09")),
+            Level0FE::new(U384::from_hex_unchecked("5f3e7dd918226781285fd6dffd72cdc8bcb3281ab0b48a3ff4a885bf4fa30d253753470588fee2161cd710c36544690")),
+        ]);
+        BLS12381TwistCurve::create_point_from_affine(x, y).unwrap()
+    }
+
+    #[test]
+    fn psi_acts_as_multiplication_by_x_on_generator() {
+        let g = BLS12381TwistCurve::generator();
+        assert_eq!(g.psi(), g.operate_with_self(super::BLS12381_X).neg());
+    }
+
+    #[test]
+    fn generator_and_its_multiples_are_in_subgroup() {
+        let g = BLS12381TwistCurve::generator();
+        assert!(g.is_in_subgroup());
+        assert!(g.operate_with_self(1234_u16).is_in_subgroup());
+        assert!(
+            ShortWeierstrassProjectivePoint::<BLS12381TwistCurve>::neutral_element()
+                .is_in_subgroup()
+        );
+    }
+
+    #[test]
+    fn point_not_in_subgroup_fails_subgroup_check() {
+        let q = point_not_in_subgroup();
+        assert!(!q.is_in_subgroup());
+        assert!(!q
+            .operate_with(&BLS12381TwistCurve::generator())
+            .is_in_subgroup());
+    }
+
+    #[test]
+    fn clear_cofactor_maps_into_subgroup() {
+        let q = point_not_in_subgroup().clear_cofactor();
+        assert!(!q.is_neutral_element());
+        assert!(q.is_in_subgroup());
+        assert!(q.operate_with_self(FrConfig::MODULUS).is_neutral_element());
+    }
+
+    #[test]
+    fn glv_mul_matches_operate_with_self() {
+        let q = BLS12381TwistCurve::generator().operate_with_self(11_u64);
+        let scalars = [
+            U256::from_u64(3),
+            U256::from_hex_unchecked(
+                "3d0ef8a3d4cf5e36a3d8e4f0c67a12b1e0b1d42f04bc9a4d7c2f2e55d9a8c6a1",
+            ),
+            U256::from_hex_unchecked(
+                "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000",
+            ),
+        ];
+        for k in scalars {
+            assert_eq!(q.glv_mul(&k), q.operate_with_self(k));
+        }
+    }
 }
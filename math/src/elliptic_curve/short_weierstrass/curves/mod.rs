@@ -1,4 +1,9 @@
 pub mod bls12_377;
 pub mod bls12_381;
+pub mod pasta;
+pub mod secp256k1;
+pub mod stark_curve;
 pub mod test_curve_1;
 pub mod test_curve_2;
+pub mod tiny_jub_jub;
+pub mod wei25519;
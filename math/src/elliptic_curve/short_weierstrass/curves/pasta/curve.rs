@@ -0,0 +1,178 @@
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+use crate::elliptic_curve::glv::GlvLattice;
+use crate::elliptic_curve::short_weierstrass::errors::DeserializationError;
+use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
+use crate::elliptic_curve::short_weierstrass::sec1;
+use crate::elliptic_curve::short_weierstrass::traits::{Compress, IsShortWeierstrass};
+use crate::elliptic_curve::traits::{HasCofactor, HasEndomorphism, IsEllipticCurve};
+use crate::field::element::FieldElement;
+use crate::field::fields::montgomery_backed_prime_fields::{IsModulus, U256PrimeField};
+use crate::unsigned_integer::element::U256;
+
+/// Parameters of one of the Pasta curves. Both are `y^2 = x^3 + 5`, over fields
+/// whose moduli are each the order of the group of points of the other curve.
+pub trait IsPastaConfig: Clone + Debug {
+    /// Modulus of the base field.
+    type BaseModulus: IsModulus<U256> + Clone + Debug;
+    /// Modulus of the scalar field, which is the order of the group of points.
+    type ScalarModulus: IsModulus<U256> + Clone + Debug;
+
+    /// A primitive cube root of unity in the base field, such that the map
+    /// (x, y) -> (ZETA * x, y) acts on the group of points as the multiplication by `LAMBDA`.
+    const ZETA: FieldElement<U256PrimeField<Self::BaseModulus>>;
+    const LAMBDA: U256;
+
+    /// Reduced basis of the lattice of determinant the order of the group, with
+    /// vectors of about 128 bits.
+    const GLV_LATTICE: GlvLattice<4>;
+}
+
+/// The curve `y^2 = x^3 + 5` over the base field of the configuration `C`.
+/// For more info, see https://electriccoin.co/blog/the-pasta-curves-for-halo-2-and-beyond/
+#[derive(Clone, Debug)]
+pub struct PastaCurve<C> {
+    phantom: PhantomData<C>,
+}
+
+impl<C: IsPastaConfig> IsEllipticCurve for PastaCurve<C> {
+    type BaseField = U256PrimeField<C::BaseModulus>;
+    type PointRepresentation = ShortWeierstrassProjectivePoint<Self>;
+
+    /// Returns the point (-1, 2), the generator used by the `pasta_curves` crate.
+    fn generator() -> Self::PointRepresentation {
+        Self::PointRepresentation::new([
+            -FieldElement::one(),
+            FieldElement::from(2),
+            FieldElement::one(),
+        ])
+    }
+}
+
+impl<C: IsPastaConfig> IsShortWeierstrass for PastaCurve<C> {
+    fn a() -> FieldElement<Self::BaseField> {
+        FieldElement::from(0)
+    }
+
+    fn b() -> FieldElement<Self::BaseField> {
+        FieldElement::from(5)
+    }
+}
+
+impl<C: IsPastaConfig> Compress for PastaCurve<C> {
+    /// Encodes `point` in the compressed SEC1 format.
+    fn compress(point: &ShortWeierstrassProjectivePoint<Self>) -> Vec<u8> {
+        sec1::compress(point)
+    }
+
+    fn decompress(
+        bytes: &[u8],
+    ) -> Result<ShortWeierstrassProjectivePoint<Self>, DeserializationError> {
+        sec1::decompress(bytes)
+    }
+}
+
+impl<C: IsPastaConfig> ShortWeierstrassProjectivePoint<PastaCurve<C>> {
+    /// Returns the image of `self` under the endomorphism (x, y) -> (ZETA * x, y).
+    pub fn phi(&self) -> Self {
+        let [x, y, z] = self.coordinates();
+        Self::new([x * C::ZETA, y.clone(), z.clone()])
+    }
+}
+
+impl<C: IsPastaConfig> HasCofactor for ShortWeierstrassProjectivePoint<PastaCurve<C>> {
+    /// The group of points has prime order, so every point belongs to it.
+    fn is_in_subgroup(&self) -> bool {
+        true
+    }
+
+    fn clear_cofactor(&self) -> Self {
+        self.clone()
+    }
+}
+
+impl<C: IsPastaConfig> HasEndomorphism<4> for ShortWeierstrassProjectivePoint<PastaCurve<C>> {
+    fn endomorphism(&self) -> Self {
+        self.phi()
+    }
+
+    fn glv_lattice() -> GlvLattice<4> {
+        C::GLV_LATTICE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cyclic_group::IsGroup;
+    use crate::elliptic_curve::short_weierstrass::curves::pasta::{
+        pallas::PallasCurveConfig, vesta::VestaCurveConfig,
+    };
+
+    fn scalars<C: IsPastaConfig>() -> [U256; 4] {
+        [
+            U256::from_u64(0),
+            U256::from_u64(1),
+            C::ScalarModulus::MODULUS - U256::from_u64(1),
+            U256::from_hex_unchecked(
+                "2f1c6e8a4b3d5f7091a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f708",
+            ),
+        ]
+    }
+
+    fn assert_generator_satisfies_defining_equation<C: IsPastaConfig>() {
+        let g = PastaCurve::<C>::generator().to_affine();
+        assert_eq!(
+            PastaCurve::<C>::defining_equation(g.x(), g.y()),
+            FieldElement::zero()
+        );
+    }
+
+    fn assert_order_of_the_group_is_the_scalar_modulus<C: IsPastaConfig>() {
+        let g = PastaCurve::<C>::generator();
+        assert!(g
+            .operate_with_self(C::ScalarModulus::MODULUS)
+            .is_neutral_element());
+        assert!(!g
+            .operate_with_self(C::BaseModulus::MODULUS)
+            .is_neutral_element());
+    }
+
+    fn assert_phi_acts_as_the_multiplication_by_lambda<C: IsPastaConfig>() {
+        let g = PastaCurve::<C>::generator();
+        assert_eq!(g.phi(), g.operate_with_self(C::LAMBDA));
+        assert_eq!(g.phi().phi().phi(), g);
+    }
+
+    fn assert_glv_mul_matches_operate_with_self<C: IsPastaConfig>() {
+        let g = PastaCurve::<C>::generator().operate_with_self(7_u64);
+        for k in scalars::<C>() {
+            assert_eq!(g.glv_mul(&k), g.operate_with_self(k));
+        }
+    }
+
+    #[test]
+    fn generators_satisfy_defining_equation() {
+        assert_generator_satisfies_defining_equation::<PallasCurveConfig>();
+        assert_generator_satisfies_defining_equation::<VestaCurveConfig>();
+    }
+
+    #[test]
+    fn order_of_the_group_is_the_modulus_of_the_scalar_field() {
+        assert_order_of_the_group_is_the_scalar_modulus::<PallasCurveConfig>();
+        assert_order_of_the_group_is_the_scalar_modulus::<VestaCurveConfig>();
+    }
+
+    #[test]
+    fn phi_acts_as_the_multiplication_by_lambda() {
+        assert_phi_acts_as_the_multiplication_by_lambda::<PallasCurveConfig>();
+        assert_phi_acts_as_the_multiplication_by_lambda::<VestaCurveConfig>();
+    }
+
+    #[test]
+    fn glv_mul_matches_operate_with_self() {
+        assert_glv_mul_matches_operate_with_self::<PallasCurveConfig>();
+        assert_glv_mul_matches_operate_with_self::<VestaCurveConfig>();
+    }
+}
@@ -0,0 +1,59 @@
+use crate::elliptic_curve::glv::{GlvLattice, SignedInteger};
+use crate::elliptic_curve::short_weierstrass::curves::pasta::curve::{IsPastaConfig, PastaCurve};
+use crate::field::element::FieldElement;
+use crate::field::fields::fft_friendly::pasta_prime_fields::{
+    MontgomeryConfigPallasPrimeField, MontgomeryConfigVestaPrimeField, PallasPrimeField,
+    VestaPrimeField,
+};
+use crate::field::fields::montgomery_backed_prime_fields::IsModulus;
+use crate::unsigned_integer::element::U256;
+
+pub type VestaFieldElement = FieldElement<VestaPrimeField>;
+
+/// The scalar field of Vesta, which is the base field of Pallas.
+pub type VestaScalarField = PallasPrimeField;
+
+/// A primitive cube root of unity in the base field. The map
+/// (x, y) -> (ZETA * x, y) acts on the group of points as the multiplication by
+/// `0x12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab9`.
+pub const ZETA: VestaFieldElement = VestaFieldElement::from_hex_unchecked(
+    "06819a58283e528e511db4d81cf70f5a0fed467d47c033af2aa9d2e050aa0e4f",
+);
+
+#[derive(Clone, Debug)]
+pub struct VestaCurveConfig;
+
+/// The base field has order `q = 2^254 + 45560315531506369815346746415080538113`,
+/// and the group of points has prime order `p`, the order of the base field of Pallas.
+impl IsPastaConfig for VestaCurveConfig {
+    type BaseModulus = MontgomeryConfigVestaPrimeField;
+    type ScalarModulus = MontgomeryConfigPallasPrimeField;
+
+    const ZETA: VestaFieldElement = ZETA;
+    const LAMBDA: U256 = U256::from_hex_unchecked(
+        "12ccca834acdba712caad5dc57aab1b01d1f8bd237ad31491dad5ebdfdfe4ab9",
+    );
+
+    const GLV_LATTICE: GlvLattice<4> = GlvLattice {
+        order: MontgomeryConfigPallasPrimeField::MODULUS,
+        v1: [
+            SignedInteger::positive(U256::from_hex_unchecked("49e69d1640a899538cb1279300000001")),
+            SignedInteger::positive(U256::from_hex_unchecked("93cd3a2c8198e2690c7c095a00000001")),
+        ],
+        v2: [
+            SignedInteger::negative(U256::from_hex_unchecked("49e69d1640f049157fcae1c700000000")),
+            SignedInteger::positive(U256::from_hex_unchecked("49e69d1640a899538cb1279300000001")),
+        ],
+        g1: SignedInteger::positive(U256::from_hex_unchecked(
+            "1279a745902a2654e32c49e4c00000003",
+        )),
+        g2: SignedInteger::negative(U256::from_hex_unchecked(
+            "24f34e8b2066389a431f0256800000003",
+        )),
+    };
+}
+
+/// The curve `y^2 = x^3 + 5` over the base field of order `q`. Its group of points
+/// has order `p`, and the group of points of Pallas has order `q`, so the two curves
+/// form a cycle.
+pub type VestaCurve = PastaCurve<VestaCurveConfig>;
@@ -0,0 +1,139 @@
+use super::curve::{Secp256k1Curve, Secp256k1FieldElement};
+use crate::elliptic_curve::short_weierstrass::{
+    errors::DeserializationError, point::ShortWeierstrassProjectivePoint, sec1, traits::Compress,
+};
+
+impl Secp256k1Curve {
+    /// Returns the point with abscissa `x` whose ordinate has the given parity,
+    /// or `None` if `x` is not the abscissa of a point of the curve.
+    pub fn lift_x(
+        x: &Secp256k1FieldElement,
+        y_is_odd: bool,
+    ) -> Option<ShortWeierstrassProjectivePoint<Self>> {
+        sec1::lift_x(x, y_is_odd)
+    }
+
+    /// Returns the SEC1 encoding of `point`, compressed or not. See [`sec1::to_sec1`].
+    pub fn to_sec1(point: &ShortWeierstrassProjectivePoint<Self>, compressed: bool) -> Vec<u8> {
+        sec1::to_sec1(point, compressed)
+    }
+
+    /// Decodes a point in any of the SEC1 encodings, checking that it belongs to the curve.
+    pub fn from_sec1(
+        bytes: &[u8],
+    ) -> Result<ShortWeierstrassProjectivePoint<Self>, DeserializationError> {
+        sec1::from_sec1(bytes)
+    }
+}
+
+impl Compress for Secp256k1Curve {
+    /// Encodes `point` in the compressed SEC1 format.
+    fn compress(point: &ShortWeierstrassProjectivePoint<Self>) -> Vec<u8> {
+        sec1::compress(point)
+    }
+
+    /// Decodes a point in the compressed SEC1 format, or the point at infinity.
+    fn decompress(
+        bytes: &[u8],
+    ) -> Result<ShortWeierstrassProjectivePoint<Self>, DeserializationError> {
+        sec1::decompress(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cyclic_group::IsGroup,
+        elliptic_curve::{short_weierstrass::sec1::COMPRESSED_EVEN_TAG, traits::IsEllipticCurve},
+        unsigned_integer::element::U256,
+    };
+
+    const GENERATOR_COMPRESSED: &str =
+        "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+    const GENERATOR_UNCOMPRESSED: &str = "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn generator_encodings_match_the_standard() {
+        let g = Secp256k1Curve::generator();
+        assert_eq!(
+            Secp256k1Curve::to_sec1(&g, true),
+            from_hex(GENERATOR_COMPRESSED)
+        );
+        assert_eq!(
+            Secp256k1Curve::to_sec1(&g, false),
+            from_hex(GENERATOR_UNCOMPRESSED)
+        );
+    }
+
+    #[test]
+    fn sec1_roundtrip() {
+        let g = Secp256k1Curve::generator();
+        for point in [
+            g.clone(),
+            g.operate_with_self(2u64),
+            g.operate_with_self(U256::from_hex_unchecked("deadbeef"))
+                .neg(),
+            ShortWeierstrassProjectivePoint::neutral_element(),
+        ] {
+            for compressed in [true, false] {
+                let bytes = Secp256k1Curve::to_sec1(&point, compressed);
+                assert_eq!(Secp256k1Curve::from_sec1(&bytes).unwrap(), point);
+            }
+            assert_eq!(
+                Secp256k1Curve::decompress(&Secp256k1Curve::compress(&point)).unwrap(),
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn from_sec1_rejects_invalid_encodings() {
+        let mut bytes = from_hex(GENERATOR_UNCOMPRESSED);
+        assert_eq!(
+            Secp256k1Curve::from_sec1(&bytes[..64]),
+            Err(DeserializationError::InvalidAmountOfBytes)
+        );
+        bytes[64] ^= 1;
+        assert_eq!(
+            Secp256k1Curve::from_sec1(&bytes),
+            Err(DeserializationError::FieldFromBytesError)
+        );
+        bytes[0] = 0x05;
+        assert_eq!(
+            Secp256k1Curve::from_sec1(&bytes),
+            Err(DeserializationError::InvalidFlags)
+        );
+        assert_eq!(
+            Secp256k1Curve::decompress(&from_hex(GENERATOR_UNCOMPRESSED)),
+            Err(DeserializationError::InvalidFlags)
+        );
+    }
+
+    #[test]
+    fn from_sec1_rejects_non_canonical_or_invalid_abscissas() {
+        let mut modulus = vec![COMPRESSED_EVEN_TAG];
+        modulus.extend(from_hex(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+        ));
+        assert_eq!(
+            Secp256k1Curve::from_sec1(&modulus),
+            Err(DeserializationError::FieldFromBytesError)
+        );
+        // x^3 + 7 is not a square for x = 5.
+        let mut bytes = vec![COMPRESSED_EVEN_TAG; 1];
+        bytes.extend([0; 31]);
+        bytes.push(5);
+        assert_eq!(
+            Secp256k1Curve::from_sec1(&bytes),
+            Err(DeserializationError::FieldFromBytesError)
+        );
+    }
+}
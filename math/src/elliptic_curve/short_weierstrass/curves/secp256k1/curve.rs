@@ -0,0 +1,100 @@
+use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
+use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::field::fields::montgomery_backed_prime_fields::{
+    IsModulus, MontgomeryBackendPrimeField,
+};
+use crate::unsigned_integer::element::U256;
+use crate::{
+    elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
+};
+
+#[derive(Clone, Debug)]
+pub struct Secp256k1FieldModulus;
+impl IsModulus<U256> for Secp256k1FieldModulus {
+    const MODULUS: U256 = U256::from_hex_unchecked(
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+    );
+}
+
+pub type Secp256k1PrimeField = MontgomeryBackendPrimeField<Secp256k1FieldModulus, 4>;
+pub type Secp256k1FieldElement = FieldElement<Secp256k1PrimeField>;
+
+/// The curve `y^2 = x^3 + 7` used by Bitcoin and Ethereum signatures, as specified
+/// in "SEC 2: Recommended Elliptic Curve Domain Parameters" (https://www.secg.org/sec2-v2.pdf).
+/// Its group of points has prime order, so every point other than the neutral element
+/// is a generator.
+#[derive(Clone, Debug)]
+pub struct Secp256k1Curve;
+
+impl IsEllipticCurve for Secp256k1Curve {
+    type BaseField = Secp256k1PrimeField;
+    type PointRepresentation = ShortWeierstrassProjectivePoint<Self>;
+
+    fn generator() -> Self::PointRepresentation {
+        Self::PointRepresentation::new([
+            Secp256k1FieldElement::from_hex_unchecked(
+                "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            ),
+            Secp256k1FieldElement::from_hex_unchecked(
+                "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+            ),
+            FieldElement::one(),
+        ])
+    }
+}
+
+impl IsShortWeierstrass for Secp256k1Curve {
+    fn a() -> FieldElement<Self::BaseField> {
+        FieldElement::from(0)
+    }
+
+    fn b() -> FieldElement<Self::BaseField> {
+        FieldElement::from(7)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cyclic_group::IsGroup,
+        elliptic_curve::short_weierstrass::curves::secp256k1::default_types::FrConfig,
+    };
+
+    #[test]
+    fn generator_satisfies_defining_equation() {
+        let g = Secp256k1Curve::generator().to_affine();
+        assert_eq!(
+            Secp256k1Curve::defining_equation(g.x(), g.y()),
+            FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn generator_has_order_n() {
+        let g = Secp256k1Curve::generator();
+        assert!(g.operate_with_self(FrConfig::MODULUS).is_neutral_element());
+    }
+
+    #[test]
+    fn doubling_the_generator_matches_addition() {
+        let g = Secp256k1Curve::generator();
+        let expected = Secp256k1Curve::create_point_from_affine(
+            Secp256k1FieldElement::from_hex_unchecked(
+                "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+            ),
+            Secp256k1FieldElement::from_hex_unchecked(
+                "1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+            ),
+        )
+        .unwrap();
+        assert_eq!(g.operate_with(&g), expected);
+        assert_eq!(g.operate_with_self(2u64), expected);
+    }
+
+    #[test]
+    fn field_inverse_works_for_a_modulus_without_spare_bits() {
+        let a = -Secp256k1FieldElement::from(3);
+        assert_eq!(&a * a.inv(), FieldElement::one());
+    }
+}
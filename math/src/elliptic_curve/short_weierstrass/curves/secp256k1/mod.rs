@@ -0,0 +1,3 @@
+pub mod compression;
+pub mod curve;
+pub mod default_types;
@@ -0,0 +1,136 @@
+use crate::elliptic_curve::short_weierstrass::errors::DeserializationError;
+use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
+use crate::elliptic_curve::short_weierstrass::sec1;
+use crate::elliptic_curve::short_weierstrass::traits::{Compress, IsShortWeierstrass};
+use crate::elliptic_curve::traits::{HasCofactor, IsEllipticCurve};
+use crate::field::element::FieldElement;
+use crate::field::fields::fft_friendly::stark_252_prime_field::Stark252PrimeField;
+
+pub type StarkFieldElement = FieldElement<Stark252PrimeField>;
+
+/// The curve `y^2 = x^3 + x + beta` over the field of order `2^251 + 17 * 2^192 + 1`,
+/// used by Starknet and StarkEx for ECDSA signatures and Pedersen hashes, where
+/// `beta = 0x6f21413efbe40de150e596d72f7a8c5609ad26c15c915c1f4cdfcb99cee9e89`.
+/// Its group of points has prime order.
+/// For more info, see https://docs.starkware.co/starkex/crypto/stark-curve.html
+#[derive(Clone, Debug)]
+pub struct StarkCurve;
+
+impl IsEllipticCurve for StarkCurve {
+    type BaseField = Stark252PrimeField;
+    type PointRepresentation = ShortWeierstrassProjectivePoint<Self>;
+
+    /// Returns the generator used by Starknet ECDSA, `EC_GEN` in cairo-lang.
+    fn generator() -> Self::PointRepresentation {
+        Self::PointRepresentation::new([
+            StarkFieldElement::from_hex_unchecked(
+                "1ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca",
+            ),
+            StarkFieldElement::from_hex_unchecked(
+                "5668060aa49730b7be4801df46ec62de53ecd11abe43a32873000c36e8dc1f",
+            ),
+            FieldElement::one(),
+        ])
+    }
+}
+
+impl IsShortWeierstrass for StarkCurve {
+    fn a() -> FieldElement<Self::BaseField> {
+        FieldElement::one()
+    }
+
+    fn b() -> FieldElement<Self::BaseField> {
+        StarkFieldElement::from_hex_unchecked(
+            "6f21413efbe40de150e596d72f7a8c5609ad26c15c915c1f4cdfcb99cee9e89",
+        )
+    }
+}
+
+impl StarkCurve {
+    /// Returns the point with abscissa `x` whose ordinate has the given parity,
+    /// or `None` if `x` is not the abscissa of a point of the curve.
+    pub fn lift_x(
+        x: &StarkFieldElement,
+        y_is_odd: bool,
+    ) -> Option<ShortWeierstrassProjectivePoint<Self>> {
+        sec1::lift_x(x, y_is_odd)
+    }
+}
+
+impl Compress for StarkCurve {
+    /// Encodes `point` in the compressed SEC1 format.
+    fn compress(point: &ShortWeierstrassProjectivePoint<Self>) -> Vec<u8> {
+        sec1::compress(point)
+    }
+
+    fn decompress(
+        bytes: &[u8],
+    ) -> Result<ShortWeierstrassProjectivePoint<Self>, DeserializationError> {
+        sec1::decompress(bytes)
+    }
+}
+
+impl HasCofactor for ShortWeierstrassProjectivePoint<StarkCurve> {
+    /// The group of points has prime order, so every point belongs to it.
+    fn is_in_subgroup(&self) -> bool {
+        true
+    }
+
+    fn clear_cofactor(&self) -> Self {
+        self.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cyclic_group::IsGroup,
+        elliptic_curve::short_weierstrass::curves::stark_curve::default_types::FrConfig,
+        field::fields::montgomery_backed_prime_fields::IsModulus, unsigned_integer::element::U256,
+    };
+
+    #[test]
+    fn generator_satisfies_defining_equation() {
+        let g = StarkCurve::generator().to_affine();
+        assert_eq!(
+            StarkCurve::defining_equation(g.x(), g.y()),
+            FieldElement::zero()
+        );
+    }
+
+    #[test]
+    fn generator_has_order_n() {
+        let g = StarkCurve::generator();
+        assert!(g.operate_with_self(FrConfig::MODULUS).is_neutral_element());
+        assert!(!g
+            .operate_with_self(FrConfig::MODULUS - U256::from_u64(1))
+            .is_neutral_element());
+    }
+
+    // Public key of the private key
+    // 0x3c1e9550e66958296d11b60f8e8e7a7ad990d07fa65d5f7652c4a6c87d4e3cc, from starknet-rs.
+    #[test]
+    fn public_key_matches_starknet_vector() {
+        let private_key = U256::from_hex_unchecked(
+            "03c1e9550e66958296d11b60f8e8e7a7ad990d07fa65d5f7652c4a6c87d4e3cc",
+        );
+        let public_key = StarkCurve::generator()
+            .operate_with_self(private_key)
+            .to_affine();
+        assert_eq!(
+            *public_key.x(),
+            StarkFieldElement::from_hex_unchecked(
+                "077a3b314db07c45076d11f62b6f9e748a39790441823307743cf00d6597ea43"
+            )
+        );
+    }
+
+    #[test]
+    fn lift_x_recovers_the_generator() {
+        let g = StarkCurve::generator().to_affine();
+        let y_is_odd = g.y().representative().limbs[3] & 1 == 1;
+        assert_eq!(StarkCurve::lift_x(g.x(), y_is_odd).unwrap(), g);
+        assert_eq!(StarkCurve::lift_x(g.x(), !y_is_odd).unwrap(), g.neg());
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{
+    field::{
+        element::FieldElement,
+        fields::montgomery_backed_prime_fields::{IsModulus, MontgomeryBackendPrimeField},
+    },
+    unsigned_integer::element::U256,
+};
+
+#[derive(Clone, Debug)]
+pub struct FrConfig;
+
+/// Order of the group of points of the STARK curve
+impl IsModulus<U256> for FrConfig {
+    const MODULUS: U256 = U256::from_hex_unchecked(
+        "0800000000000010ffffffffffffffffb781126dcae7b2321e66a241adc64d2f",
+    );
+}
+
+/// FrField using MontgomeryBackend for the STARK curve
+pub type FrField = MontgomeryBackendPrimeField<FrConfig, 4>;
+/// FrElement using MontgomeryBackend for the STARK curve
+pub type FrElement = FieldElement<FrField>;
@@ -3,7 +3,10 @@
 /// This small curve has only 5 elements.
 use crate::{
     elliptic_curve::{
-        short_weierstrass::{point::ShortWeierstrassProjectivePoint, traits::IsShortWeierstrass},
+        short_weierstrass::{
+            pairing::IsPairingFriendly, point::ShortWeierstrassProjectivePoint,
+            traits::IsShortWeierstrass,
+        },
         traits::IsEllipticCurve,
     },
     field::{
@@ -58,3 +61,36 @@ impl IsShortWeierstrass for TestCurve1 {
         FieldElement::from(0)
     }
 }
+
+/// The curve is supersingular with embedding degree 2. G1 and G2 are both the subgroup of
+/// order 5 of the points over 𝔽p, and G2 is mapped to the curve over 𝔽p² with the
+/// distortion map `(x, y) -> (-x, iy)`, which gives symmetric pairings.
+impl IsPairingFriendly for TestCurve1 {
+    type G2Curve = Self;
+    type OutputField = QuadraticExtensionField<TestCurveQuadraticNonResidue>;
+
+    const SUBGROUP_ORDER: &'static [u64] = &[TEST_CURVE_1_MAIN_SUBGROUP_ORDER];
+    /// `p` is congruent to `r - 1` modulo `r`. The ate pairing with `t - 1 = -1` is degenerate.
+    const ATE_LOOP_PARAMETER: &'static [u64] = &[TEST_CURVE_1_MAIN_SUBGROUP_ORDER - 1];
+    const ATE_LOOP_PARAMETER_IS_NEGATIVE: bool = false;
+
+    fn embed(a: &FieldElement<Self::BaseField>) -> FieldElement<Self::OutputField> {
+        a.clone()
+    }
+
+    fn g2_to_output(
+        q: &ShortWeierstrassProjectivePoint<Self>,
+    ) -> [FieldElement<Self::OutputField>; 2] {
+        let q = q.to_affine();
+        let i = FieldElement::new([FieldElement::zero(), FieldElement::one()]);
+        [-q.x(), i * q.y()]
+    }
+
+    /// Raises `f` to `(p^2 - 1) / r = (p - 1) (p + 1) / r`, with `f^p` being the conjugate of `f`.
+    fn final_exponentiation(
+        f: &FieldElement<Self::OutputField>,
+    ) -> FieldElement<Self::OutputField> {
+        (f.conjugate() * f.inv())
+            .pow((TEST_CURVE_1_PRIME_FIELD_ORDER + 1) / TEST_CURVE_1_MAIN_SUBGROUP_ORDER)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::cyclic_group::IsGroup;
+use crate::elliptic_curve::short_weierstrass::pairing::IsPairingFriendly;
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
 use crate::elliptic_curve::traits::IsEllipticCurve;
 use crate::field::fields::montgomery_backed_prime_fields::{
@@ -20,6 +22,13 @@ pub const TEST_CURVE_2_PRIME_FIELD_ORDER: U384 =
 pub const TEST_CURVE_2_MAIN_SUBGROUP_ORDER: U384 =
     U384::from_hex_unchecked("40a065fb5a76390de709fb229");
 
+/// `(p + 1) / r`, the hard part of the final exponentiation.
+const TEST_CURVE_2_COFACTOR: u64 = 22376893008;
+
+/// `r - 1`, which is congruent to `p` modulo `r`.
+const TEST_CURVE_2_ATE_LOOP_PARAMETER: U384 =
+    U384::sub(&TEST_CURVE_2_MAIN_SUBGROUP_ORDER, &U384::from_u64(1)).0;
+
 // FPBLS12381
 #[derive(Clone, Debug)]
 pub struct TestCurve2Modulus;
@@ -82,3 +91,54 @@ impl IsShortWeierstrass for TestCurve2 {
         FieldElement::from(1)
     }
 }
+
+impl TestCurve2 {
+    /// Returns the image of `p` by the Frobenius endomorphism `π`, which
+    /// conjugates its coordinates.
+    fn frobenius(
+        p: &ShortWeierstrassProjectivePoint<Self>,
+    ) -> ShortWeierstrassProjectivePoint<Self> {
+        let [x, y, z] = p.coordinates();
+        ShortWeierstrassProjectivePoint::new([x.conjugate(), y.conjugate(), z.conjugate()])
+    }
+}
+
+/// The curve is supersingular with embedding degree 2. G1 and G2 are both the subgroup of
+/// order `r` generated by the generator, which is not defined over 𝔽p. It is mapped to the
+/// eigenspaces of the Frobenius endomorphism `π` with the trace `P + π(P)` for G1,
+/// whose image is defined over 𝔽p, and with `Q - π(Q)` for G2, whose image is in the
+/// eigenspace of eigenvalue `p`, as the ate pairing requires.
+impl IsPairingFriendly for TestCurve2 {
+    type G2Curve = Self;
+    type OutputField = QuadraticExtensionField<TestCurve2QuadraticNonResidue>;
+
+    const SUBGROUP_ORDER: &'static [u64] = &TEST_CURVE_2_MAIN_SUBGROUP_ORDER.limbs;
+    /// The ate pairing with `t - 1 = -1` is degenerate.
+    const ATE_LOOP_PARAMETER: &'static [u64] = &TEST_CURVE_2_ATE_LOOP_PARAMETER.limbs;
+    const ATE_LOOP_PARAMETER_IS_NEGATIVE: bool = false;
+
+    fn embed(a: &FieldElement<Self::BaseField>) -> FieldElement<Self::OutputField> {
+        a.clone()
+    }
+
+    fn g1_to_output(
+        p: &ShortWeierstrassProjectivePoint<Self>,
+    ) -> [FieldElement<Self::OutputField>; 2] {
+        let image = p.operate_with(&Self::frobenius(p)).to_affine();
+        [image.x().clone(), image.y().clone()]
+    }
+
+    fn g2_to_output(
+        q: &ShortWeierstrassProjectivePoint<Self>,
+    ) -> [FieldElement<Self::OutputField>; 2] {
+        let image = q.operate_with(&Self::frobenius(q).neg()).to_affine();
+        [image.x().clone(), image.y().clone()]
+    }
+
+    /// Raises `f` to `(p^2 - 1) / r = (p - 1) (p + 1) / r`, with `f^p` being the conjugate of `f`.
+    fn final_exponentiation(
+        f: &FieldElement<Self::OutputField>,
+    ) -> FieldElement<Self::OutputField> {
+        (f.conjugate() * f.inv()).pow(TEST_CURVE_2_COFACTOR)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{
+    elliptic_curve::{
+        short_weierstrass::{
+            errors::DeserializationError,
+            point::ShortWeierstrassProjectivePoint,
+            sec1,
+            traits::{Compress, IsShortWeierstrass},
+        },
+        traits::IsEllipticCurve,
+    },
+    field::{element::FieldElement, fields::u64_prime_field::U64PrimeField},
+};
+
+/// Taken from moonmath manual page 74
+#[derive(Debug, Clone)]
+pub struct TinyJubJubWeierstrass;
+
+impl IsEllipticCurve for TinyJubJubWeierstrass {
+    type BaseField = U64PrimeField<13>;
+    type PointRepresentation = ShortWeierstrassProjectivePoint<Self>;
+
+    fn generator() -> Self::PointRepresentation {
+        Self::PointRepresentation::new([
+            FieldElement::from(10),
+            FieldElement::from(10),
+            FieldElement::one(),
+        ])
+    }
+}
+
+impl IsShortWeierstrass for TinyJubJubWeierstrass {
+    fn a() -> FieldElement<Self::BaseField> {
+        FieldElement::from(8)
+    }
+
+    fn b() -> FieldElement<Self::BaseField> {
+        FieldElement::from(8)
+    }
+}
+
+impl Compress for TinyJubJubWeierstrass {
+    /// Encodes `point` in the compressed SEC1 format.
+    fn compress(point: &ShortWeierstrassProjectivePoint<Self>) -> Vec<u8> {
+        sec1::compress(point)
+    }
+
+    fn decompress(
+        bytes: &[u8],
+    ) -> Result<ShortWeierstrassProjectivePoint<Self>, DeserializationError> {
+        sec1::decompress(bytes)
+    }
+}
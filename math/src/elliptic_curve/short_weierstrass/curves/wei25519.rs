@@ -0,0 +1,75 @@
+use crate::elliptic_curve::edwards::curves::ed25519::curve::{
+    Ed25519FieldElement, Ed25519PrimeField,
+};
+use crate::elliptic_curve::short_weierstrass::errors::DeserializationError;
+use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
+use crate::elliptic_curve::short_weierstrass::sec1;
+use crate::elliptic_curve::short_weierstrass::traits::{Compress, IsShortWeierstrass};
+use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::field::element::FieldElement;
+
+/// The short Weierstrass model of Curve25519 and edwards25519, over the field of
+/// `2^255 - 19` elements. It lets algorithms written for short Weierstrass curves,
+/// like hash to curve, be used with these curves.
+/// For more info, see https://datatracker.ietf.org/doc/draft-ietf-lwig-curve-representations/
+#[derive(Clone, Debug)]
+pub struct Wei25519Curve;
+
+impl IsEllipticCurve for Wei25519Curve {
+    type BaseField = Ed25519PrimeField;
+    type PointRepresentation = ShortWeierstrassProjectivePoint<Self>;
+
+    /// Returns the image of the base point of Curve25519.
+    fn generator() -> Self::PointRepresentation {
+        Self::PointRepresentation::new([
+            Ed25519FieldElement::from_hex_unchecked(
+                "2aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaad245a",
+            ),
+            Ed25519FieldElement::from_hex_unchecked(
+                "20ae19a1b8a086b4e01edd2c7748d14c923d4d7e6d7c61b229e9c5a27eced3d9",
+            ),
+            FieldElement::one(),
+        ])
+    }
+}
+
+impl IsShortWeierstrass for Wei25519Curve {
+    fn a() -> FieldElement<Self::BaseField> {
+        Ed25519FieldElement::from_hex_unchecked(
+            "2aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa984914a144",
+        )
+    }
+
+    fn b() -> FieldElement<Self::BaseField> {
+        Ed25519FieldElement::from_hex_unchecked(
+            "7b425ed097b425ed097b425ed097b425ed097b425ed097b4260b5e9c7710c864",
+        )
+    }
+}
+
+impl Compress for Wei25519Curve {
+    /// Encodes `point` in the compressed SEC1 format.
+    fn compress(point: &ShortWeierstrassProjectivePoint<Self>) -> Vec<u8> {
+        sec1::compress(point)
+    }
+
+    fn decompress(
+        bytes: &[u8],
+    ) -> Result<ShortWeierstrassProjectivePoint<Self>, DeserializationError> {
+        sec1::decompress(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generator_satisfies_defining_equation() {
+        let g = Wei25519Curve::generator();
+        assert_eq!(
+            Wei25519Curve::defining_equation(g.x(), g.y()),
+            FieldElement::zero()
+        );
+    }
+}
@@ -10,6 +10,10 @@ pub enum DeserializationError {
     FieldFromBytesError,
     #[error("Error trying to load a pointer bigger than the supported architecture")]
     PointerSizeError,
+    #[error("Invalid flags in the encoding of a point")]
+    InvalidFlags,
+    #[error("Point is not in the prime order subgroup")]
+    NotInSubgroup,
 }
 
 impl From<ByteConversionError> for DeserializationError {
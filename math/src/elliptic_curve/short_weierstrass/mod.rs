@@ -2,7 +2,13 @@
 pub mod curves;
 /// Shared errors
 pub mod errors;
+/// Generic Miller loop and pairings for pairing friendly curves.
+pub mod pairing;
 /// Structs for points
 pub mod point;
+/// SEC1 encodings of points of curves over prime fields.
+pub mod sec1;
 /// Common behaviour for Elliptic curves.
 pub mod traits;
+/// Compressed encoding of points with the flags of the Zcash encoding of BLS12-381.
+pub mod zcash;
@@ -0,0 +1,443 @@
+use super::{point::ShortWeierstrassProjectivePoint, traits::IsShortWeierstrass};
+use crate::{
+    cyclic_group::IsGroup,
+    elliptic_curve::traits::IsPairing,
+    field::{element::FieldElement, traits::IsField},
+};
+use core::marker::PhantomData;
+
+/// A curve with pairings computed by the generic Miller loop of this module.
+/// G1 is a subgroup of order `r` of the points of the curve and G2 a subgroup of order `r`
+/// of the points of `G2Curve`, which can be the curve itself or one of its twists.
+/// Both are mapped to the curve over the output field 𝔽pᵏ, where the Miller functions
+/// are computed in affine coordinates. This is much slower than a pairing specialized
+/// to the tower of extensions of a curve, like `BLS12381AtePairing`, but it only needs
+/// the parameters of the curve. BLS12-381 and BLS12-377 implement it too, which allows
+/// checking their specialized pairings against the generic ones.
+pub trait IsPairingFriendly: IsShortWeierstrass {
+    type G2Curve: IsShortWeierstrass;
+    type OutputField: IsField;
+
+    /// Order `r` of G1 and G2 as big endian limbs. It is the loop parameter of the Tate pairing.
+    const SUBGROUP_ORDER: &'static [u64];
+
+    /// Absolute value of the loop parameter of the ate pairing as big endian limbs.
+    /// It must be congruent to `p` or `-p` modulo `r`, such as `t - 1` for the ate pairing
+    /// or `x` for the optimal ate pairing of a BLS12 curve.
+    const ATE_LOOP_PARAMETER: &'static [u64];
+
+    /// Whether the loop parameter of the ate pairing is negative.
+    const ATE_LOOP_PARAMETER_IS_NEGATIVE: bool;
+
+    /// Embeds an element of the base field of the curve in the output field.
+    fn embed(a: &FieldElement<Self::BaseField>) -> FieldElement<Self::OutputField>;
+
+    /// Maps a point of G2, which is not the neutral element, to the curve over the
+    /// output field, with an untwisting isomorphism or a distortion map.
+    /// Returns its affine coordinates.
+    fn g2_to_output(
+        q: &ShortWeierstrassProjectivePoint<Self::G2Curve>,
+    ) -> [FieldElement<Self::OutputField>; 2];
+
+    /// Raises the result of the Miller loop to the power `(p^k - 1) / r`.
+    fn final_exponentiation(f: &FieldElement<Self::OutputField>)
+        -> FieldElement<Self::OutputField>;
+
+    /// Maps a point of G1, which is not the neutral element, to the curve over the
+    /// output field. Returns its affine coordinates.
+    fn g1_to_output(
+        p: &ShortWeierstrassProjectivePoint<Self>,
+    ) -> [FieldElement<Self::OutputField>; 2] {
+        let p = p.to_affine();
+        [Self::embed(p.x()), Self::embed(p.y())]
+    }
+}
+
+/// Returns the bits of the integer with big endian `limbs` from the most significant
+/// one, skipping it.
+fn loop_bits(limbs: &[u64]) -> impl Iterator<Item = bool> + '_ {
+    limbs
+        .iter()
+        .flat_map(|limb| (0..u64::BITS).rev().map(move |i| (limb >> i) & 1 == 1))
+        .skip_while(|bit| !bit)
+        .skip(1)
+}
+
+/// A line of the Miller loop evaluated at a point `(x, y)`.
+#[derive(Clone, Debug)]
+enum MillerLine<F: IsField> {
+    /// The line `y - slope * x - intercept` through the running point, divided
+    /// by the vertical line `x - vertical` through the resulting point.
+    Chord {
+        slope: FieldElement<F>,
+        intercept: FieldElement<F>,
+        vertical: FieldElement<F>,
+    },
+    /// The vertical line `x - vertical`, when the resulting point is the neutral element.
+    Vertical(FieldElement<F>),
+}
+
+#[derive(Clone, Debug)]
+enum MillerStep<F: IsField> {
+    /// Squares the accumulator.
+    Square,
+    /// Multiplies the accumulator by a line.
+    Line(MillerLine<F>),
+    /// Inverts the accumulator.
+    Invert,
+}
+
+/// The steps of the Miller loop computing the function `f_{n, T}`, which only depend
+/// on `n` and on the point `T`. They can be evaluated at any other point.
+#[derive(Clone, Debug)]
+pub struct MillerLines<F: IsField> {
+    steps: Vec<MillerStep<F>>,
+}
+
+impl<F: IsField> MillerLines<F> {
+    /// Computes the steps of the Miller loop of `f_{n, T}`, where `T` is a point of a
+    /// curve with coefficient `a` given in affine coordinates and `|n|` has big endian `limbs`.
+    pub fn new(
+        t: &[FieldElement<F>; 2],
+        a: &FieldElement<F>,
+        limbs: &[u64],
+        negative: bool,
+    ) -> Self {
+        let mut steps = Vec::new();
+        // The running point, `None` being the neutral element.
+        let mut r = Some(t.clone());
+        for bit in loop_bits(limbs) {
+            steps.push(MillerStep::Square);
+            if let Some(current) = &r {
+                let (line, next) = Self::double_step(current, a);
+                steps.push(MillerStep::Line(line));
+                r = next;
+            }
+            if bit {
+                match &r {
+                    Some(current) => {
+                        let (line, next) = Self::add_step(current, t, a);
+                        steps.push(MillerStep::Line(line));
+                        r = next;
+                    }
+                    // The line through the neutral element and T is the vertical through T,
+                    // which cancels out with the denominator.
+                    None => r = Some(t.clone()),
+                }
+            }
+        }
+        if negative {
+            // f_{-n, T} = 1 / (f_{n, T} (x - x_{nT}))
+            if let Some([x, _]) = r {
+                steps.push(MillerStep::Line(MillerLine::Vertical(x)));
+            }
+            steps.push(MillerStep::Invert);
+        }
+        Self { steps }
+    }
+
+    /// Returns the tangent line at `r` and the point `2r`.
+    fn double_step(
+        [x, y]: &[FieldElement<F>; 2],
+        a: &FieldElement<F>,
+    ) -> (MillerLine<F>, Option<[FieldElement<F>; 2]>) {
+        if *y == FieldElement::zero() {
+            return (MillerLine::Vertical(x.clone()), None);
+        }
+        let x_squared = x.square();
+        let slope = (&x_squared + &x_squared + &x_squared + a) / (y + y);
+        Self::chord(slope, [x, y], x)
+    }
+
+    /// Returns the line through `r` and `t` and the point `r + t`.
+    fn add_step(
+        r: &[FieldElement<F>; 2],
+        [x_t, y_t]: &[FieldElement<F>; 2],
+        a: &FieldElement<F>,
+    ) -> (MillerLine<F>, Option<[FieldElement<F>; 2]>) {
+        let [x_r, y_r] = r;
+        if x_r == x_t {
+            if y_r == y_t {
+                return Self::double_step(r, a);
+            }
+            return (MillerLine::Vertical(x_r.clone()), None);
+        }
+        let slope = (y_t - y_r) / (x_t - x_r);
+        Self::chord(slope, [x_r, y_r], x_t)
+    }
+
+    /// Returns the line of slope `slope` through `(x, y)` and `other`, and the third
+    /// point of the curve on it, negated.
+    fn chord(
+        slope: FieldElement<F>,
+        [x, y]: [&FieldElement<F>; 2],
+        x_other: &FieldElement<F>,
+    ) -> (MillerLine<F>, Option<[FieldElement<F>; 2]>) {
+        let x_next = slope.square() - x - x_other;
+        let y_next = &slope * (x - &x_next) - y;
+        let intercept = y - &slope * x;
+        let line = MillerLine::Chord {
+            slope,
+            intercept,
+            vertical: x_next.clone(),
+        };
+        (line, Some([x_next, y_next]))
+    }
+
+    /// Evaluates `f_{n, T}` at the point `(x, y)`, returning it as a numerator and a denominator.
+    pub fn evaluate(&self, [x, y]: &[FieldElement<F>; 2]) -> [FieldElement<F>; 2] {
+        let mut numerator = FieldElement::one();
+        let mut denominator = FieldElement::one();
+        for step in &self.steps {
+            match step {
+                MillerStep::Square => {
+                    numerator = numerator.square();
+                    denominator = denominator.square();
+                }
+                MillerStep::Line(MillerLine::Chord {
+                    slope,
+                    intercept,
+                    vertical,
+                }) => {
+                    numerator = numerator * (y - slope * x - intercept);
+                    denominator = denominator * (x - vertical);
+                }
+                MillerStep::Line(MillerLine::Vertical(vertical)) => {
+                    numerator = numerator * (x - vertical);
+                }
+                MillerStep::Invert => core::mem::swap(&mut numerator, &mut denominator),
+            }
+        }
+        [numerator, denominator]
+    }
+}
+
+/// Multiplies the fractions returned by the Miller loops and applies the final exponentiation.
+/// If a point of G1 is a zero or a pole of a Miller function, which only happens for
+/// degenerate inputs, the product is not defined and one is returned.
+fn reduce<E: IsPairingFriendly>(
+    fractions: impl Iterator<Item = [FieldElement<E::OutputField>; 2]>,
+) -> FieldElement<E::OutputField> {
+    let [numerator, denominator] = fractions.fold(
+        [FieldElement::one(), FieldElement::one()],
+        |[numerator, denominator], [a, b]| [numerator * a, denominator * b],
+    );
+    if numerator == FieldElement::zero() || denominator == FieldElement::zero() {
+        return FieldElement::one();
+    }
+    E::final_exponentiation(&(numerator * denominator.inv()))
+}
+
+/// The reduced Tate pairing `f_{r, P}(Q)^((p^k - 1) / r)` of a point `P` in G1 and `Q` in G2.
+#[derive(Clone, Debug)]
+pub struct TatePairing<E>(PhantomData<E>);
+
+impl<E: IsPairingFriendly> IsPairing for TatePairing<E> {
+    type G1Point = ShortWeierstrassProjectivePoint<E>;
+    type G2Point = ShortWeierstrassProjectivePoint<E::G2Curve>;
+    /// The image of the point of G2 in the curve over the output field,
+    /// or `None` for the neutral element.
+    type G2Prepared = Option<[FieldElement<E::OutputField>; 2]>;
+    type OutputField = E::OutputField;
+
+    fn compute_batch(
+        pairs: &[(&Self::G1Point, &Self::G2Point)],
+    ) -> FieldElement<Self::OutputField> {
+        let prepared: Vec<_> = pairs.iter().map(|(_, q)| Self::prepare(q)).collect();
+        let pairs: Vec<_> = pairs
+            .iter()
+            .zip(prepared.iter())
+            .map(|((p, _), q)| (*p, q))
+            .collect();
+        Self::compute_batch_prepared(&pairs)
+    }
+
+    fn prepare(q: &Self::G2Point) -> Self::G2Prepared {
+        if q.is_neutral_element() {
+            None
+        } else {
+            Some(E::g2_to_output(q))
+        }
+    }
+
+    fn compute_batch_prepared(
+        pairs: &[(&Self::G1Point, &Self::G2Prepared)],
+    ) -> FieldElement<Self::OutputField> {
+        let a = E::embed(&E::a());
+        reduce::<E>(pairs.iter().filter_map(|(p, q)| match q {
+            Some(q) if !p.is_neutral_element() => {
+                let lines = MillerLines::new(&E::g1_to_output(p), &a, E::SUBGROUP_ORDER, false);
+                Some(lines.evaluate(q))
+            }
+            _ => None,
+        }))
+    }
+}
+
+/// The reduced ate pairing `f_{n, Q}(P)^((p^k - 1) / r)` of a point `P` in G1 and `Q` in G2,
+/// where `n` is the loop parameter `ATE_LOOP_PARAMETER`.
+/// It is non degenerate if `r` does not divide `(n^k - 1) / r`.
+#[derive(Clone, Debug)]
+pub struct AtePairing<E>(PhantomData<E>);
+
+impl<E: IsPairingFriendly> IsPairing for AtePairing<E> {
+    type G1Point = ShortWeierstrassProjectivePoint<E>;
+    type G2Point = ShortWeierstrassProjectivePoint<E::G2Curve>;
+    /// The steps of the Miller loop of the point of G2, or `None` for the neutral element.
+    type G2Prepared = Option<MillerLines<E::OutputField>>;
+    type OutputField = E::OutputField;
+
+    fn compute_batch(
+        pairs: &[(&Self::G1Point, &Self::G2Point)],
+    ) -> FieldElement<Self::OutputField> {
+        let prepared: Vec<_> = pairs.iter().map(|(_, q)| Self::prepare(q)).collect();
+        let pairs: Vec<_> = pairs
+            .iter()
+            .zip(prepared.iter())
+            .map(|((p, _), q)| (*p, q))
+            .collect();
+        Self::compute_batch_prepared(&pairs)
+    }
+
+    fn prepare(q: &Self::G2Point) -> Self::G2Prepared {
+        if q.is_neutral_element() {
+            None
+        } else {
+            Some(MillerLines::new(
+                &E::g2_to_output(q),
+                &E::embed(&E::a()),
+                E::ATE_LOOP_PARAMETER,
+                E::ATE_LOOP_PARAMETER_IS_NEGATIVE,
+            ))
+        }
+    }
+
+    fn compute_batch_prepared(
+        pairs: &[(&Self::G1Point, &Self::G2Prepared)],
+    ) -> FieldElement<Self::OutputField> {
+        reduce::<E>(pairs.iter().filter_map(|(p, q)| match q {
+            Some(lines) if !p.is_neutral_element() => Some(lines.evaluate(&E::g1_to_output(p))),
+            _ => None,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::{
+        short_weierstrass::curves::{
+            test_curve_1::{TestCurve1, TEST_CURVE_1_MAIN_SUBGROUP_ORDER},
+            test_curve_2::{TestCurve2, TEST_CURVE_2_MAIN_SUBGROUP_ORDER},
+        },
+        traits::IsEllipticCurve,
+    };
+
+    fn assert_bilinear<P: IsPairing>(p: &P::G1Point, q: &P::G2Point, a: u64, b: u64)
+    where
+        FieldElement<P::OutputField>: core::fmt::Debug,
+    {
+        let e = P::compute(p, q);
+        assert_ne!(e, FieldElement::one());
+        assert_eq!(
+            P::compute(&p.operate_with_self(a), &q.operate_with_self(b)),
+            e.pow(a * b)
+        );
+    }
+
+    #[test]
+    fn loop_bits_skip_the_most_significant_one() {
+        let bits: Vec<_> = loop_bits(&[0, 0b1011]).collect();
+        assert_eq!(bits, [false, true, true]);
+        assert_eq!(loop_bits(&[1, 0]).count(), 64);
+    }
+
+    #[test]
+    fn tate_pairing_on_test_curve_1_is_bilinear() {
+        let g = TestCurve1::generator();
+        assert_bilinear::<TatePairing<TestCurve1>>(&g, &g, 2, 3);
+    }
+
+    #[test]
+    fn ate_pairing_on_test_curve_1_is_bilinear() {
+        let g = TestCurve1::generator();
+        assert_bilinear::<AtePairing<TestCurve1>>(&g, &g, 2, 3);
+    }
+
+    #[test]
+    fn tate_pairing_on_test_curve_2_is_bilinear() {
+        let g = TestCurve2::generator();
+        assert_bilinear::<TatePairing<TestCurve2>>(&g, &g, 1234, 5678);
+    }
+
+    #[test]
+    fn ate_pairing_on_test_curve_2_is_bilinear() {
+        let g = TestCurve2::generator();
+        assert_bilinear::<AtePairing<TestCurve2>>(&g, &g, 1234, 5678);
+    }
+
+    #[test]
+    fn pairings_on_test_curves_have_order_r() {
+        let g = TestCurve1::generator();
+        let e = TatePairing::<TestCurve1>::compute(&g, &g);
+        assert_eq!(e.pow(TEST_CURVE_1_MAIN_SUBGROUP_ORDER), FieldElement::one());
+        let g = TestCurve2::generator();
+        let e = AtePairing::<TestCurve2>::compute(&g, &g);
+        assert_eq!(e.pow(TEST_CURVE_2_MAIN_SUBGROUP_ORDER), FieldElement::one());
+    }
+
+    #[test]
+    fn pairings_with_the_neutral_element_are_one() {
+        let g = TestCurve2::generator();
+        let o = ShortWeierstrassProjectivePoint::<TestCurve2>::neutral_element();
+        assert_eq!(
+            TatePairing::<TestCurve2>::compute(&g, &o),
+            FieldElement::one()
+        );
+        assert_eq!(
+            TatePairing::<TestCurve2>::compute(&o, &g),
+            FieldElement::one()
+        );
+        assert_eq!(
+            AtePairing::<TestCurve2>::compute(&g, &o),
+            FieldElement::one()
+        );
+        assert_eq!(
+            AtePairing::<TestCurve2>::compute(&o, &g),
+            FieldElement::one()
+        );
+    }
+
+    #[test]
+    fn reduce_returns_one_at_a_pole_of_the_miller_function() {
+        // The first line of the loop is divided by the vertical line through 2T.
+        let g = TestCurve2::generator();
+        let lines = MillerLines::new(
+            &TestCurve2::g1_to_output(&g),
+            &TestCurve2::embed(&TestCurve2::a()),
+            TestCurve2::SUBGROUP_ORDER,
+            false,
+        );
+        let fraction = lines.evaluate(&TestCurve2::g1_to_output(&g.operate_with_self(2u64)));
+        assert_eq!(fraction[1], FieldElement::zero());
+        assert_eq!(
+            reduce::<TestCurve2>(core::iter::once(fraction)),
+            FieldElement::one()
+        );
+    }
+
+    #[test]
+    fn compute_batch_is_the_product_of_the_pairings() {
+        let g = TestCurve2::generator();
+        let p = g.operate_with_self(5u64);
+        let q = g.operate_with_self(7u64);
+        assert_eq!(
+            AtePairing::<TestCurve2>::compute_batch(&[(&g, &q), (&p, &g)]),
+            AtePairing::<TestCurve2>::compute(&g, &q) * AtePairing::<TestCurve2>::compute(&p, &g)
+        );
+        assert_eq!(
+            TatePairing::<TestCurve2>::compute_batch(&[(&g, &q), (&q, &g.neg())]),
+            FieldElement::one()
+        );
+    }
+}
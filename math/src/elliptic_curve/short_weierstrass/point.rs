@@ -2,13 +2,17 @@ use crate::{
     cyclic_group::IsGroup,
     elliptic_curve::{
         point::ProjectivePoint,
-        traits::{EllipticCurveError, FromAffine, IsEllipticCurve},
+        traits::{EllipticCurveError, FromAffine, HasCofactor, IsEllipticCurve, ToAffine},
     },
     field::element::FieldElement,
     traits::{ByteConversion, Deserializable, Serializable},
 };
 
-use super::{errors::DeserializationError, traits::IsShortWeierstrass};
+use super::{
+    errors::DeserializationError,
+    traits::{Compress, IsShortWeierstrass},
+    zcash,
+};
 
 #[derive(Clone, Debug)]
 pub struct ShortWeierstrassProjectivePoint<E: IsEllipticCurve>(pub ProjectivePoint<E>);
@@ -69,6 +73,17 @@ impl<E: IsShortWeierstrass> FromAffine<E::BaseField> for ShortWeierstrassProject
     }
 }
 
+impl<E: IsShortWeierstrass> ToAffine<E::BaseField> for ShortWeierstrassProjectivePoint<E> {
+    fn to_affine_coordinates(&self) -> Option<[FieldElement<E::BaseField>; 2]> {
+        if self.is_neutral_element() {
+            None
+        } else {
+            let z_inv = self.z().inv();
+            Some([self.x() * &z_inv, self.y() * z_inv])
+        }
+    }
+}
+
 impl<E: IsShortWeierstrass> IsGroup for ShortWeierstrassProjectivePoint<E> {
     /// The point at infinity.
     fn neutral_element() -> Self {
@@ -159,12 +174,146 @@ impl<E: IsShortWeierstrass> IsGroup for ShortWeierstrassProjectivePoint<E> {
     }
 }
 
+/// Complete formulas by Renes, Costello and Batina, which compute the sum of any two
+/// points, including equal points and the point at infinity, with the same sequence
+/// of field operations. They are valid on curves without points of order two, like
+/// prime order curves and the BLS12-381 groups, and avoid the branches of
+/// `operate_with`, at the cost of a few more multiplications for generic additions.
+/// For more info, see "Complete addition formulas for prime order elliptic curves"
+/// (https://eprint.iacr.org/2015/1060).
+impl<E: IsShortWeierstrass> ShortWeierstrassProjectivePoint<E> {
+    /// Computes the addition of `self` and `other` with the complete formulas.
+    /// Uses Algorithm 7 of the paper when `a = 0` and Algorithm 1 otherwise.
+    pub fn complete_add(&self, other: &Self) -> Self {
+        if E::a() == FieldElement::zero() {
+            self.complete_add_a_zero(other)
+        } else {
+            self.complete_add_general_a(other)
+        }
+    }
+
+    /// Computes `2 * self` with the complete formulas.
+    /// Uses Algorithm 9 of the paper when `a = 0` and Algorithm 3 otherwise.
+    pub fn complete_double(&self) -> Self {
+        if E::a() == FieldElement::zero() {
+            self.complete_double_a_zero()
+        } else {
+            self.complete_double_general_a()
+        }
+    }
+
+    fn b3() -> FieldElement<E::BaseField> {
+        let b = E::b();
+        &b + &b + b
+    }
+
+    fn complete_add_general_a(&self, other: &Self) -> Self {
+        let [x1, y1, z1] = self.coordinates();
+        let [x2, y2, z2] = other.coordinates();
+        let (a, b3) = (E::a(), Self::b3());
+
+        let t0 = x1 * x2;
+        let t1 = y1 * y2;
+        let t2 = z1 * z2;
+        let t3 = (x1 + y1) * (x2 + y2) - (&t0 + &t1);
+        let t4 = (x1 + z1) * (x2 + z2) - (&t0 + &t2);
+        let t5 = (y1 + z1) * (y2 + z2) - (&t1 + &t2);
+        let z3 = &a * &t4 + &b3 * &t2;
+        let x3 = &t1 - &z3;
+        let z3 = &t1 + &z3;
+        let y3 = &x3 * &z3;
+        let t1 = &t0 + &t0 + &t0;
+        let t2 = &a * &t2;
+        let t4 = &b3 * &t4 + &a * (&t0 - &t2);
+        let t1 = t1 + &t2;
+        let y3 = y3 + &t1 * &t4;
+        let x3 = &t3 * &x3 - &t5 * &t4;
+        let z3 = t5 * z3 + t3 * t1;
+        Self::new([x3, y3, z3])
+    }
+
+    fn complete_double_general_a(&self) -> Self {
+        let [x, y, z] = self.coordinates();
+        let (a, b3) = (E::a(), Self::b3());
+
+        let t0 = x.square();
+        let t1 = y.square();
+        let t2 = z.square();
+        let t3 = x * y;
+        let t3 = &t3 + &t3;
+        let z3 = x * z;
+        let z3 = &z3 + &z3;
+        let x3 = &a * &z3;
+        let y3 = &x3 + &b3 * &t2;
+        let x3 = &t1 - &y3;
+        let y3 = &x3 * (&t1 + &y3);
+        let x3 = t3 * x3;
+        let z3 = &b3 * z3;
+        let t2 = &a * t2;
+        let t3 = &a * (&t0 - &t2) + z3;
+        let t0 = &t0 + &t0 + &t0 + t2;
+        let y3 = y3 + t0 * &t3;
+        let t2 = y * z;
+        let t2 = &t2 + &t2;
+        let x3 = x3 - &t2 * t3;
+        let z3 = t2 * t1;
+        let z3 = &z3 + &z3;
+        let z3 = &z3 + &z3;
+        Self::new([x3, y3, z3])
+    }
+
+    fn complete_add_a_zero(&self, other: &Self) -> Self {
+        let [x1, y1, z1] = self.coordinates();
+        let [x2, y2, z2] = other.coordinates();
+        let b3 = Self::b3();
+
+        let t0 = x1 * x2;
+        let t1 = y1 * y2;
+        let t2 = z1 * z2;
+        let t3 = (x1 + y1) * (x2 + y2) - (&t0 + &t1);
+        let t4 = (y1 + z1) * (y2 + z2) - (&t1 + &t2);
+        let y3 = (x1 + z1) * (x2 + z2) - (&t0 + &t2);
+        let t0 = &t0 + &t0 + &t0;
+        let t2 = &b3 * t2;
+        let z3 = &t1 + &t2;
+        let t1 = t1 - t2;
+        let y3 = b3 * y3;
+        let x3 = &t3 * &t1 - &t4 * &y3;
+        let y3 = &y3 * &t0 + t1 * &z3;
+        let z3 = z3 * t4 + t0 * t3;
+        Self::new([x3, y3, z3])
+    }
+
+    fn complete_double_a_zero(&self) -> Self {
+        let [x, y, z] = self.coordinates();
+        let b3 = Self::b3();
+
+        let t0 = y.square();
+        let z3 = &t0 + &t0;
+        let z3 = &z3 + &z3;
+        let z3 = &z3 + &z3;
+        let t1 = y * z;
+        let t2 = b3 * z.square();
+        let x3 = &t2 * &z3;
+        let y3 = &t0 + &t2;
+        let z3 = t1 * z3;
+        let t0 = t0 - (&t2 + &t2 + t2);
+        let y3 = x3 + &t0 * y3;
+        let t1 = x * y;
+        let x3 = t0 * t1;
+        let x3 = &x3 + &x3;
+        Self::new([x3, y3, z3])
+    }
+}
+
 #[derive(PartialEq)]
 pub enum PointFormat {
+    /// The projective coordinates [x: y: z] of the point.
     Projective,
-    // TO DO:
-    // Uncompressed,
-    // Compressed,
+    /// The affine coordinates (x, y) of the point.
+    /// The point at infinity is encoded as zeros, or as the infinity flag
+    /// followed by zeros on curves with Zcash flags.
+    Uncompressed,
 }
 
 #[derive(PartialEq)]
@@ -181,69 +330,168 @@ where
     E: IsShortWeierstrass,
     FieldElement<E::BaseField>: ByteConversion,
 {
-    /// Serialize the points in the given format
-    pub fn serialize(&self, _point_format: PointFormat, endianness: Endianness) -> Vec<u8> {
-        // TODO: Add more compact serialization formats
-        // Uncompressed affine / Compressed
+    /// Serialize the points in the given format.
+    pub fn serialize(&self, point_format: PointFormat, endianness: Endianness) -> Vec<u8> {
+        match point_format {
+            PointFormat::Projective => {
+                let [x, y, z] = self.coordinates();
+                let mut bytes = Self::field_element_to_bytes(x, &endianness);
+                bytes.extend(Self::field_element_to_bytes(y, &endianness));
+                bytes.extend(Self::field_element_to_bytes(z, &endianness));
+                bytes
+            }
+            PointFormat::Uncompressed => {
+                if self.is_neutral_element() {
+                    let zero = Self::field_element_to_bytes(&FieldElement::zero(), &endianness);
+                    let mut bytes = vec![0; zero.len() * 2];
+                    if E::HAS_ZCASH_FLAGS {
+                        bytes[Self::flags_index(zero.len(), &endianness)] = zcash::INFINITY_FLAG;
+                    }
+                    bytes
+                } else {
+                    let affine = self.to_affine();
+                    let mut bytes = Self::field_element_to_bytes(affine.x(), &endianness);
+                    bytes.extend(Self::field_element_to_bytes(affine.y(), &endianness));
+                    bytes
+                }
+            }
+        }
+    }
 
-        let mut bytes: Vec<u8> = Vec::new();
-        let x_bytes: Vec<u8>;
-        let y_bytes: Vec<u8>;
-        let z_bytes: Vec<u8>;
+    pub fn deserialize(
+        bytes: &[u8],
+        point_format: PointFormat,
+        endianness: Endianness,
+    ) -> Result<Self, DeserializationError> {
+        match point_format {
+            PointFormat::Projective => {
+                if !bytes.len().is_multiple_of(3) {
+                    return Err(DeserializationError::InvalidAmountOfBytes);
+                }
 
-        let [x, y, z] = self.coordinates();
-        if endianness == Endianness::BigEndian {
-            x_bytes = x.to_bytes_be();
-            y_bytes = y.to_bytes_be();
-            z_bytes = z.to_bytes_be();
-        } else {
-            x_bytes = x.to_bytes_le();
-            y_bytes = y.to_bytes_le();
-            z_bytes = z.to_bytes_le();
+                let len = bytes.len() / 3;
+                let x = Self::field_element_from_bytes(&bytes[..len], &endianness)?;
+                let y = Self::field_element_from_bytes(&bytes[len..len * 2], &endianness)?;
+                let z = Self::field_element_from_bytes(&bytes[len * 2..], &endianness)?;
+
+                if z == FieldElement::zero() {
+                    let point = Self::new([x, y, z]);
+                    if point.is_neutral_element() {
+                        Ok(point)
+                    } else {
+                        Err(DeserializationError::FieldFromBytesError)
+                    }
+                } else if E::defining_equation(&(&x / &z), &(&y / &z)) == FieldElement::zero() {
+                    Ok(Self::new([x, y, z]))
+                } else {
+                    Err(DeserializationError::FieldFromBytesError)
+                }
+            }
+            PointFormat::Uncompressed => {
+                if !bytes.len().is_multiple_of(2) {
+                    return Err(DeserializationError::InvalidAmountOfBytes);
+                }
+
+                let len = bytes.len() / 2;
+                let is_infinity = if E::HAS_ZCASH_FLAGS {
+                    zcash::is_uncompressed_infinity(bytes, Self::flags_index(len, &endianness))?
+                } else {
+                    bytes.iter().all(|byte| *byte == 0)
+                };
+                if is_infinity {
+                    return Ok(Self::neutral_element());
+                }
+
+                let x = Self::field_element_from_bytes(&bytes[..len], &endianness)?;
+                let y = Self::field_element_from_bytes(&bytes[len..], &endianness)?;
+
+                Self::from_affine(x, y).map_err(|_| DeserializationError::FieldFromBytesError)
+            }
         }
+    }
 
-        bytes.extend(&x_bytes);
-        bytes.extend(&y_bytes);
-        bytes.extend(&z_bytes);
+    /// Returns the index of the most significant byte of the encoding of `x`,
+    /// which holds the flags on curves with Zcash flags.
+    fn flags_index(field_element_len: usize, endianness: &Endianness) -> usize {
+        match endianness {
+            Endianness::BigEndian => 0,
+            Endianness::LittleEndian => field_element_len - 1,
+        }
+    }
 
-        bytes
+    fn field_element_to_bytes(
+        element: &FieldElement<E::BaseField>,
+        endianness: &Endianness,
+    ) -> Vec<u8> {
+        match endianness {
+            Endianness::BigEndian => element.to_bytes_be(),
+            Endianness::LittleEndian => element.to_bytes_le(),
+        }
     }
 
-    pub fn deserialize(
+    fn field_element_from_bytes(
         bytes: &[u8],
-        _point_format: PointFormat,
+        endianness: &Endianness,
+    ) -> Result<FieldElement<E::BaseField>, DeserializationError> {
+        let element = match endianness {
+            Endianness::BigEndian => FieldElement::from_bytes_be(bytes)?,
+            Endianness::LittleEndian => FieldElement::from_bytes_le(bytes)?,
+        };
+        Ok(element)
+    }
+}
+
+impl<E> ShortWeierstrassProjectivePoint<E>
+where
+    E: IsShortWeierstrass,
+    FieldElement<E::BaseField>: ByteConversion,
+    Self: HasCofactor,
+{
+    /// Deserializes a point like [`Self::deserialize`], additionally rejecting
+    /// points that are not in the prime order subgroup. This should be used
+    /// whenever the bytes come from an untrusted source.
+    pub fn deserialize_checked(
+        bytes: &[u8],
+        point_format: PointFormat,
         endianness: Endianness,
     ) -> Result<Self, DeserializationError> {
-        if bytes.len() % 3 != 0 {
-            return Err(DeserializationError::InvalidAmountOfBytes);
+        let point = Self::deserialize(bytes, point_format, endianness)?;
+        if point.is_in_subgroup() {
+            Ok(point)
+        } else {
+            Err(DeserializationError::NotInSubgroup)
         }
+    }
+}
 
-        let len = bytes.len() / 3;
-        let x: FieldElement<E::BaseField>;
-        let y: FieldElement<E::BaseField>;
-        let z: FieldElement<E::BaseField>;
+impl<E: Compress> ShortWeierstrassProjectivePoint<E> {
+    /// Serializes the point in the compressed encoding of its curve,
+    /// which stores only the `x` coordinate and the information needed to
+    /// recover `y`. See [`Compress`].
+    pub fn serialize_compressed(&self) -> Vec<u8> {
+        E::compress(self)
+    }
 
-        if endianness == Endianness::BigEndian {
-            x = FieldElement::from_bytes_be(&bytes[..len])?;
-            y = FieldElement::from_bytes_be(&bytes[len..len * 2])?;
-            z = FieldElement::from_bytes_be(&bytes[len * 2..])?;
-        } else {
-            x = FieldElement::from_bytes_le(&bytes[..len])?;
-            y = FieldElement::from_bytes_le(&bytes[len..len * 2])?;
-            z = FieldElement::from_bytes_le(&bytes[len * 2..])?;
-        }
+    /// Deserializes a point in the compressed encoding of its curve,
+    /// checking that it belongs to the curve.
+    pub fn deserialize_compressed(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        E::decompress(bytes)
+    }
+}
 
-        if z == FieldElement::zero() {
-            let point = Self::new([x, y, z]);
-            if point.is_neutral_element() {
-                Ok(point)
-            } else {
-                Err(DeserializationError::FieldFromBytesError)
-            }
-        } else if E::defining_equation(&(&x / &z), &(&y / &z)) == FieldElement::zero() {
-            Ok(Self::new([x, y, z]))
+impl<E> ShortWeierstrassProjectivePoint<E>
+where
+    E: Compress,
+    Self: HasCofactor,
+{
+    /// Deserializes a point like [`Self::deserialize_compressed`], additionally
+    /// rejecting points that are not in the prime order subgroup.
+    pub fn deserialize_compressed_checked(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let point = Self::deserialize_compressed(bytes)?;
+        if point.is_in_subgroup() {
+            Ok(point)
         } else {
-            Err(DeserializationError::FieldFromBytesError)
+            Err(DeserializationError::NotInSubgroup)
         }
     }
 }
@@ -379,4 +627,135 @@ mod tests {
             DeserializationError::InvalidAmountOfBytes
         );
     }
+
+    #[test]
+    fn deserialize_checked_accepts_points_in_subgroup() {
+        let g = BLS12381Curve::generator();
+        let bytes = g.serialize(PointFormat::Uncompressed, Endianness::BigEndian);
+
+        let result = ShortWeierstrassProjectivePoint::deserialize_checked(
+            &bytes,
+            PointFormat::Uncompressed,
+            Endianness::BigEndian,
+        );
+        assert_eq!(result.unwrap(), g);
+    }
+
+    #[test]
+    fn deserialize_checked_rejects_points_not_in_subgroup() {
+        let x = FEE::from(4);
+        let y = FEE::new_base("a989badd40d6212b33cffc3f3763e9bc760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c");
+        let p = BLS12381Curve::create_point_from_affine(x, y).unwrap();
+        let bytes = p.serialize(PointFormat::Uncompressed, Endianness::BigEndian);
+
+        let result = ShortWeierstrassProjectivePoint::<BLS12381Curve>::deserialize_checked(
+            &bytes,
+            PointFormat::Uncompressed,
+            Endianness::BigEndian,
+        );
+        assert_eq!(result.unwrap_err(), DeserializationError::NotInSubgroup);
+
+        let result =
+            ShortWeierstrassProjectivePoint::<BLS12381Curve>::deserialize_compressed_checked(
+                &p.serialize_compressed(),
+            );
+        assert_eq!(result.unwrap_err(), DeserializationError::NotInSubgroup);
+    }
+
+    #[test]
+    fn serialization_roundtrip_on_curve_without_compression() {
+        use crate::elliptic_curve::short_weierstrass::curves::stark_curve::curve::StarkCurve;
+        type StarkPoint = ShortWeierstrassProjectivePoint<StarkCurve>;
+
+        for p in [
+            StarkCurve::generator().operate_with_self(12345_u64),
+            StarkPoint::neutral_element(),
+        ] {
+            let bytes = p.serialize(PointFormat::Projective, Endianness::BigEndian);
+            let result =
+                StarkPoint::deserialize(&bytes, PointFormat::Projective, Endianness::BigEndian);
+            assert_eq!(result.unwrap(), p);
+
+            let bytes = p.serialize(PointFormat::Uncompressed, Endianness::LittleEndian);
+            assert_eq!(bytes.len(), 64);
+            let result = StarkPoint::deserialize(
+                &bytes,
+                PointFormat::Uncompressed,
+                Endianness::LittleEndian,
+            );
+            assert_eq!(result.unwrap(), p);
+
+            let bytes = Serializable::serialize(&p);
+            let result: StarkPoint = Deserializable::deserialize(&bytes).unwrap();
+            assert_eq!(result, p);
+        }
+    }
+
+    mod complete_formulas {
+        use super::*;
+        use crate::elliptic_curve::short_weierstrass::curves::{
+            bls12_381::twist::BLS12381TwistCurve, stark_curve::curve::StarkCurve,
+            test_curve_1::TestCurve1, test_curve_2::TestCurve2,
+        };
+        use proptest::{prelude::*, test_runner::TestCaseError};
+
+        const CASES: u32 = 20;
+
+        /// Checks the complete formulas against `operate_with` on `p` and `q`, with
+        /// the formulas for general `a` checked on every curve.
+        fn check<E: IsShortWeierstrass>(
+            p: &ShortWeierstrassProjectivePoint<E>,
+            q: &ShortWeierstrassProjectivePoint<E>,
+        ) -> Result<(), TestCaseError> {
+            let neutral = ShortWeierstrassProjectivePoint::<E>::neutral_element();
+            let sum = p.operate_with(q);
+            let double = p.operate_with(p);
+            prop_assert_eq!(p.complete_add(q), sum.clone());
+            prop_assert_eq!(p.complete_add_general_a(q), sum);
+            prop_assert_eq!(p.complete_add(p), double.clone());
+            prop_assert_eq!(p.complete_double(), double.clone());
+            prop_assert_eq!(p.complete_double_general_a(), double);
+            prop_assert_eq!(p.complete_add(&neutral), p.clone());
+            prop_assert_eq!(neutral.complete_add(p), p.clone());
+            prop_assert!(p.complete_add(&p.neg()).is_neutral_element());
+            prop_assert!(neutral.complete_double().is_neutral_element());
+            Ok(())
+        }
+
+        proptest! {
+            #![proptest_config(ProptestConfig {
+                cases: CASES, .. ProptestConfig::default()
+            })]
+            #[test]
+            fn complete_formulas_match_operate_with_on_bls12_381(k1: u64, k2: u64) {
+                let g = BLS12381Curve::generator();
+                check(&g.operate_with_self(k1), &g.operate_with_self(k2))?;
+            }
+
+            #[test]
+            fn complete_formulas_match_operate_with_on_bls12_381_twist(k1: u64, k2: u64) {
+                let g = BLS12381TwistCurve::generator();
+                check(&g.operate_with_self(k1), &g.operate_with_self(k2))?;
+            }
+
+            #[test]
+            fn complete_formulas_match_operate_with_on_test_curve_1(k1: u64, k2: u64) {
+                let g = TestCurve1::generator();
+                check(&g.operate_with_self(k1), &g.operate_with_self(k2))?;
+            }
+
+            #[test]
+            fn complete_formulas_match_operate_with_on_test_curve_2(k1: u64, k2: u64) {
+                let g = TestCurve2::generator();
+                check(&g.operate_with_self(k1), &g.operate_with_self(k2))?;
+            }
+
+            // The STARK curve has `a = 1`.
+            #[test]
+            fn complete_formulas_match_operate_with_on_stark_curve(k1: u64, k2: u64) {
+                let g = StarkCurve::generator();
+                check(&g.operate_with_self(k1), &g.operate_with_self(k2))?;
+            }
+        }
+    }
 }
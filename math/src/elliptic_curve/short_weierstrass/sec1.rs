@@ -0,0 +1,210 @@
+use crate::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{
+            errors::DeserializationError, point::ShortWeierstrassProjectivePoint,
+            traits::IsShortWeierstrass,
+        },
+        traits::FromAffine,
+    },
+    field::{element::FieldElement, traits::IsPrimeField},
+    traits::ByteConversion,
+};
+
+/// First byte of the SEC1 encodings of points, as specified in section 2.3.3 of
+/// "SEC 1: Elliptic Curve Cryptography" (https://www.secg.org/sec1-v2.pdf).
+pub const INFINITY_TAG: u8 = 0x00;
+pub const COMPRESSED_EVEN_TAG: u8 = 0x02;
+pub const COMPRESSED_ODD_TAG: u8 = 0x03;
+pub const UNCOMPRESSED_TAG: u8 = 0x04;
+
+/// Reads a field element from its big-endian encoding, rejecting
+/// non-canonical encodings, that is, integers not smaller than the modulus.
+fn field_element_from_bytes<F>(bytes: &[u8]) -> Result<FieldElement<F>, DeserializationError>
+where
+    F: IsPrimeField,
+    FieldElement<F>: ByteConversion,
+{
+    let element = FieldElement::<F>::from_bytes_be(bytes)?;
+    if element.to_bytes_be() != bytes {
+        return Err(DeserializationError::FieldFromBytesError);
+    }
+    Ok(element)
+}
+
+/// Returns true if the representative of `y` is odd.
+pub fn is_odd<F: IsPrimeField>(y: &FieldElement<F>) -> bool {
+    let one = F::RepresentativeType::from(1_u16);
+    y.representative() & one == one
+}
+
+/// Returns the point with abscissa `x` whose ordinate has the given parity,
+/// or `None` if `x` is not the abscissa of a point of the curve.
+pub fn lift_x<E>(
+    x: &FieldElement<E::BaseField>,
+    y_is_odd: bool,
+) -> Option<ShortWeierstrassProjectivePoint<E>>
+where
+    E: IsShortWeierstrass,
+    E::BaseField: IsPrimeField,
+{
+    let y_squared = x.pow(3_u16) + E::a() * x + E::b();
+    let (y, minus_y) = y_squared.sqrt()?;
+    let y = if is_odd(&y) == y_is_odd { y } else { minus_y };
+    Some(ShortWeierstrassProjectivePoint::new([
+        x.clone(),
+        y,
+        FieldElement::one(),
+    ]))
+}
+
+/// Returns the SEC1 encoding of `point`: the single byte `0x00` for the point at
+/// infinity, `0x02` or `0x03` followed by `x` if compressed, with the tag giving the
+/// parity of `y`, or `0x04` followed by `x` and `y` otherwise.
+pub fn to_sec1<E>(point: &ShortWeierstrassProjectivePoint<E>, compressed: bool) -> Vec<u8>
+where
+    E: IsShortWeierstrass,
+    E::BaseField: IsPrimeField,
+    FieldElement<E::BaseField>: ByteConversion,
+{
+    if point.is_neutral_element() {
+        return vec![INFINITY_TAG];
+    }
+    let affine = point.to_affine();
+    let mut bytes = Vec::new();
+    if compressed {
+        bytes.push(if is_odd(affine.y()) {
+            COMPRESSED_ODD_TAG
+        } else {
+            COMPRESSED_EVEN_TAG
+        });
+        bytes.extend(affine.x().to_bytes_be());
+    } else {
+        bytes.push(UNCOMPRESSED_TAG);
+        bytes.extend(affine.x().to_bytes_be());
+        bytes.extend(affine.y().to_bytes_be());
+    }
+    bytes
+}
+
+/// Decodes a point in any of the SEC1 encodings, checking that it belongs to the curve.
+pub fn from_sec1<E>(
+    bytes: &[u8],
+) -> Result<ShortWeierstrassProjectivePoint<E>, DeserializationError>
+where
+    E: IsShortWeierstrass,
+    E::BaseField: IsPrimeField,
+    FieldElement<E::BaseField>: ByteConversion,
+{
+    let field_bytes = FieldElement::<E::BaseField>::zero().to_bytes_be().len();
+    let (tag, coordinates) = bytes
+        .split_first()
+        .ok_or(DeserializationError::InvalidAmountOfBytes)?;
+    match (*tag, coordinates.len()) {
+        (INFINITY_TAG, 0) => Ok(ShortWeierstrassProjectivePoint::neutral_element()),
+        (COMPRESSED_EVEN_TAG | COMPRESSED_ODD_TAG, length) if length == field_bytes => {
+            let x = field_element_from_bytes(coordinates)?;
+            lift_x(&x, *tag == COMPRESSED_ODD_TAG).ok_or(DeserializationError::FieldFromBytesError)
+        }
+        (UNCOMPRESSED_TAG, length) if length == 2 * field_bytes => {
+            let (x, y) = coordinates.split_at(field_bytes);
+            let x = field_element_from_bytes(x)?;
+            let y = field_element_from_bytes(y)?;
+            ShortWeierstrassProjectivePoint::from_affine(x, y)
+                .map_err(|_| DeserializationError::FieldFromBytesError)
+        }
+        (INFINITY_TAG | COMPRESSED_EVEN_TAG | COMPRESSED_ODD_TAG | UNCOMPRESSED_TAG, _) => {
+            Err(DeserializationError::InvalidAmountOfBytes)
+        }
+        _ => Err(DeserializationError::InvalidFlags),
+    }
+}
+
+/// Encodes `point` in the compressed SEC1 format.
+pub fn compress<E>(point: &ShortWeierstrassProjectivePoint<E>) -> Vec<u8>
+where
+    E: IsShortWeierstrass,
+    E::BaseField: IsPrimeField,
+    FieldElement<E::BaseField>: ByteConversion,
+{
+    to_sec1(point, true)
+}
+
+/// Decodes a point in the compressed SEC1 format, or the point at infinity.
+pub fn decompress<E>(
+    bytes: &[u8],
+) -> Result<ShortWeierstrassProjectivePoint<E>, DeserializationError>
+where
+    E: IsShortWeierstrass,
+    E::BaseField: IsPrimeField,
+    FieldElement<E::BaseField>: ByteConversion,
+{
+    match bytes.first() {
+        Some(&UNCOMPRESSED_TAG) => Err(DeserializationError::InvalidFlags),
+        _ => from_sec1(bytes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::{
+        short_weierstrass::{
+            curves::{
+                pasta::{pallas::PallasCurve, vesta::VestaCurve},
+                stark_curve::curve::StarkCurve,
+                tiny_jub_jub::TinyJubJubWeierstrass,
+                wei25519::Wei25519Curve,
+            },
+            traits::Compress,
+        },
+        traits::IsEllipticCurve,
+    };
+
+    fn assert_encodings_roundtrip<E>()
+    where
+        E: Compress + IsEllipticCurve<PointRepresentation = ShortWeierstrassProjectivePoint<E>>,
+        E::BaseField: IsPrimeField,
+        FieldElement<E::BaseField>: ByteConversion,
+    {
+        let g = E::generator();
+        for i in 0..8_u16 {
+            let point = g.operate_with_self(i);
+            for compressed in [true, false] {
+                let bytes = to_sec1(&point, compressed);
+                assert_eq!(from_sec1::<E>(&bytes).unwrap(), point);
+            }
+            let point = point.neg();
+            assert_eq!(E::decompress(&E::compress(&point)).unwrap(), point);
+        }
+    }
+
+    #[test]
+    fn encodings_roundtrip_on_curves_over_prime_fields() {
+        assert_encodings_roundtrip::<StarkCurve>();
+        assert_encodings_roundtrip::<PallasCurve>();
+        assert_encodings_roundtrip::<VestaCurve>();
+        assert_encodings_roundtrip::<Wei25519Curve>();
+        assert_encodings_roundtrip::<TinyJubJubWeierstrass>();
+    }
+
+    #[test]
+    fn compressed_encoding_has_the_tag_and_the_abscissa() {
+        let g = StarkCurve::generator();
+        let bytes = StarkCurve::compress(&g);
+        assert_eq!(bytes.len(), 33);
+        assert_eq!(&bytes[1..], g.x().to_bytes_be());
+        assert_eq!(
+            bytes[0],
+            if is_odd(g.y()) {
+                COMPRESSED_ODD_TAG
+            } else {
+                COMPRESSED_EVEN_TAG
+            }
+        );
+        assert_eq!(
+            StarkCurve::decompress(&to_sec1(&g, false)),
+            Err(DeserializationError::InvalidFlags)
+        );
+    }
+}
@@ -2,6 +2,9 @@ use crate::elliptic_curve::traits::IsEllipticCurve;
 use crate::field::element::FieldElement;
 use std::fmt::Debug;
 
+use super::errors::DeserializationError;
+use super::point::ShortWeierstrassProjectivePoint;
+
 /// Trait to add elliptic curves behaviour to a struct.
 /// We use the short Weierstrass form equation: `y^2 = x^3 + a * x  + b`.
 pub trait IsShortWeierstrass: IsEllipticCurve + Clone + Debug {
@@ -11,6 +14,13 @@ pub trait IsShortWeierstrass: IsEllipticCurve + Clone + Debug {
     /// `b` coefficient for the equation  `y^2 = x^3 + a * x  + b`.
     fn b() -> FieldElement<Self::BaseField>;
 
+    /// Whether the three most significant bits of the encoding of the `x` coordinate
+    /// carry the flags of the Zcash encoding, see [`super::zcash`]. The uncompressed
+    /// encoding of the point at infinity is then the infinity flag followed by zeros,
+    /// and all zeros is rejected. Otherwise the point at infinity is encoded as zeros,
+    /// which requires `(0, 0)` not to be a point of the curve.
+    const HAS_ZCASH_FLAGS: bool = false;
+
     fn defining_equation(
         x: &FieldElement<Self::BaseField>,
         y: &FieldElement<Self::BaseField>,
@@ -18,3 +28,16 @@ pub trait IsShortWeierstrass: IsEllipticCurve + Clone + Debug {
         y.pow(2_u16) - x.pow(3_u16) - Self::a() * x - Self::b()
     }
 }
+
+/// Trait for curves whose points have a compressed encoding, that is,
+/// the `x` coordinate together with the information needed to recover `y`.
+/// The layout of the encoding is defined by each curve.
+pub trait Compress: IsShortWeierstrass {
+    /// Returns the compressed encoding of `point`.
+    fn compress(point: &ShortWeierstrassProjectivePoint<Self>) -> Vec<u8>;
+
+    /// Returns the point encoded in `bytes`, checking that it belongs to the curve.
+    fn decompress(
+        bytes: &[u8],
+    ) -> Result<ShortWeierstrassProjectivePoint<Self>, DeserializationError>;
+}
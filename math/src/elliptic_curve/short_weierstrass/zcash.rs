@@ -0,0 +1,167 @@
+use crate::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{
+            errors::DeserializationError, point::ShortWeierstrassProjectivePoint,
+            traits::IsShortWeierstrass,
+        },
+        traits::FromAffine,
+    },
+    field::{
+        element::FieldElement,
+        traits::{IsField, IsPrimeField},
+    },
+    traits::ByteConversion,
+};
+
+/// The three most significant bits of a serialized point are used as flags,
+/// following the Zcash encoding of BLS12-381 points:
+/// https://github.com/zkcrypto/pairing/tree/master/src/bls12_381#serialization
+pub const COMPRESSION_FLAG: u8 = 0b1000_0000;
+pub const INFINITY_FLAG: u8 = 0b0100_0000;
+pub const SORT_FLAG: u8 = 0b0010_0000;
+pub const FLAGS_MASK: u8 = COMPRESSION_FLAG | INFINITY_FLAG | SORT_FLAG;
+
+/// Fields whose elements have a big-endian encoding that leaves the three most
+/// significant bits free, so that encodings of points can carry the flags of the
+/// Zcash encoding, and whose elements can be recovered from their squares.
+pub trait HasZcashEncoding: IsField {
+    /// Returns the big-endian encoding of `a`.
+    fn to_bytes(a: &FieldElement<Self>) -> Vec<u8>;
+
+    /// Decodes a field element, rejecting non-canonical encodings.
+    fn from_bytes(bytes: &[u8]) -> Result<FieldElement<Self>, DeserializationError>;
+
+    /// Returns true if `a` is greater than `-a` in the order used by the sort flag.
+    fn is_lexicographically_largest(a: &FieldElement<Self>) -> bool;
+
+    /// Returns a square root of `a`, or `None` if `a` is not a square.
+    fn square_root(a: &FieldElement<Self>) -> Option<FieldElement<Self>>;
+}
+
+/// Elements of prime fields are encoded as their big-endian representatives,
+/// compared as integers in `[0, p)`.
+impl<F> HasZcashEncoding for F
+where
+    F: IsPrimeField,
+    FieldElement<F>: ByteConversion,
+{
+    fn to_bytes(a: &FieldElement<Self>) -> Vec<u8> {
+        a.to_bytes_be()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<FieldElement<Self>, DeserializationError> {
+        let element = FieldElement::<F>::from_bytes_be(bytes)?;
+        if element.to_bytes_be() != bytes {
+            return Err(DeserializationError::FieldFromBytesError);
+        }
+        Ok(element)
+    }
+
+    fn is_lexicographically_largest(a: &FieldElement<Self>) -> bool {
+        a.representative() > (-a).representative()
+    }
+
+    fn square_root(a: &FieldElement<Self>) -> Option<FieldElement<Self>> {
+        a.sqrt().map(|(root, _)| root)
+    }
+}
+
+/// Returns the flags of a compressed encoding, checking that they are consistent.
+/// If the point at infinity is encoded, the remaining bits must all be zero.
+fn compressed_flags(bytes: &[u8]) -> Result<u8, DeserializationError> {
+    let flags = bytes[0] & FLAGS_MASK;
+    if flags & COMPRESSION_FLAG == 0 {
+        return Err(DeserializationError::InvalidFlags);
+    }
+    if flags & INFINITY_FLAG != 0
+        && (flags & SORT_FLAG != 0
+            || bytes[0] & !FLAGS_MASK != 0
+            || bytes[1..].iter().any(|byte| *byte != 0))
+    {
+        return Err(DeserializationError::InvalidFlags);
+    }
+    Ok(flags)
+}
+
+/// Returns true if an uncompressed encoding, whose flags are in the byte at
+/// `flags_index`, is the encoding of the point at infinity. The compression and
+/// sort flags must be clear, and if the infinity flag is set every other bit
+/// must be zero.
+pub fn is_uncompressed_infinity(
+    bytes: &[u8],
+    flags_index: usize,
+) -> Result<bool, DeserializationError> {
+    let flags = bytes[flags_index] & FLAGS_MASK;
+    if flags & (COMPRESSION_FLAG | SORT_FLAG) != 0 {
+        return Err(DeserializationError::InvalidFlags);
+    }
+    if flags & INFINITY_FLAG == 0 {
+        return Ok(false);
+    }
+    let other_bits_are_zero = bytes.iter().enumerate().all(|(i, byte)| {
+        if i == flags_index {
+            byte & !INFINITY_FLAG == 0
+        } else {
+            *byte == 0
+        }
+    });
+    if !other_bits_are_zero {
+        return Err(DeserializationError::InvalidFlags);
+    }
+    Ok(true)
+}
+
+/// Encodes `point` as the big-endian `x` coordinate with the compression,
+/// infinity and sort flags in the most significant bits.
+pub fn compress<E>(point: &ShortWeierstrassProjectivePoint<E>) -> Vec<u8>
+where
+    E: IsShortWeierstrass,
+    E::BaseField: HasZcashEncoding,
+{
+    if point.is_neutral_element() {
+        let mut bytes = vec![0; E::BaseField::to_bytes(&FieldElement::zero()).len()];
+        bytes[0] = COMPRESSION_FLAG | INFINITY_FLAG;
+        bytes
+    } else {
+        let affine = point.to_affine();
+        let mut bytes = E::BaseField::to_bytes(affine.x());
+        bytes[0] |= COMPRESSION_FLAG;
+        if E::BaseField::is_lexicographically_largest(affine.y()) {
+            bytes[0] |= SORT_FLAG;
+        }
+        bytes
+    }
+}
+
+/// Decodes a point encoded by [`compress`], checking that it belongs to the curve.
+pub fn decompress<E>(
+    bytes: &[u8],
+) -> Result<ShortWeierstrassProjectivePoint<E>, DeserializationError>
+where
+    E: IsShortWeierstrass,
+    E::BaseField: HasZcashEncoding,
+{
+    if bytes.len() != E::BaseField::to_bytes(&FieldElement::zero()).len() {
+        return Err(DeserializationError::InvalidAmountOfBytes);
+    }
+
+    let flags = compressed_flags(bytes)?;
+    if flags & INFINITY_FLAG != 0 {
+        return Ok(ShortWeierstrassProjectivePoint::neutral_element());
+    }
+
+    let mut x_bytes = bytes.to_vec();
+    x_bytes[0] &= !FLAGS_MASK;
+    let x = E::BaseField::from_bytes(&x_bytes)?;
+
+    let y_squared = x.pow(3_u16) + E::a() * &x + E::b();
+    let mut y =
+        E::BaseField::square_root(&y_squared).ok_or(DeserializationError::FieldFromBytesError)?;
+    if E::BaseField::is_lexicographically_largest(&y) != (flags & SORT_FLAG != 0) {
+        y = -y;
+    }
+
+    ShortWeierstrassProjectivePoint::from_affine(x, y)
+        .map_err(|_| DeserializationError::FieldFromBytesError)
+}
@@ -1,6 +1,11 @@
 use crate::{
     cyclic_group::IsGroup,
+    elliptic_curve::{
+        glv::{glv_mul, GlvLattice},
+        short_weierstrass::errors::DeserializationError,
+    },
     field::{element::FieldElement, traits::IsField},
+    unsigned_integer::element::UnsignedInteger,
 };
 use std::fmt::Debug;
 
@@ -35,18 +40,86 @@ pub trait FromAffine<F: IsField>: Sized {
     fn from_affine(x: FieldElement<F>, y: FieldElement<F>) -> Result<Self, EllipticCurveError>;
 }
 
+/// Counterpart of `FromAffine`, to read the affine coordinates of a point.
+pub trait ToAffine<F: IsField> {
+    /// Returns the affine coordinates `[x, y]` of `self`, or `None` if it is the
+    /// point at infinity.
+    fn to_affine_coordinates(&self) -> Option<[FieldElement<F>; 2]>;
+}
+
+/// Trait for points of curves whose group of points is larger than the prime
+/// order subgroup used in protocols, that is, curves with a cofactor.
+pub trait HasCofactor: IsGroup {
+    /// Returns true if `self` belongs to the prime order subgroup.
+    fn is_in_subgroup(&self) -> bool;
+
+    /// Maps `self` into the prime order subgroup.
+    fn clear_cofactor(&self) -> Self;
+}
+
+/// Trait for points of curves with an efficiently computable endomorphism that acts
+/// on the prime order subgroup as the multiplication by a scalar `lambda`.
+/// It allows computing scalar multiplications with the GLV method, which halves
+/// the number of doublings.
+pub trait HasEndomorphism<const NUM_LIMBS: usize>: IsGroup {
+    /// Applies the endomorphism to `self`.
+    fn endomorphism(&self) -> Self;
+
+    /// Returns the lattice used to split scalars into two halves.
+    fn glv_lattice() -> GlvLattice<NUM_LIMBS>;
+
+    /// Computes `k * self` using the GLV method.
+    /// `self` must belong to the prime order subgroup.
+    fn glv_mul(&self, k: &UnsignedInteger<NUM_LIMBS>) -> Self {
+        glv_mul(self, &self.endomorphism(), &Self::glv_lattice(), k)
+    }
+}
+
 pub trait IsPairing {
     type G1Point: IsGroup;
     type G2Point: IsGroup;
+    /// A point of G2 with the precomputed values of the Miller loop that only depend on it.
+    type G2Prepared: Clone;
     type OutputField: IsField;
 
     /// Compute the product of the pairings for a list of point pairs.
     fn compute_batch(pairs: &[(&Self::G1Point, &Self::G2Point)])
         -> FieldElement<Self::OutputField>;
 
+    /// Precomputes the values of the Miller loop for a point `q` in G2, to pair it repeatedly.
+    fn prepare(q: &Self::G2Point) -> Self::G2Prepared;
+
+    /// Compute the product of the pairings for a list of pairs of points in G1 and prepared points in G2.
+    fn compute_batch_prepared(
+        pairs: &[(&Self::G1Point, &Self::G2Prepared)],
+    ) -> FieldElement<Self::OutputField>;
+
     /// Compute the ate pairing between point `p` in G1 and `q` in G2.
     #[allow(unused)]
     fn compute(p: &Self::G1Point, q: &Self::G2Point) -> FieldElement<Self::OutputField> {
         Self::compute_batch(&[(p, q)])
     }
 }
+
+/// Operations on the target group GT of a pairing, the subgroup of order `r` of the
+/// multiplicative group of the output field. GT lies in the cyclotomic subgroup,
+/// where inverses and squarings are cheaper than for generic field elements.
+pub trait HasTargetGroup: IsPairing {
+    /// Number of bytes of the compressed encoding of an element of GT.
+    const COMPRESSED_SIZE: usize;
+
+    /// Squares an element of GT.
+    fn cyclotomic_square(a: &FieldElement<Self::OutputField>) -> FieldElement<Self::OutputField>;
+
+    /// Returns the inverse of an element of GT.
+    fn cyclotomic_inverse(a: &FieldElement<Self::OutputField>) -> FieldElement<Self::OutputField>;
+
+    /// Checks whether an element of the output field belongs to GT.
+    fn is_in_target_group(a: &FieldElement<Self::OutputField>) -> bool;
+
+    /// Encodes an element of GT in `COMPRESSED_SIZE` bytes.
+    fn compress(a: &FieldElement<Self::OutputField>) -> Vec<u8>;
+
+    /// Decodes an element encoded with `compress`. It does not check that the result is in GT.
+    fn decompress(bytes: &[u8]) -> Result<FieldElement<Self::OutputField>, DeserializationError>;
+}
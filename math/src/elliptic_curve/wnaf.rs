@@ -0,0 +1,78 @@
+use crate::cyclic_group::IsGroup;
+use crate::unsigned_integer::element::UnsignedInteger;
+
+/// Computes `k * point` using the width-`window_size` non-adjacent form of `k`.
+/// It precomputes the `2^(window_size - 2)` odd multiples `point, 3 * point, ...` and then
+/// needs one doubling per bit of `k` but only one addition every `window_size + 1` bits
+/// on average. `window_size` must be between 2 and 32; 4 or 5 work well for 256-bit scalars.
+/// For more info, see "Guide to Elliptic Curve Cryptography", Algorithm 3.36.
+pub fn wnaf_mul<G: IsGroup, const NUM_LIMBS: usize>(
+    point: &G,
+    k: &UnsignedInteger<NUM_LIMBS>,
+    window_size: usize,
+) -> G {
+    let digits = k.to_wnaf(window_size);
+    let odd_multiples = odd_multiples(point, window_size);
+
+    let mut result = G::neutral_element();
+    for digit in digits.iter().rev() {
+        result = result.operate_with(&result);
+        if *digit > 0 {
+            result = result.operate_with(&odd_multiples[(*digit as usize) / 2]);
+        } else if *digit < 0 {
+            result = result.operate_with(&odd_multiples[(digit.unsigned_abs() as usize) / 2].neg());
+        }
+    }
+    result
+}
+
+/// Returns `[point, 3 * point, ..., (2^(window_size - 1) - 1) * point]`.
+fn odd_multiples<G: IsGroup>(point: &G, window_size: usize) -> Vec<G> {
+    let double = point.operate_with(point);
+    let mut multiples = Vec::with_capacity(1 << (window_size - 2));
+    multiples.push(point.clone());
+    for i in 1..(1 << (window_size - 2)) {
+        let next = multiples[i - 1].operate_with(&double);
+        multiples.push(next);
+    }
+    multiples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::{
+        short_weierstrass::curves::bls12_381::{curve::BLS12381Curve, twist::BLS12381TwistCurve},
+        traits::IsEllipticCurve,
+    };
+    use crate::unsigned_integer::element::U256;
+
+    fn scalars() -> [U256; 4] {
+        [
+            U256::from_u64(0),
+            U256::from_u64(1),
+            U256::from_u64(1122),
+            U256::from_hex_unchecked(
+                "ffffffffffffffff3d0ef8a3d4cf5e36a3d8e4f0c67a12b1e0b1d42f04bc9a4d",
+            ),
+        ]
+    }
+
+    #[test]
+    fn wnaf_mul_matches_operate_with_self_in_g1() {
+        let g = BLS12381Curve::generator();
+        for window_size in 2..7 {
+            for k in scalars() {
+                assert_eq!(wnaf_mul(&g, &k, window_size), g.operate_with_self(k));
+            }
+        }
+    }
+
+    #[test]
+    fn wnaf_mul_matches_operate_with_self_in_g2() {
+        let g = BLS12381TwistCurve::generator();
+        for k in scalars() {
+            assert_eq!(wnaf_mul(&g, &k, 4), g.operate_with_self(k));
+        }
+    }
+}
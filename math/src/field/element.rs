@@ -12,7 +12,7 @@ use std::{
 };
 
 use super::fields::montgomery_backed_prime_fields::{IsModulus, MontgomeryBackendPrimeField};
-use super::traits::{IsPrimeField, LegendreSymbol};
+use super::traits::{HasConditionalSwap, IsPrimeField, LegendreSymbol};
 
 /// A field element with operations algorithms defined in `F`
 #[derive(Debug, Clone)]
@@ -79,6 +79,14 @@ where
     }
 }
 
+impl<F: HasConditionalSwap> FieldElement<F> {
+    /// Swaps `a` and `b` if `mask` is `u64::MAX` and leaves them unchanged if it is zero,
+    /// with the same sequence of operations in both cases.
+    pub fn conditional_swap(mask: u64, a: &mut Self, b: &mut Self) {
+        F::conditional_swap(mask, &mut a.value, &mut b.value);
+    }
+}
+
 /// Equality operator overloading for field elements
 impl<F> PartialEq<FieldElement<F>> for FieldElement<F>
 where
@@ -1,2 +1,4 @@
+/// Implementation of the two-adic base fields of the Pallas and Vesta curves.
+pub mod pasta_prime_fields;
 /// Implementation of two-adic prime field over 256 bit unsigned integers.
 pub mod stark_252_prime_field;
@@ -0,0 +1,76 @@
+use crate::{
+    field::{
+        fields::montgomery_backed_prime_fields::{IsModulus, U256PrimeField},
+        traits::IsFFTField,
+    },
+    unsigned_integer::element::U256,
+};
+
+#[derive(Clone, Debug)]
+pub struct MontgomeryConfigPallasPrimeField;
+impl IsModulus<U256> for MontgomeryConfigPallasPrimeField {
+    const MODULUS: U256 = U256::from_hex_unchecked(
+        "40000000000000000000000000000000224698fc094cf91b992d30ed00000001",
+    );
+}
+
+/// Base field of Pallas and scalar field of Vesta.
+pub type PallasPrimeField = U256PrimeField<MontgomeryConfigPallasPrimeField>;
+
+impl IsFFTField for PallasPrimeField {
+    const TWO_ADICITY: u64 = 32;
+    /// `5^((p - 1) / 2^32)`, where 5 generates the multiplicative group.
+    const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: U256 = U256::from_hex_unchecked(
+        "2bce74deac30ebda362120830561f81aea322bf2b7bb7584bdad6fabd87ea32f",
+    );
+
+    fn field_name() -> &'static str {
+        "pallas"
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MontgomeryConfigVestaPrimeField;
+impl IsModulus<U256> for MontgomeryConfigVestaPrimeField {
+    const MODULUS: U256 = U256::from_hex_unchecked(
+        "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000001",
+    );
+}
+
+/// Base field of Vesta and scalar field of Pallas.
+pub type VestaPrimeField = U256PrimeField<MontgomeryConfigVestaPrimeField>;
+
+impl IsFFTField for VestaPrimeField {
+    const TWO_ADICITY: u64 = 32;
+    /// `5^((q - 1) / 2^32)`, where 5 generates the multiplicative group.
+    const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: U256 = U256::from_hex_unchecked(
+        "2de6a9b8746d3f589e5c4dfd492ae26e9bb97ea3c106f049a70e2c1102b6d05f",
+    );
+
+    fn field_name() -> &'static str {
+        "vesta"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::element::FieldElement;
+
+    fn assert_primitive_root_of_unity<F: IsFFTField>() {
+        let root = F::get_primitive_root_of_unity::<F>(F::TWO_ADICITY).unwrap();
+        let half_order = 1_u64 << (F::TWO_ADICITY - 1);
+        assert_eq!(root.pow(half_order), -FieldElement::<F>::one());
+        assert_eq!(root.pow(2 * half_order), FieldElement::<F>::one());
+    }
+
+    #[test]
+    fn two_adic_root_of_unity_of_pallas_field_has_order_two_to_the_32() {
+        assert_primitive_root_of_unity::<PallasPrimeField>();
+    }
+
+    #[test]
+    fn two_adic_root_of_unity_of_vesta_field_has_order_two_to_the_32() {
+        assert_primitive_root_of_unity::<VestaPrimeField>();
+    }
+}
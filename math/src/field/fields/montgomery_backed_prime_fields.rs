@@ -1,5 +1,5 @@
 use crate::field::element::FieldElement;
-use crate::field::traits::IsPrimeField;
+use crate::field::traits::{HasConditionalSwap, IsPrimeField};
 use crate::traits::ByteConversion;
 use crate::{
     field::traits::IsField, unsigned_integer::element::UnsignedInteger,
@@ -189,18 +189,20 @@ where
                     }
                 }
 
+                // `b - c` and `c - b` are computed modulo the modulus. When it has no spare
+                // bits, adding it can overflow, but the wrapped difference is still correct.
                 if v <= u {
                     u = u - v;
                     if b < c {
-                        b = b + modulus;
+                        (b, _) = UnsignedInteger::<NUM_LIMBS>::add(&b, &modulus);
                     }
-                    b = b - c;
+                    (b, _) = UnsignedInteger::<NUM_LIMBS>::sub(&b, &c);
                 } else {
                     v = v - u;
                     if c < b {
-                        c = c + modulus;
+                        (c, _) = UnsignedInteger::<NUM_LIMBS>::add(&c, &modulus);
                     }
-                    c = c - b;
+                    (c, _) = UnsignedInteger::<NUM_LIMBS>::sub(&c, &b);
                 }
             }
 
@@ -248,6 +250,19 @@ where
     }
 }
 
+impl<M, const NUM_LIMBS: usize> HasConditionalSwap for MontgomeryBackendPrimeField<M, NUM_LIMBS>
+where
+    M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
+{
+    fn conditional_swap(mask: u64, a: &mut Self::BaseType, b: &mut Self::BaseType) {
+        for (a, b) in a.limbs.iter_mut().zip(b.limbs.iter_mut()) {
+            let t = mask & (*a ^ *b);
+            *a ^= t;
+            *b ^= t;
+        }
+    }
+}
+
 impl<M, const NUM_LIMBS: usize> IsPrimeField for MontgomeryBackendPrimeField<M, NUM_LIMBS>
 where
     M: IsModulus<UnsignedInteger<NUM_LIMBS>> + Clone + Debug,
@@ -700,6 +715,16 @@ mod tests_u256_prime_fields {
     type U256F29 = U256PrimeField<U256Modulus29>;
     type U256F29Element = FieldElement<U256F29>;
 
+    #[test]
+    fn conditional_swap_swaps_only_with_a_full_mask() {
+        let (a, b) = (U256F29Element::from(3), U256F29Element::from(17));
+        let (mut x, mut y) = (a.clone(), b.clone());
+        FieldElement::conditional_swap(0, &mut x, &mut y);
+        assert_eq!((&x, &y), (&a, &b));
+        FieldElement::conditional_swap(u64::MAX, &mut x, &mut y);
+        assert_eq!((x, y), (b, a));
+    }
+
     #[test]
     fn montgomery_backend_primefield_compute_r2_parameter() {
         let r2: U256 = UnsignedInteger {
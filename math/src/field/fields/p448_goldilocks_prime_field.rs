@@ -1,4 +1,7 @@
-use crate::field::traits::IsField;
+use crate::errors::ByteConversionError;
+use crate::field::element::FieldElement;
+use crate::field::traits::{HasConditionalSwap, IsField, IsPrimeField, LegendreSymbol};
+use crate::traits::ByteConversion;
 use crate::unsigned_integer::element::UnsignedInteger;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -126,8 +129,10 @@ impl IsField for P448GoldilocksPrimeField {
         Self::mul(a, &b_inv)
     }
 
+    /// Compares the canonical representations, since the limbs of an element
+    /// are only weakly reduced.
     fn eq(a: &U56x8, b: &U56x8) -> bool {
-        a.limbs == b.limbs
+        Self::strong_reduce(a).limbs == Self::strong_reduce(b).limbs
     }
 
     fn zero() -> U56x8 {
@@ -154,7 +159,90 @@ impl IsField for P448GoldilocksPrimeField {
     }
 }
 
+impl HasConditionalSwap for P448GoldilocksPrimeField {
+    fn conditional_swap(mask: u64, a: &mut U56x8, b: &mut U56x8) {
+        for (a, b) in a.limbs.iter_mut().zip(b.limbs.iter_mut()) {
+            let t = mask & (*a ^ *b);
+            *a ^= t;
+            *b ^= t;
+        }
+    }
+}
+
+impl IsPrimeField for P448GoldilocksPrimeField {
+    type RepresentativeType = U448;
+
+    fn representative(a: &U56x8) -> U448 {
+        let mut bytes = Vec::with_capacity(56);
+        for limb in Self::strong_reduce(a).limbs {
+            bytes.extend_from_slice(&limb.to_le_bytes()[..7]);
+        }
+        U448::from_bytes_le(&bytes).unwrap()
+    }
+
+    fn field_bit_size() -> usize {
+        448
+    }
+
+    /// Since `p = 3 mod 4`, the square roots of a square `a` are `a^((p + 1) / 4)` and its opposite.
+    fn sqrt(a: &U56x8) -> Option<(U56x8, U56x8)> {
+        match Self::legendre_symbol(a) {
+            LegendreSymbol::Zero => Some((Self::zero(), Self::zero())),
+            LegendreSymbol::MinusOne => None,
+            LegendreSymbol::One => {
+                let root = Self::pow(
+                    a,
+                    (P448_GOLDILOCKS_PRIME_FIELD_ORDER >> 2) + U448::from_u64(1),
+                );
+                let minus_root = Self::neg(&root);
+                Some((root, minus_root))
+            }
+        }
+    }
+}
+
 impl P448GoldilocksPrimeField {
+    /// Returns the element with the given integer representative, which may be
+    /// larger than the modulus.
+    pub fn from_integer(x: &U448) -> U56x8 {
+        let bytes = x.to_bytes_le();
+        let mut limbs = [0u64; 8];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(7)) {
+            let mut limb_bytes = [0u8; 8];
+            limb_bytes[..7].copy_from_slice(chunk);
+            *limb = u64::from_le_bytes(limb_bytes);
+        }
+        Self::from_base_type(U56x8 { limbs })
+    }
+
+    /// Returns the canonical representation of `a`, with every limb smaller than
+    /// `2^56` and the value smaller than the modulus.
+    fn strong_reduce(a: &U56x8) -> U56x8 {
+        let mut a = *a;
+        Self::weak_reduce(&mut a);
+        let a = &mut a.limbs;
+        let mask = (1u64 << 56) - 1;
+        let p_limb = |i: usize| if i == 4 { mask - 1 } else { mask };
+
+        // Subtract p, which leaves a borrow of -1 if the value was smaller than p.
+        let mut borrow = 0i128;
+        for (i, limb) in a.iter_mut().enumerate() {
+            borrow += *limb as i128 - p_limb(i) as i128;
+            *limb = (borrow as u64) & mask;
+            borrow >>= 56;
+        }
+
+        // Add p back if there was a borrow.
+        let add_back = (borrow as u64) & mask;
+        let mut carry = 0u128;
+        for (i, limb) in a.iter_mut().enumerate() {
+            carry += *limb as u128 + (p_limb(i) & add_back) as u128;
+            *limb = (carry as u64) & mask;
+            carry >>= 56;
+        }
+        U56x8 { limbs: *a }
+    }
+
     fn weak_reduce(a: &mut U56x8) {
         let a = &mut a.limbs;
 
@@ -202,10 +290,45 @@ impl U56x8 {
     }
 }
 
+impl ByteConversion for FieldElement<P448GoldilocksPrimeField> {
+    fn to_bytes_be(&self) -> Vec<u8> {
+        self.representative().to_bytes_be()
+    }
+
+    fn to_bytes_le(&self) -> Vec<u8> {
+        self.representative().to_bytes_le()
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let value = U448::from_bytes_be(bytes)?;
+        Ok(Self::from_raw(&P448GoldilocksPrimeField::from_integer(
+            &value,
+        )))
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let value = U448::from_bytes_le(bytes)?;
+        Ok(Self::from_raw(&P448GoldilocksPrimeField::from_integer(
+            &value,
+        )))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn conditional_swap_swaps_only_with_a_full_mask() {
+        type FE = FieldElement<P448GoldilocksPrimeField>;
+        let (a, b) = (FE::from(5), -FE::from(8));
+        let (mut x, mut y) = (a.clone(), b.clone());
+        FE::conditional_swap(0, &mut x, &mut y);
+        assert_eq!((&x, &y), (&a, &b));
+        FE::conditional_swap(u64::MAX, &mut x, &mut y);
+        assert_eq!((x, y), (b, a));
+    }
+
     #[test]
     fn construc_u56x8_from_hex_string_1() {
         let hex_str = "1";
@@ -301,4 +424,43 @@ mod tests {
         let num2 = P448GoldilocksPrimeField::from_base_type(num1);
         assert_eq!(num2, U56x8::from("564a75b90ae34f8155d5821d7e9484"));
     }
+
+    type FE = FieldElement<P448GoldilocksPrimeField>;
+
+    #[test]
+    fn p448_representative_is_canonical() {
+        let p = FE::from_raw(&P448GoldilocksPrimeField::from_integer(
+            &P448_GOLDILOCKS_PRIME_FIELD_ORDER,
+        ));
+        assert_eq!(p, FE::zero());
+        assert_eq!(p.representative(), U448::from_u64(0));
+        let minus_one = -FE::one();
+        assert_eq!(
+            minus_one.representative(),
+            P448_GOLDILOCKS_PRIME_FIELD_ORDER - U448::from_u64(1)
+        );
+    }
+
+    #[test]
+    fn p448_sqrt_test_1() {
+        let a = FE::from(39081);
+        let (root, minus_root) = a.square().sqrt().unwrap();
+        assert!(root == a || minus_root == a);
+        assert_eq!(&root + &minus_root, FE::zero());
+        // -1 is not a square, since p = 3 mod 4.
+        assert_eq!((-FE::one()).sqrt(), None);
+        assert_eq!(FE::zero().sqrt(), Some((FE::zero(), FE::zero())));
+    }
+
+    #[test]
+    fn p448_byte_conversion_roundtrip() {
+        let a = FE::new(U56x8::from("49bbeeaa7102b38a0cfba4634f64a288bcb9b1366599f7afcb5453567ef7c34cce0f7139c6dea4841497172f637c7bbbf3ca1990ad88381e"));
+        let bytes = a.to_bytes_be();
+        assert_eq!(bytes.len(), 56);
+        assert_eq!(bytes[0], 0x49);
+        assert_eq!(FE::from_bytes_be(&bytes).unwrap(), a);
+        let bytes = a.to_bytes_le();
+        assert_eq!(bytes[0], 0x1e);
+        assert_eq!(FE::from_bytes_le(&bytes).unwrap(), a);
+    }
 }
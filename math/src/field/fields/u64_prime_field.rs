@@ -2,7 +2,7 @@ use crate::cyclic_group::IsGroup;
 use crate::elliptic_curve::short_weierstrass::errors::DeserializationError;
 use crate::errors::ByteConversionError::{FromBEBytesError, FromLEBytesError};
 use crate::field::element::FieldElement;
-use crate::field::traits::{IsFFTField, IsField, IsPrimeField};
+use crate::field::traits::{HasConditionalSwap, IsFFTField, IsField, IsPrimeField};
 use crate::traits::{ByteConversion, Deserializable, Serializable};
 
 /// Type representing prime fields over unsigned 64-bit integers.
@@ -69,6 +69,14 @@ impl<const MODULUS: u64> IsField for U64PrimeField<MODULUS> {
 
 impl<const MODULUS: u64> Copy for U64FieldElement<MODULUS> {}
 
+impl<const MODULUS: u64> HasConditionalSwap for U64PrimeField<MODULUS> {
+    fn conditional_swap(mask: u64, a: &mut u64, b: &mut u64) {
+        let t = mask & (*a ^ *b);
+        *a ^= t;
+        *b ^= t;
+    }
+}
+
 impl<const MODULUS: u64> IsPrimeField for U64PrimeField<MODULUS> {
     type RepresentativeType = u64;
 
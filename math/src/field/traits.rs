@@ -136,6 +136,13 @@ pub trait IsField: Debug + Clone {
     fn from_base_type(x: Self::BaseType) -> Self::BaseType;
 }
 
+/// Fields whose elements can be swapped without branching on the condition, by masking
+/// the limbs of their representation. Used where the condition is secret.
+pub trait HasConditionalSwap: IsField {
+    /// Swaps `a` and `b` if `mask` is `u64::MAX` and leaves them unchanged if it is zero.
+    fn conditional_swap(mask: u64, a: &mut Self::BaseType, b: &mut Self::BaseType);
+}
+
 #[derive(PartialEq)]
 pub enum LegendreSymbol {
     MinusOne,
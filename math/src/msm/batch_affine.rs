@@ -0,0 +1,335 @@
+use crate::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{point::ShortWeierstrassProjectivePoint, traits::IsShortWeierstrass},
+        traits::IsEllipticCurve,
+    },
+    field::element::FieldElement,
+    unsigned_integer::element::UnsignedInteger,
+};
+
+use super::{naive::MSMError, pippenger};
+
+/// Affine coordinates `[x, y]` of a point other than the point at infinity.
+pub struct AffineBase<E: IsEllipticCurve>(pub [FieldElement<E::BaseField>; 2]);
+
+impl<E: IsEllipticCurve> Clone for AffineBase<E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<E: IsEllipticCurve> core::fmt::Debug for AffineBase<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("AffineBase").field(&self.0).finish()
+    }
+}
+
+impl<E: IsEllipticCurve> PartialEq for AffineBase<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<E: IsEllipticCurve> Eq for AffineBase<E> {}
+
+/// Below this number of additions in a round, the remaining additions of the window are
+/// done in projective coordinates, since a batch inversion no longer pays off.
+const MIN_BATCH_ADDITIONS: usize = 32;
+
+/// Converts `points` to affine coordinates with a single field inversion.
+/// The point at infinity has no affine coordinates and is mapped to `None`.
+pub fn batch_to_affine<E: IsShortWeierstrass>(
+    points: &[ShortWeierstrassProjectivePoint<E>],
+) -> Vec<Option<AffineBase<E>>> {
+    let mut z_invs: Vec<_> = points
+        .iter()
+        .filter(|p| !p.is_neutral_element())
+        .map(|p| p.z().clone())
+        .collect();
+    FieldElement::inplace_batch_inverse(&mut z_invs);
+    let mut z_invs = z_invs.into_iter();
+    points
+        .iter()
+        .map(|p| {
+            if p.is_neutral_element() {
+                None
+            } else {
+                let z_inv = z_invs.next().unwrap();
+                Some(AffineBase([p.x() * &z_inv, p.y() * z_inv]))
+            }
+        })
+        .collect()
+}
+
+/// Computes the multiscalar multiplication `k_1 * g_1 + ... + k_n * g_n` of points given
+/// in affine coordinates, with the window size chosen as in `pippenger::msm`.
+///
+/// Returns an error if `cs` and `bases` have different lengths.
+pub fn msm<const NUM_LIMBS: usize, E: IsShortWeierstrass>(
+    cs: &[UnsignedInteger<NUM_LIMBS>],
+    bases: &[AffineBase<E>],
+) -> Result<ShortWeierstrassProjectivePoint<E>, MSMError> {
+    if cs.len() != bases.len() {
+        return Err(MSMError::LengthMismatch(cs.len(), bases.len()));
+    }
+
+    let window_size = pippenger::optimum_window_size(cs.len());
+
+    Ok(msm_with(cs, bases, window_size))
+}
+
+/// Signed-digit Pippenger whose buckets are kept in affine coordinates.
+///
+/// The additions to the buckets of a window are done in rounds. Each round adds at most
+/// one point to every bucket, so that the additions are independent and all of their
+/// slopes are computed with a single batch inversion. An affine addition then costs
+/// about half of a projective one. Points whose bucket already has an addition in the
+/// current round are deferred to the next one.
+pub fn msm_with<const NUM_LIMBS: usize, E: IsShortWeierstrass>(
+    cs: &[UnsignedInteger<NUM_LIMBS>],
+    bases: &[AffineBase<E>],
+    window_size: usize,
+) -> ShortWeierstrassProjectivePoint<E> {
+    const MIN_WINDOW_SIZE: usize = 2;
+    const MAX_WINDOW_SIZE: usize = 32;
+
+    let window_size = window_size.clamp(MIN_WINDOW_SIZE, MAX_WINDOW_SIZE);
+    let num_windows = 64 * NUM_LIMBS / window_size + 1;
+    let n_buckets = 1 << (window_size - 1);
+
+    let mut buckets = WindowBuckets::<E>::new(n_buckets);
+
+    (0..num_windows)
+        .rev()
+        .map(|window_idx| {
+            let additions = cs.iter().zip(bases).filter_map(|(k, AffineBase(p))| {
+                let digit = pippenger::signed_digit(k, window_idx, window_size);
+                match digit {
+                    0 => None,
+                    d if d > 0 => Some(((d - 1) as usize, p.clone())),
+                    d => Some(((-d - 1) as usize, [p[0].clone(), -&p[1]])),
+                }
+            });
+            buckets.accumulate(additions.collect());
+            buckets.reduce()
+        })
+        // NOTE: this operation is non-associative and strictly sequential
+        .reduce(|t, g| t.operate_with_self(1_u64 << window_size).operate_with(&g))
+        .unwrap_or_else(ShortWeierstrassProjectivePoint::neutral_element)
+}
+
+/// The buckets of a window: an affine part, where `None` is the point at infinity, and
+/// a projective part collecting the additions left over by the batched rounds.
+struct WindowBuckets<E: IsShortWeierstrass> {
+    affine: Vec<Option<[FieldElement<E::BaseField>; 2]>>,
+    projective: Vec<ShortWeierstrassProjectivePoint<E>>,
+    busy: Vec<bool>,
+}
+
+impl<E: IsShortWeierstrass> WindowBuckets<E> {
+    fn new(n_buckets: usize) -> Self {
+        Self {
+            affine: vec![None; n_buckets],
+            projective: vec![ShortWeierstrassProjectivePoint::neutral_element(); n_buckets],
+            busy: vec![false; n_buckets],
+        }
+    }
+
+    /// Adds every point of `pending` to the bucket it is paired with.
+    fn accumulate(&mut self, mut pending: Vec<(usize, [FieldElement<E::BaseField>; 2])>) {
+        let threshold = MIN_BATCH_ADDITIONS.min(self.affine.len());
+        while !pending.is_empty() {
+            let mut deferred = Vec::new();
+            // Each scheduled addition keeps its bucket, the point, and the numerator of
+            // the slope, whose denominator goes to `denominators` at the same position.
+            let mut scheduled = Vec::new();
+            let mut denominators = Vec::new();
+
+            for (idx, p) in pending {
+                if self.busy[idx] {
+                    deferred.push((idx, p));
+                    continue;
+                }
+                let Some(q) = &self.affine[idx] else {
+                    self.affine[idx] = Some(p);
+                    continue;
+                };
+                if p[0] != q[0] {
+                    denominators.push(&q[0] - &p[0]);
+                    let numerator = &q[1] - &p[1];
+                    scheduled.push((idx, p, numerator));
+                } else if p[1] == q[1] && p[1] != FieldElement::zero() {
+                    denominators.push(&p[1] + &p[1]);
+                    let numerator = p[0].square() * FieldElement::from(3) + E::a();
+                    scheduled.push((idx, p, numerator));
+                } else {
+                    // `p = -q`, so the bucket becomes the point at infinity.
+                    self.affine[idx] = None;
+                    continue;
+                }
+                self.busy[idx] = true;
+            }
+
+            FieldElement::inplace_batch_inverse(&mut denominators);
+            for ((idx, p, numerator), denominator_inv) in scheduled.iter().zip(denominators) {
+                let q = self.affine[*idx].as_ref().unwrap();
+                let lambda = numerator * denominator_inv;
+                let x = lambda.square() - &p[0] - &q[0];
+                let y = lambda * (&p[0] - &x) - &p[1];
+                self.affine[*idx] = Some([x, y]);
+                self.busy[*idx] = false;
+            }
+
+            if !deferred.is_empty() && scheduled.len() < threshold {
+                for (idx, [x, y]) in deferred {
+                    let p = ShortWeierstrassProjectivePoint::new([x, y, FieldElement::one()]);
+                    self.projective[idx] = self.projective[idx].operate_with(&p);
+                }
+                return;
+            }
+            pending = deferred;
+        }
+    }
+
+    /// Returns the sum of the buckets weighted by their index, `b_1 + 2 * b_2 + ...`,
+    /// and empties them for the next window.
+    fn reduce(&mut self) -> ShortWeierstrassProjectivePoint<E> {
+        let mut running_sum = ShortWeierstrassProjectivePoint::neutral_element();
+        let mut total = ShortWeierstrassProjectivePoint::neutral_element();
+        for (affine, projective) in self.affine.iter_mut().zip(&mut self.projective).rev() {
+            if let Some([x, y]) = affine.take() {
+                let p = ShortWeierstrassProjectivePoint::new([x, y, FieldElement::one()]);
+                running_sum = running_sum.operate_with(&p);
+            }
+            if !projective.is_neutral_element() {
+                running_sum = running_sum.operate_with(projective);
+                *projective = ShortWeierstrassProjectivePoint::neutral_element();
+            }
+            total = total.operate_with(&running_sum);
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elliptic_curve::short_weierstrass::curves::bls12_381::curve::BLS12381Curve, msm::naive,
+    };
+    use proptest::{collection, prelude::*, prop_assert_eq, prop_compose, proptest};
+
+    type G1 = ShortWeierstrassProjectivePoint<BLS12381Curve>;
+
+    const _CASES: u32 = 20;
+    const _MAX_WSIZE: usize = 8;
+    const _MAX_LEN: usize = 30;
+
+    prop_compose! {
+        fn unsigned_integer()(limbs: [u64; 6]) -> UnsignedInteger<6> {
+            UnsignedInteger::from_limbs(limbs)
+        }
+    }
+
+    prop_compose! {
+        fn point()(power: u128) -> G1 {
+            BLS12381Curve::generator().operate_with_self(power)
+        }
+    }
+
+    prop_compose! {
+        fn scalars_and_points()(len in 0.._MAX_LEN)(
+            cs in collection::vec(unsigned_integer(), len),
+            points in collection::vec(point(), len),
+        ) -> (Vec<UnsignedInteger<6>>, Vec<G1>) {
+            (cs, points)
+        }
+    }
+
+    /// Drops the points at infinity along with their scalars, since they add nothing.
+    fn affine_inputs(
+        cs: &[UnsignedInteger<6>],
+        points: &[G1],
+    ) -> (Vec<UnsignedInteger<6>>, Vec<AffineBase<BLS12381Curve>>) {
+        cs.iter()
+            .zip(batch_to_affine(points))
+            .filter_map(|(k, p)| p.map(|p| (*k, p)))
+            .unzip()
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig {
+            cases: _CASES, .. ProptestConfig::default()
+          })]
+        // Property-based test that ensures `batch_affine::msm_with` gives same result as `naive::msm`.
+        #[test]
+        fn test_batch_affine_pippenger_matches_naive_msm(window_size in 1.._MAX_WSIZE, (cs, points) in scalars_and_points()) {
+            let (affine_cs, bases) = affine_inputs(&cs, &points);
+            let batch_affine = msm_with(&affine_cs, &bases, window_size);
+            let naive = naive::msm(&cs, &points).unwrap();
+
+            prop_assert_eq!(naive, batch_affine);
+        }
+    }
+
+    #[test]
+    fn batch_to_affine_matches_to_affine() {
+        let g = BLS12381Curve::generator();
+        let points = [
+            g.operate_with_self(5_u64),
+            G1::neutral_element(),
+            g.operate_with_self(7_u64),
+        ];
+        let affine = batch_to_affine(&points);
+        assert_eq!(affine[1], None);
+        for i in [0, 2] {
+            let [x, y, _] = points[i].to_affine().coordinates().clone();
+            assert_eq!(affine[i], Some(AffineBase([x, y])));
+        }
+    }
+
+    #[test]
+    fn repeated_and_opposite_points_in_a_bucket_are_handled() {
+        // Equal points double in their bucket, opposite ones cancel, and the many
+        // additions to the same buckets exhaust the batched rounds.
+        let g = BLS12381Curve::generator();
+        let h = g.operate_with_self(3_u64);
+        let mut points = vec![g.clone(), g.clone(), g.neg(), h.clone(), h.neg()];
+        points.extend((0..200).map(|i| if i % 3 == 0 { g.clone() } else { h.clone() }));
+        let cs: Vec<_> = (0..points.len() as u64)
+            .map(|i| UnsignedInteger::<6>::from_u64(1 + i % 5))
+            .collect();
+        let (affine_cs, bases) = affine_inputs(&cs, &points);
+        let naive = naive::msm(&cs, &points).unwrap();
+        for window_size in [2, 3, 4, 8] {
+            assert_eq!(msm_with(&affine_cs, &bases, window_size), naive);
+        }
+        assert_eq!(msm(&affine_cs, &bases).unwrap(), naive);
+    }
+
+    #[test]
+    fn msm_matches_pippenger_on_many_points() {
+        let g = BLS12381Curve::generator();
+        let points: Vec<_> = (1..=300_u64)
+            .map(|i| g.operate_with_self(i * i + 17))
+            .collect();
+        let cs: Vec<_> = (1..=300_u64)
+            .map(|i| UnsignedInteger::<6>::from_limbs([i, i * 3, i << 20, i * i, 7, u64::MAX - i]))
+            .collect();
+        let (affine_cs, bases) = affine_inputs(&cs, &points);
+        let expected = pippenger::msm(&cs, &points).unwrap();
+        for window_size in [3, 5, 6] {
+            assert_eq!(msm_with(&affine_cs, &bases, window_size), expected);
+        }
+    }
+
+    #[test]
+    fn msm_rejects_length_mismatch() {
+        let bases = batch_to_affine(&[BLS12381Curve::generator()])
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        assert!(msm::<6, BLS12381Curve>(&[], &bases).is_err());
+    }
+}
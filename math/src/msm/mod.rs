@@ -1,2 +1,5 @@
+pub mod batch_affine;
 pub mod naive;
 pub mod pippenger;
+pub mod precomputed;
+pub mod streaming;
@@ -1,10 +1,17 @@
 use crate::cyclic_group::IsGroup;
+use crate::elliptic_curve::short_weierstrass::errors::DeserializationError;
 use crate::unsigned_integer::traits::IsUnsignedInteger;
 
 #[derive(Debug, thiserror::Error)]
 pub enum MSMError {
     #[error("`cs` and `points` must be of the same length to compute `msm`. Got: {0} and {1}")]
     LengthMismatch(usize, usize),
+    #[error("The precomputed bases only support scalars of up to {0} bits")]
+    ScalarTooLarge(usize),
+    #[error("Error when reading the points: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Error when deserializing the points: {0}")]
+    Deserialization(#[from] DeserializationError),
 }
 
 /// This function computes the multiscalar multiplication (MSM).
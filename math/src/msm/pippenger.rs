@@ -1,4 +1,8 @@
-use crate::{cyclic_group::IsGroup, unsigned_integer::element::UnsignedInteger};
+use crate::{
+    cyclic_group::IsGroup,
+    field::{element::FieldElement, traits::IsPrimeField},
+    unsigned_integer::element::UnsignedInteger,
+};
 
 use super::naive::MSMError;
 
@@ -26,10 +30,13 @@ where
 
     let window_size = optimum_window_size(cs.len());
 
-    Ok(msm_with(cs, points, window_size))
+    // Signed digits need half the buckets of unsigned ones for the same window size,
+    // at the cost of one extra window, which is always worth it.
+    Ok(signed_msm_with(cs, points, window_size))
 }
 
-fn optimum_window_size(data_length: usize) -> usize {
+/// Returns the window size used by `msm` for `data_length` points.
+pub fn optimum_window_size(data_length: usize) -> usize {
     const SCALE_FACTORS: (usize, usize) = (4, 5);
 
     // We approximate the optimum window size with: f(n) = k * log2(n), where k is a scaling factor
@@ -158,16 +165,245 @@ where
         .reduce(G::neutral_element, |a, b| a.operate_with(&b))
 }
 
+/// Returns the `window_idx`-th digit of `k` in the signed base `2^window_size`
+/// representation, whose digits lie in `[-2^(window_size - 1), 2^(window_size - 1)]`.
+///
+/// A window whose top bit is set is taken as negative, subtracting `2^window_size`
+/// from it and carrying one into the next window. Since the carry into a window is
+/// the top bit of the previous one, every digit can be computed independently.
+pub(crate) fn signed_digit<const NUM_LIMBS: usize>(
+    k: &UnsignedInteger<NUM_LIMBS>,
+    window_idx: usize,
+    window_size: usize,
+) -> i64 {
+    let mask = (1_u64 << window_size) - 1;
+    let (window, carry) = if window_idx == 0 {
+        (k.limbs[NUM_LIMBS - 1] & mask, 0)
+    } else {
+        // We read the top bit of the previous window along with the current one.
+        // This is ok because window_size < 63.
+        let bits = (k >> (window_idx * window_size - 1)).limbs[NUM_LIMBS - 1];
+        ((bits >> 1) & mask, bits & 1)
+    };
+    let borrow = window >> (window_size - 1);
+    (window + carry) as i64 - ((borrow << window_size) as i64)
+}
+
+/// Adds every point to the bucket of the absolute value of its signed digit for the
+/// window `window_idx`, negated if the digit is negative, and returns the sum of the
+/// buckets weighted by their index. The buckets are left empty for reuse.
+pub(crate) fn signed_window_sum<const NUM_LIMBS: usize, G>(
+    cs: &[UnsignedInteger<NUM_LIMBS>],
+    points: &[G],
+    window_idx: usize,
+    window_size: usize,
+    buckets: &mut [G],
+) -> G
+where
+    G: IsGroup,
+{
+    cs.iter().zip(points).for_each(|(k, p)| {
+        add_to_bucket(buckets, signed_digit(k, window_idx, window_size), p);
+    });
+
+    reduce_buckets(buckets)
+}
+
+/// Adds `p` to the bucket of `digit`, or `-p` to the bucket of `-digit` if it is negative.
+pub(crate) fn add_to_bucket<G: IsGroup>(buckets: &mut [G], digit: i64, p: &G) {
+    if digit > 0 {
+        let idx = (digit - 1) as usize;
+        buckets[idx] = buckets[idx].operate_with(p);
+    } else if digit < 0 {
+        let idx = (-digit - 1) as usize;
+        buckets[idx] = buckets[idx].operate_with(&p.neg());
+    }
+}
+
+/// Returns `b_1 + 2 * b_2 + ... + n * b_n` for the `n` buckets, leaving them empty for reuse.
+pub(crate) fn reduce_buckets<G: IsGroup>(buckets: &mut [G]) -> G {
+    buckets
+        .iter_mut()
+        .rev()
+        .scan(G::neutral_element(), |m, b| {
+            *m = m.operate_with(b); // Reduction step.
+            *b = G::neutral_element(); // Cleanup bucket slot to reuse in the next window.
+            Some(m.clone())
+        })
+        .reduce(|g, m| g.operate_with(&m))
+        .unwrap_or_else(G::neutral_element)
+}
+
+/// Same as `msm_with`, but decomposing the scalars in signed digits, so that each window
+/// only needs `2^(window_size - 1)` buckets instead of `2^window_size - 1`. Negative digits
+/// add the negated point to the bucket of their absolute value, since negation is cheap.
+pub fn signed_msm_with<const NUM_LIMBS: usize, G>(
+    cs: &[UnsignedInteger<NUM_LIMBS>],
+    points: &[G],
+    window_size: usize,
+) -> G
+where
+    G: IsGroup,
+{
+    const MIN_WINDOW_SIZE: usize = 2;
+    const MAX_WINDOW_SIZE: usize = 32;
+
+    let window_size = window_size.clamp(MIN_WINDOW_SIZE, MAX_WINDOW_SIZE);
+
+    // The last carry needs an extra window when `window_size` divides the bit length,
+    // so there are floor(lambda/s) + 1 windows.
+    let num_windows = 64 * NUM_LIMBS / window_size + 1;
+
+    let mut buckets = vec![G::neutral_element(); 1 << (window_size - 1)];
+
+    (0..num_windows)
+        .rev()
+        .map(|window_idx| signed_window_sum(cs, points, window_idx, window_size, &mut buckets))
+        // NOTE: this operation is non-associative and strictly sequential
+        .reduce(|t, g| t.operate_with_self(1_u64 << window_size).operate_with(&g))
+        .unwrap_or_else(G::neutral_element)
+}
+
+#[cfg(feature = "rayon")]
+/// Parallel version of `signed_msm_with`, which computes the windows in parallel as
+/// `parallel_msm_with` does.
+pub fn parallel_signed_msm_with<const NUM_LIMBS: usize, G>(
+    cs: &[UnsignedInteger<NUM_LIMBS>],
+    points: &[G],
+    window_size: usize,
+) -> G
+where
+    G: IsGroup + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    const MIN_WINDOW_SIZE: usize = 2;
+    const MAX_WINDOW_SIZE: usize = 32;
+
+    let window_size = window_size.clamp(MIN_WINDOW_SIZE, MAX_WINDOW_SIZE);
+    let num_windows = 64 * NUM_LIMBS / window_size + 1;
+
+    (0..num_windows)
+        .into_par_iter()
+        .map(|window_idx| {
+            let mut buckets = vec![G::neutral_element(); 1 << (window_size - 1)];
+            let window_item = signed_window_sum(cs, points, window_idx, window_size, &mut buckets);
+            // The shift of the last window can be the bit length of the scalars, which
+            // does not fit in an `UnsignedInteger<NUM_LIMBS>`, so we double instead.
+            (0..window_idx * window_size).fold(window_item, |acc, _| acc.operate_with(&acc))
+        })
+        .reduce(G::neutral_element, |a, b| a.operate_with(&b))
+}
+
+/// Scalars of at most this number of bits, or whose negation is, are multiplied in a
+/// separate MSM that only goes through the windows of their bits.
+const SMALL_SCALAR_BITS: u32 = 64;
+
+/// Computes the multiscalar multiplication of `points` by the field elements `cs`, as
+/// `msm` does with their representatives.
+///
+/// Zero scalars, which are common in witness commitments, are skipped, and the points
+/// of the scalars 1 and -1 are added or subtracted directly. The remaining scalars that
+/// are small, or whose negation is, are multiplied apart from the rest, and each group only
+/// processes the windows up to the largest bit length of its scalars.
+///
+/// Returns an error if `cs` and `points` have different lengths.
+pub fn msm_field<F, const NUM_LIMBS: usize, G>(
+    cs: &[FieldElement<F>],
+    points: &[G],
+) -> Result<G, MSMError>
+where
+    F: IsPrimeField<RepresentativeType = UnsignedInteger<NUM_LIMBS>>,
+    G: IsGroup,
+{
+    if cs.len() != points.len() {
+        return Err(MSMError::LengthMismatch(cs.len(), points.len()));
+    }
+
+    let one = UnsignedInteger::from_u64(1);
+    let mut unit_terms = G::neutral_element();
+    let mut small_terms = Vec::new();
+    let mut large_terms = Vec::new();
+
+    for (c, p) in cs.iter().zip(points) {
+        let k = c.representative();
+        if k.bits() == 0 {
+            continue;
+        }
+        if k == one {
+            unit_terms = unit_terms.operate_with(p);
+        } else if k.bits() <= SMALL_SCALAR_BITS {
+            small_terms.push((k, p, false));
+        } else {
+            let minus_k = (-c).representative();
+            if minus_k == one {
+                unit_terms = unit_terms.operate_with(&p.neg());
+            } else if minus_k.bits() <= SMALL_SCALAR_BITS {
+                small_terms.push((minus_k, p, true));
+            } else {
+                large_terms.push((k, p, false));
+            }
+        }
+    }
+
+    Ok(unit_terms
+        .operate_with(&signed_msm_of_terms(&small_terms))
+        .operate_with(&signed_msm_of_terms(&large_terms)))
+}
+
+/// Signed-digit Pippenger over the terms `(k, p, negate)`, which stand for `k * p`, or
+/// `-k * p` if `negate` is set. Only the windows up to the largest bit length of the
+/// scalars are processed.
+fn signed_msm_of_terms<const NUM_LIMBS: usize, G>(
+    terms: &[(UnsignedInteger<NUM_LIMBS>, &G, bool)],
+) -> G
+where
+    G: IsGroup,
+{
+    let num_bits = terms.iter().map(|(k, _, _)| k.bits()).max().unwrap_or(0) as usize;
+    if num_bits == 0 {
+        return G::neutral_element();
+    }
+
+    const MIN_WINDOW_SIZE: usize = 2;
+    let window_size =
+        optimum_window_size(terms.len()).clamp(MIN_WINDOW_SIZE, num_bits.max(MIN_WINDOW_SIZE));
+    let num_windows = num_bits / window_size + 1;
+    let mut buckets = vec![G::neutral_element(); 1 << (window_size - 1)];
+
+    (0..num_windows)
+        .rev()
+        .map(|window_idx| {
+            for (k, p, negate) in terms {
+                let digit = signed_digit(k, window_idx, window_size);
+                add_to_bucket(&mut buckets, if *negate { -digit } else { digit }, *p);
+            }
+            reduce_buckets(&mut buckets)
+        })
+        // NOTE: this operation is non-associative and strictly sequential
+        .reduce(|t, g| t.operate_with_self(1_u64 << window_size).operate_with(&g))
+        .unwrap_or_else(G::neutral_element)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::cyclic_group::IsGroup;
     use crate::msm::{naive, pippenger};
     use crate::{
         elliptic_curve::{
-            short_weierstrass::curves::bls12_381::curve::BLS12381Curve, traits::IsEllipticCurve,
+            short_weierstrass::{
+                curves::bls12_381::{
+                    curve::BLS12381Curve,
+                    default_types::{FrElement, FrField},
+                },
+                point::ShortWeierstrassProjectivePoint,
+            },
+            traits::IsEllipticCurve,
         },
         unsigned_integer::element::UnsignedInteger,
     };
+
+    type G1 = ShortWeierstrassProjectivePoint<BLS12381Curve>;
     use proptest::{collection, prelude::*, prop_assert_eq, prop_compose, proptest};
 
     const _CASES: u32 = 20;
@@ -192,6 +428,18 @@ mod tests {
         }
     }
 
+    prop_compose! {
+        // Zero, small, negated small and random scalars, in similar proportions.
+        fn field_element()(kind in 0..4_u8, small: u32, limbs: [u64; 4]) -> FrElement {
+            match kind {
+                0 => FrElement::zero(),
+                1 => FrElement::from(small as u64),
+                2 => -FrElement::from(small as u64),
+                _ => FrElement::new(UnsignedInteger::from_limbs(limbs)),
+            }
+        }
+    }
+
     prop_compose! {
         fn points_vec()(vec in collection::vec(point(), 0.._MAX_LEN)) -> Vec<<BLS12381Curve as IsEllipticCurve>::PointRepresentation> {
             vec
@@ -228,5 +476,116 @@ mod tests {
 
             prop_assert_eq!(parallel, sequential);
         }
+
+        // Property-based test that ensures `pippenger::msm_field` gives same result as `naive::msm`.
+        #[test]
+        fn test_msm_field_matches_naive_msm(cs in collection::vec(field_element(), 0.._MAX_LEN), points in points_vec()) {
+            let min_len = cs.len().min(points.len());
+            let cs = cs[..min_len].to_vec();
+            let points = points[..min_len].to_vec();
+
+            let representatives: Vec<_> = cs.iter().map(|c| c.representative()).collect();
+            let field = pippenger::msm_field(&cs, &points).unwrap();
+            let naive = naive::msm(&representatives, &points).unwrap();
+
+            prop_assert_eq!(naive, field);
+        }
+
+        // Property-based test that ensures `pippenger::signed_msm_with` gives same result as `naive::msm`.
+        #[test]
+        fn test_signed_pippenger_matches_naive_msm(window_size in 1.._MAX_WSIZE, cs in unsigned_integer_vec(), points in points_vec()) {
+            let min_len = cs.len().min(points.len());
+            let cs = cs[..min_len].to_vec();
+            let points = points[..min_len].to_vec();
+
+            let signed = pippenger::signed_msm_with(&cs, &points, window_size);
+            let naive = naive::msm(&cs, &points).unwrap();
+
+            prop_assert_eq!(naive, signed);
+        }
+
+        // Property-based test that ensures `pippenger::parallel_signed_msm_with` gives same result as `pippenger::signed_msm_with`.
+        #[test]
+        #[cfg(feature = "rayon")]
+        fn test_parallel_signed_pippenger_matches_sequential(window_size in 1.._MAX_WSIZE, cs in unsigned_integer_vec(), points in points_vec()) {
+            let min_len = cs.len().min(points.len());
+            let cs = cs[..min_len].to_vec();
+            let points = points[..min_len].to_vec();
+
+            let sequential = pippenger::signed_msm_with(&cs, &points, window_size);
+            let parallel = pippenger::parallel_signed_msm_with(&cs, &points, window_size);
+
+            prop_assert_eq!(parallel, sequential);
+        }
+    }
+
+    #[test]
+    fn test_msm_field_matches_naive_msm_on_special_scalars() {
+        let minus_one = -FrElement::one();
+        let cs = vec![
+            FrElement::zero(),
+            FrElement::one(),
+            minus_one.clone(),
+            FrElement::from(2),
+            -FrElement::from(3),
+            FrElement::from(u64::MAX),
+            -FrElement::from(u64::MAX),
+            FrElement::from_hex_unchecked("10000000000000000"),
+            FrElement::from_hex_unchecked(
+                "3c208c16d87cfd47efcfca3b7acb1f3a2df6e1a7b1ba4c5b3a0b6c2cf1f2e4d5",
+            ),
+            minus_one.clone() * FrElement::from(1 << 20) * FrElement::from(u64::MAX),
+            FrElement::zero(),
+            FrElement::zero(),
+        ];
+        let points: Vec<_> = (1..=cs.len() as u64)
+            .map(|i| BLS12381Curve::generator().operate_with_self(i * 101))
+            .collect();
+        let representatives: Vec<_> = cs.iter().map(|c| c.representative()).collect();
+        let naive = naive::msm(&representatives, &points).unwrap();
+
+        assert_eq!(pippenger::msm_field(&cs, &points).unwrap(), naive);
+        for i in 0..cs.len() {
+            assert_eq!(
+                pippenger::msm_field(&cs[i..=i], &points[i..=i]).unwrap(),
+                naive::msm(&representatives[i..=i], &points[i..=i]).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_msm_field_of_zeros_and_empty_inputs_is_the_neutral_element() {
+        let points = vec![BLS12381Curve::generator(); 5];
+        assert_eq!(
+            pippenger::msm_field(&vec![FrElement::zero(); 5], &points).unwrap(),
+            G1::neutral_element()
+        );
+        assert_eq!(
+            pippenger::msm_field::<FrField, 4, G1>(&[], &[]).unwrap(),
+            G1::neutral_element()
+        );
+        assert!(pippenger::msm_field(&[FrElement::one()], &points).is_err());
+    }
+
+    #[test]
+    fn test_signed_pippenger_carries_out_of_the_top_window() {
+        // Every window of these scalars is negative, so each one carries into the next.
+        let cs = [
+            UnsignedInteger::<6>::from_limbs([u64::MAX; 6]),
+            UnsignedInteger::from_u64(0),
+        ];
+        let points = [
+            BLS12381Curve::generator(),
+            BLS12381Curve::generator().operate_with_self(2_u64),
+        ];
+        let naive = naive::msm(&cs, &points).unwrap();
+        for window_size in [2, 3, 4, 5, 8] {
+            assert_eq!(pippenger::signed_msm_with(&cs, &points, window_size), naive);
+            #[cfg(feature = "rayon")]
+            assert_eq!(
+                pippenger::parallel_signed_msm_with(&cs, &points, window_size),
+                naive
+            );
+        }
     }
 }
@@ -0,0 +1,343 @@
+use crate::{
+    cyclic_group::IsGroup,
+    elliptic_curve::short_weierstrass::errors::DeserializationError,
+    traits::{Deserializable, Serializable},
+    unsigned_integer::element::UnsignedInteger,
+};
+
+use super::{
+    naive::MSMError,
+    pippenger::{add_to_bucket, reduce_buckets, signed_digit},
+};
+
+const MIN_WINDOW_SIZE: usize = 2;
+const MAX_WINDOW_SIZE: usize = 32;
+
+/// Multiscalar multiplication against a fixed vector of bases, which are preprocessed once.
+///
+/// Scalars are decomposed in signed digits of `window_size` bits, as in
+/// `pippenger::signed_msm_with`. For every base `g`, the multiples `2^(k * stride * window_size) * g`
+/// are stored, so that the digits of all windows that are `stride` apart are added to the
+/// same buckets. An MSM then needs only `stride` bucket reductions, joined with
+/// `(stride - 1) * window_size` doublings, instead of one per window.
+///
+/// Memory grows as `bases.len() * scalar_bits / (window_size * stride)` points: a larger
+/// `stride` uses less memory and a slower MSM, with `stride = 1` being the fastest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrecomputedMSM<G: IsGroup> {
+    window_size: usize,
+    stride: usize,
+    scalar_bits: usize,
+    num_bases: usize,
+    /// `multiples[i * num_tables + k] = 2^(k * stride * window_size) * bases[i]`.
+    multiples: Vec<G>,
+}
+
+impl<G: IsGroup> PrecomputedMSM<G> {
+    /// Preprocesses `bases` for scalars of up to `scalar_bits` bits.
+    pub fn new(bases: &[G], scalar_bits: usize, window_size: usize, stride: usize) -> Self {
+        let window_size = window_size.clamp(MIN_WINDOW_SIZE, MAX_WINDOW_SIZE);
+        let stride = stride.max(1);
+        let num_tables = num_tables(scalar_bits, window_size, stride);
+
+        let multiples = bases
+            .iter()
+            .flat_map(|base| {
+                std::iter::successors(Some(base.clone()), |p| {
+                    Some((0..stride * window_size).fold(p.clone(), |acc, _| acc.operate_with(&acc)))
+                })
+                .take(num_tables)
+            })
+            .collect();
+
+        Self {
+            window_size,
+            stride,
+            scalar_bits,
+            num_bases: bases.len(),
+            multiples,
+        }
+    }
+
+    /// Returns the number of preprocessed bases.
+    pub fn len(&self) -> usize {
+        self.num_bases
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_bases == 0
+    }
+
+    /// Returns the maximum number of bits of the scalars.
+    pub fn scalar_bits(&self) -> usize {
+        self.scalar_bits
+    }
+
+    /// Returns the preprocessed bases, in their original order.
+    pub fn bases(&self) -> impl Iterator<Item = &G> {
+        self.multiples.iter().step_by(self.num_tables())
+    }
+
+    fn num_windows(&self) -> usize {
+        self.scalar_bits / self.window_size + 1
+    }
+
+    fn num_tables(&self) -> usize {
+        num_tables(self.scalar_bits, self.window_size, self.stride)
+    }
+
+    /// Computes `k_1 * g_1 + ... + k_n * g_n`, where `g_1, ..., g_n` are the first `n`
+    /// bases and `cs = [k_1, ..., k_n]`.
+    ///
+    /// Returns an error if there are more scalars than bases, or if a scalar has more than
+    /// `scalar_bits` bits.
+    pub fn msm<const NUM_LIMBS: usize>(
+        &self,
+        cs: &[UnsignedInteger<NUM_LIMBS>],
+    ) -> Result<G, MSMError> {
+        if cs.len() > self.num_bases {
+            return Err(MSMError::LengthMismatch(cs.len(), self.num_bases));
+        }
+        if self.scalar_bits < 64 * NUM_LIMBS
+            && cs
+                .iter()
+                .any(|k| *k >> self.scalar_bits != UnsignedInteger::from_u64(0))
+        {
+            return Err(MSMError::ScalarTooLarge(self.scalar_bits));
+        }
+
+        // Scalars with fewer bits than the preprocessing have zero digits in the last
+        // windows, which must not be read past the limbs of the scalars.
+        let num_windows = self
+            .num_windows()
+            .min(64 * NUM_LIMBS / self.window_size + 1);
+        let num_tables = self.num_tables();
+        let mut buckets = vec![G::neutral_element(); 1 << (self.window_size - 1)];
+
+        Ok((0..self.stride)
+            .rev()
+            .map(|offset| {
+                for (k, multiples) in cs.iter().zip(self.multiples.chunks(num_tables)) {
+                    for (table, p) in multiples.iter().enumerate() {
+                        let window_idx = table * self.stride + offset;
+                        if window_idx < num_windows {
+                            let digit = signed_digit(k, window_idx, self.window_size);
+                            add_to_bucket(&mut buckets, digit, p);
+                        }
+                    }
+                }
+                reduce_buckets(&mut buckets)
+            })
+            .reduce(|t, g| {
+                t.operate_with_self(1_u64 << self.window_size)
+                    .operate_with(&g)
+            })
+            .unwrap_or_else(G::neutral_element))
+    }
+}
+
+fn num_tables(scalar_bits: usize, window_size: usize, stride: usize) -> usize {
+    let num_windows = scalar_bits / window_size + 1;
+    (num_windows - 1) / stride + 1
+}
+
+impl<G> Serializable for PrecomputedMSM<G>
+where
+    G: IsGroup + Serializable,
+{
+    /// The window size, the stride, the number of bits of the scalars and the number of
+    /// bases are encoded as 8 bytes little endian integers, followed by the multiples.
+    fn serialize(&self) -> Vec<u8> {
+        let mut serialized_data = Vec::new();
+        for parameter in [
+            self.window_size,
+            self.stride,
+            self.scalar_bits,
+            self.num_bases,
+        ] {
+            serialized_data.extend((parameter as u64).to_le_bytes());
+        }
+        for point in &self.multiples {
+            serialized_data.extend(point.serialize());
+        }
+        serialized_data
+    }
+}
+
+impl<G> Deserializable for PrecomputedMSM<G>
+where
+    G: IsGroup + Deserializable,
+{
+    fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        const HEADER_SIZE: usize = 32;
+
+        if bytes.len() < HEADER_SIZE {
+            return Err(DeserializationError::InvalidAmountOfBytes);
+        }
+        let mut parameters = bytes[..HEADER_SIZE].chunks(8).map(|chunk| {
+            // This unwrap can't fail since the chunks have 8 bytes
+            usize::try_from(u64::from_le_bytes(chunk.try_into().unwrap()))
+                .map_err(|_| DeserializationError::PointerSizeError)
+        });
+        let window_size = parameters.next().unwrap()?;
+        let stride = parameters.next().unwrap()?;
+        let scalar_bits = parameters.next().unwrap()?;
+        let num_bases = parameters.next().unwrap()?;
+
+        if !(MIN_WINDOW_SIZE..=MAX_WINDOW_SIZE).contains(&window_size) || stride == 0 {
+            return Err(DeserializationError::InvalidAmountOfBytes);
+        }
+        let num_multiples = num_bases
+            .checked_mul(num_tables(scalar_bits, window_size, stride))
+            .ok_or(DeserializationError::InvalidAmountOfBytes)?;
+
+        let points_bytes = &bytes[HEADER_SIZE..];
+        // Every multiple is encoded with the same number of bytes.
+        let multiples = match points_bytes.len().checked_div(num_multiples) {
+            None if points_bytes.is_empty() => Vec::new(),
+            Some(point_size)
+                if point_size > 0 && point_size * num_multiples == points_bytes.len() =>
+            {
+                points_bytes
+                    .chunks(point_size)
+                    .map(G::deserialize)
+                    .collect::<Result<_, _>>()?
+            }
+            _ => return Err(DeserializationError::InvalidAmountOfBytes),
+        };
+
+        Ok(Self {
+            window_size,
+            stride,
+            scalar_bits,
+            num_bases,
+            multiples,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elliptic_curve::{
+            short_weierstrass::{
+                curves::bls12_381::curve::BLS12381Curve, point::ShortWeierstrassProjectivePoint,
+            },
+            traits::IsEllipticCurve,
+        },
+        msm::pippenger,
+    };
+
+    type G1 = ShortWeierstrassProjectivePoint<BLS12381Curve>;
+
+    fn bases(n: u64) -> Vec<G1> {
+        let g = BLS12381Curve::generator();
+        (1..=n).map(|i| g.operate_with_self(i * 7919)).collect()
+    }
+
+    fn scalars(n: u64) -> Vec<UnsignedInteger<4>> {
+        (1..=n)
+            .map(|i| {
+                UnsignedInteger::from_limbs([
+                    // Keep the scalars below 2^255.
+                    (i * 0x9e37_79b9) >> 1,
+                    i.wrapping_mul(0x7f4a_7c15_f39c_c060),
+                    u64::MAX - i,
+                    i << 32 | i,
+                ])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn precomputed_msm_matches_pippenger() {
+        let bases = bases(40);
+        let cs = scalars(40);
+        let expected = pippenger::msm(&cs, &bases).unwrap();
+        for (window_size, stride) in [(2, 1), (3, 2), (4, 1), (5, 3), (8, 4), (6, 100)] {
+            let precomputed = PrecomputedMSM::new(&bases, 255, window_size, stride);
+            assert_eq!(precomputed.msm(&cs).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn precomputed_msm_uses_a_prefix_of_the_bases() {
+        let bases = bases(10);
+        let cs = scalars(4);
+        let precomputed = PrecomputedMSM::new(&bases, 255, 4, 2);
+        assert_eq!(
+            precomputed.msm(&cs).unwrap(),
+            pippenger::msm(&cs, &bases[..4]).unwrap()
+        );
+        assert_eq!(precomputed.msm::<4>(&[]).unwrap(), G1::neutral_element());
+        assert!(precomputed.bases().eq(bases.iter()));
+    }
+
+    #[test]
+    fn precomputed_msm_handles_scalars_of_all_ones() {
+        let bases = bases(3);
+        let cs = vec![UnsignedInteger::<4>::from_limbs([u64::MAX; 4]); 3];
+        let expected = pippenger::msm(&cs, &bases).unwrap();
+        for window_size in [2, 3, 4, 8] {
+            let precomputed = PrecomputedMSM::new(&bases, 256, window_size, 2);
+            assert_eq!(precomputed.msm(&cs).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn precomputed_msm_rejects_invalid_inputs() {
+        let precomputed = PrecomputedMSM::new(&bases(2), 255, 4, 1);
+        assert!(matches!(
+            precomputed.msm(&scalars(3)),
+            Err(MSMError::LengthMismatch(3, 2))
+        ));
+        let too_large = [UnsignedInteger::<4>::from_limbs([1 << 63, 0, 0, 0])];
+        assert!(matches!(
+            precomputed.msm(&too_large),
+            Err(MSMError::ScalarTooLarge(255))
+        ));
+    }
+
+    #[test]
+    fn precomputed_msm_accepts_scalars_with_fewer_limbs_than_bits() {
+        let bases = bases(6);
+        let small: Vec<_> = (1..=6_u64)
+            .map(|i| UnsignedInteger::<1>::from_u64(u64::MAX / i))
+            .collect();
+        let wide: Vec<_> = small
+            .iter()
+            .map(|k| UnsignedInteger::<4>::from_u64(k.limbs[0]))
+            .collect();
+        let expected = pippenger::msm(&wide, &bases).unwrap();
+        for (scalar_bits, window_size, stride) in
+            [(256, 4, 1), (256, 5, 3), (64, 8, 2), (1000, 3, 2)]
+        {
+            let precomputed = PrecomputedMSM::new(&bases, scalar_bits, window_size, stride);
+            assert_eq!(precomputed.msm(&small).unwrap(), expected);
+            assert_eq!(precomputed.msm(&wide).unwrap(), expected);
+        }
+
+        let precomputed = PrecomputedMSM::new(&bases, 32, 4, 1);
+        assert!(matches!(
+            precomputed.msm(&small),
+            Err(MSMError::ScalarTooLarge(32))
+        ));
+    }
+
+    #[test]
+    fn serialization_roundtrip() {
+        let bases = bases(5);
+        let precomputed = PrecomputedMSM::new(&bases, 255, 5, 3);
+        let bytes = precomputed.serialize();
+        let deserialized = PrecomputedMSM::<G1>::deserialize(&bytes).unwrap();
+        assert_eq!(deserialized, precomputed);
+        assert_eq!(
+            deserialized.msm(&scalars(5)).unwrap(),
+            pippenger::msm(&scalars(5), &bases).unwrap()
+        );
+        assert!(PrecomputedMSM::<G1>::deserialize(&bytes[..bytes.len() - 1]).is_err());
+        assert!(PrecomputedMSM::<G1>::deserialize(&bytes[..20]).is_err());
+        assert!(PrecomputedMSM::<G1>::deserialize(&bytes[..32]).is_err());
+    }
+}
@@ -0,0 +1,315 @@
+use std::io::Read;
+
+use crate::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{short_weierstrass::errors::DeserializationError, traits::HasCofactor},
+    traits::Deserializable,
+    unsigned_integer::element::UnsignedInteger,
+};
+
+use super::{
+    naive::MSMError,
+    pippenger::{optimum_window_size, signed_window_sum},
+};
+
+/// Multiscalar multiplication whose scalars and points are given in chunks, so that only
+/// one chunk has to be in memory at a time.
+///
+/// Each chunk is added to the running sum of every window of the signed-digit Pippenger
+/// algorithm, and the windows are only combined in `finalize`. The result is the same as
+/// the one of `pippenger::msm` over the concatenation of the chunks.
+pub struct StreamingMSM<const NUM_LIMBS: usize, G: IsGroup> {
+    window_size: usize,
+    window_sums: Vec<G>,
+    buckets: Vec<G>,
+}
+
+impl<const NUM_LIMBS: usize, G: IsGroup> StreamingMSM<NUM_LIMBS, G> {
+    /// Creates an empty MSM, which uses windows of `window_size` bits.
+    pub fn new(window_size: usize) -> Self {
+        const MIN_WINDOW_SIZE: usize = 2;
+        const MAX_WINDOW_SIZE: usize = 32;
+
+        let window_size = window_size.clamp(MIN_WINDOW_SIZE, MAX_WINDOW_SIZE);
+        let num_windows = 64 * NUM_LIMBS / window_size + 1;
+        Self {
+            window_size,
+            window_sums: vec![G::neutral_element(); num_windows],
+            buckets: vec![G::neutral_element(); 1 << (window_size - 1)],
+        }
+    }
+
+    /// Adds the terms `k_i * g_i` of a chunk.
+    ///
+    /// Returns an error if `cs` and `points` have different lengths.
+    pub fn update(
+        &mut self,
+        cs: &[UnsignedInteger<NUM_LIMBS>],
+        points: &[G],
+    ) -> Result<(), MSMError> {
+        if cs.len() != points.len() {
+            return Err(MSMError::LengthMismatch(cs.len(), points.len()));
+        }
+        for (window_idx, window_sum) in self.window_sums.iter_mut().enumerate() {
+            let chunk_sum =
+                signed_window_sum(cs, points, window_idx, self.window_size, &mut self.buckets);
+            *window_sum = window_sum.operate_with(&chunk_sum);
+        }
+        Ok(())
+    }
+
+    /// Returns the sum of all the terms added so far.
+    pub fn finalize(self) -> G {
+        self.window_sums
+            .into_iter()
+            .rev()
+            // NOTE: this operation is non-associative and strictly sequential
+            .reduce(|t, g| {
+                t.operate_with_self(1_u64 << self.window_size)
+                    .operate_with(&g)
+            })
+            .unwrap_or_else(G::neutral_element)
+    }
+}
+
+/// Computes the same result as `pippenger::msm`, pulling `chunk_size` scalars and points
+/// at a time from the iterators.
+///
+/// Returns an error if the iterators have different lengths.
+pub fn msm_from_iter<const NUM_LIMBS: usize, G, I, J>(
+    cs: I,
+    points: J,
+    chunk_size: usize,
+) -> Result<G, MSMError>
+where
+    G: IsGroup,
+    I: IntoIterator<Item = UnsignedInteger<NUM_LIMBS>>,
+    J: IntoIterator<Item = G>,
+{
+    let chunk_size = chunk_size.max(1);
+    let mut msm = StreamingMSM::new(optimum_window_size(chunk_size));
+    let mut cs = cs.into_iter();
+    let mut points = points.into_iter();
+    let mut num_terms = 0;
+
+    loop {
+        let cs_chunk: Vec<_> = cs.by_ref().take(chunk_size).collect();
+        let points_chunk: Vec<_> = points.by_ref().take(chunk_size).collect();
+        if cs_chunk.len() != points_chunk.len() {
+            return Err(MSMError::LengthMismatch(
+                num_terms + cs_chunk.len(),
+                num_terms + points_chunk.len(),
+            ));
+        }
+        if cs_chunk.is_empty() {
+            return Ok(msm.finalize());
+        }
+        num_terms += cs_chunk.len();
+        msm.update(&cs_chunk, &points_chunk)?;
+    }
+}
+
+/// Computes the multiscalar multiplication of the scalars `cs` by points deserialized from
+/// `reader`, each of them encoded in `point_size` bytes. One point is read for every scalar,
+/// `chunk_size` at a time, and the rest of the reader is left untouched.
+///
+/// The points are only checked as much as `G::deserialize` does, so `reader` must come from
+/// a trusted source. Use `msm_from_reader_checked` otherwise.
+///
+/// Returns an error if the reader ends before there is a point for every scalar, or if a
+/// point cannot be deserialized.
+pub fn msm_from_reader<const NUM_LIMBS: usize, G, I, R>(
+    cs: I,
+    reader: R,
+    point_size: usize,
+    chunk_size: usize,
+) -> Result<G, MSMError>
+where
+    G: IsGroup + Deserializable,
+    I: IntoIterator<Item = UnsignedInteger<NUM_LIMBS>>,
+    R: Read,
+{
+    msm_from_reader_with(cs, reader, point_size, chunk_size, G::deserialize)
+}
+
+/// Computes the same result as `msm_from_reader`, additionally rejecting points that are not
+/// in the prime order subgroup as they are read.
+///
+/// Returns an error if the reader ends before there is a point for every scalar, if a point
+/// cannot be deserialized or if it is not in the subgroup.
+pub fn msm_from_reader_checked<const NUM_LIMBS: usize, G, I, R>(
+    cs: I,
+    reader: R,
+    point_size: usize,
+    chunk_size: usize,
+) -> Result<G, MSMError>
+where
+    G: IsGroup + Deserializable + HasCofactor,
+    I: IntoIterator<Item = UnsignedInteger<NUM_LIMBS>>,
+    R: Read,
+{
+    msm_from_reader_with(cs, reader, point_size, chunk_size, |bytes| {
+        let point = G::deserialize(bytes)?;
+        if point.is_in_subgroup() {
+            Ok(point)
+        } else {
+            Err(DeserializationError::NotInSubgroup)
+        }
+    })
+}
+
+fn msm_from_reader_with<const NUM_LIMBS: usize, G, I, R, D>(
+    cs: I,
+    mut reader: R,
+    point_size: usize,
+    chunk_size: usize,
+    deserialize: D,
+) -> Result<G, MSMError>
+where
+    G: IsGroup,
+    I: IntoIterator<Item = UnsignedInteger<NUM_LIMBS>>,
+    R: Read,
+    D: Fn(&[u8]) -> Result<G, DeserializationError>,
+{
+    let chunk_size = chunk_size.max(1);
+    let mut msm = StreamingMSM::new(optimum_window_size(chunk_size));
+    let mut cs = cs.into_iter();
+    let mut point_bytes = vec![0; point_size];
+
+    loop {
+        let cs_chunk: Vec<_> = cs.by_ref().take(chunk_size).collect();
+        if cs_chunk.is_empty() {
+            return Ok(msm.finalize());
+        }
+        let points_chunk = cs_chunk
+            .iter()
+            .map(|_| {
+                reader.read_exact(&mut point_bytes)?;
+                Ok(deserialize(&point_bytes)?)
+            })
+            .collect::<Result<Vec<_>, MSMError>>()?;
+        msm.update(&cs_chunk, &points_chunk)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elliptic_curve::{
+            short_weierstrass::{
+                curves::bls12_381::curve::BLS12381Curve, point::ShortWeierstrassProjectivePoint,
+            },
+            traits::IsEllipticCurve,
+        },
+        msm::pippenger,
+        traits::Serializable,
+    };
+
+    type G1 = ShortWeierstrassProjectivePoint<BLS12381Curve>;
+
+    fn scalars_and_points(n: u64) -> (Vec<UnsignedInteger<6>>, Vec<G1>) {
+        let g = BLS12381Curve::generator();
+        let cs = (1..=n)
+            .map(|i| UnsignedInteger::from_limbs([i, 0, i * i, u64::MAX - i, i << 40, i * 31]))
+            .collect();
+        let points = (1..=n).map(|i| g.operate_with_self(i * 977)).collect();
+        (cs, points)
+    }
+
+    #[test]
+    fn msm_from_iter_matches_msm_for_any_chunk_size() {
+        let (cs, points) = scalars_and_points(37);
+        let expected = pippenger::msm(&cs, &points).unwrap();
+        for chunk_size in [0, 1, 5, 36, 37, 100] {
+            let streamed = msm_from_iter(cs.clone(), points.clone(), chunk_size).unwrap();
+            assert_eq!(streamed, expected);
+        }
+    }
+
+    #[test]
+    fn msm_from_iter_of_empty_iterators_is_the_neutral_element() {
+        let streamed = msm_from_iter::<6, G1, _, _>(vec![], vec![], 4).unwrap();
+        assert_eq!(streamed, G1::neutral_element());
+    }
+
+    #[test]
+    fn msm_from_iter_rejects_length_mismatch() {
+        let (cs, points) = scalars_and_points(9);
+        assert!(matches!(
+            msm_from_iter(cs.clone(), points[..8].to_vec(), 4),
+            Err(MSMError::LengthMismatch(9, 8))
+        ));
+        assert!(matches!(
+            msm_from_iter(cs[..4].to_vec(), points, 4),
+            Err(MSMError::LengthMismatch(4, 8))
+        ));
+    }
+
+    #[test]
+    fn msm_from_reader_matches_msm() {
+        let (cs, points) = scalars_and_points(23);
+        let point_size = Serializable::serialize(&points[0]).len();
+        let mut bytes: Vec<u8> = points.iter().flat_map(Serializable::serialize).collect();
+        // Trailing data after the points that are needed is not read.
+        bytes.extend([0xff; 7]);
+
+        let expected = pippenger::msm(&cs[..20], &points[..20]).unwrap();
+        let streamed: G1 =
+            msm_from_reader(cs[..20].to_vec(), bytes.as_slice(), point_size, 6).unwrap();
+        assert_eq!(streamed, expected);
+    }
+
+    #[test]
+    fn msm_from_reader_fails_if_the_points_run_out() {
+        let (cs, points) = scalars_and_points(5);
+        let point_size = Serializable::serialize(&points[0]).len();
+        let bytes: Vec<u8> = points[..4]
+            .iter()
+            .flat_map(Serializable::serialize)
+            .collect();
+        let result: Result<G1, _> = msm_from_reader(cs, bytes.as_slice(), point_size, 2);
+        assert!(matches!(result, Err(MSMError::Io(_))));
+    }
+
+    #[test]
+    fn msm_from_reader_checked_rejects_points_outside_the_subgroup() {
+        use crate::elliptic_curve::short_weierstrass::curves::bls12_381::field_extension::BLS12381PrimeField;
+        use crate::field::element::FieldElement;
+
+        let (cs, points) = scalars_and_points(3);
+        let point_size = Serializable::serialize(&points[0]).len();
+        let bytes: Vec<u8> = points.iter().flat_map(Serializable::serialize).collect();
+        let streamed: G1 =
+            msm_from_reader_checked(cs.clone(), bytes.as_slice(), point_size, 2).unwrap();
+        assert_eq!(streamed, pippenger::msm(&cs, &points).unwrap());
+
+        // A point of the curve of order not dividing the order of the subgroup.
+        let x = FieldElement::<BLS12381PrimeField>::from(4);
+        let y = FieldElement::<BLS12381PrimeField>::new_base("a989badd40d6212b33cffc3f3763e9bc760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c");
+        let outside = BLS12381Curve::create_point_from_affine(x, y).unwrap();
+        let bytes: Vec<u8> = [&points[0], &outside, &points[2]]
+            .into_iter()
+            .flat_map(Serializable::serialize)
+            .collect();
+        let streamed: Result<G1, _> = msm_from_reader(cs.clone(), bytes.as_slice(), point_size, 2);
+        assert!(streamed.is_ok());
+        let streamed: Result<G1, _> = msm_from_reader_checked(cs, bytes.as_slice(), point_size, 2);
+        assert!(matches!(
+            streamed,
+            Err(MSMError::Deserialization(
+                DeserializationError::NotInSubgroup
+            ))
+        ));
+    }
+
+    #[test]
+    fn streaming_msm_accumulates_chunks() {
+        let (cs, points) = scalars_and_points(12);
+        let mut msm = StreamingMSM::new(3);
+        msm.update(&cs[..5], &points[..5]).unwrap();
+        msm.update(&cs[5..], &points[5..]).unwrap();
+        assert!(msm.update(&cs[..2], &points[..1]).is_err());
+        assert_eq!(msm.finalize(), pippenger::msm(&cs, &points).unwrap());
+    }
+}
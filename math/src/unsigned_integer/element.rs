@@ -690,7 +690,7 @@ impl<const NUM_LIMBS: usize> UnsignedInteger<NUM_LIMBS> {
         while i > 0 {
             i -= 1;
             if NUM_LIMBS - 1 <= i * 2 {
-                let index = 2 * i - NUM_LIMBS + 1;
+                let index = 2 * i + 1 - NUM_LIMBS;
                 let cs = lo.limbs[index] as u128 + a.limbs[i] as u128 * a.limbs[i] as u128 + c;
                 c = cs >> 64;
                 lo.limbs[index] = cs as u64;
@@ -720,15 +720,60 @@ impl<const NUM_LIMBS: usize> UnsignedInteger<NUM_LIMBS> {
     /// Returns the number of bits needed to represent the number (0 for zero).
     /// If nonzero, this is equivalent to one plus the floored log2 of the number.
     pub const fn bits(&self) -> u32 {
-        let mut i = NUM_LIMBS;
-        while i > 0 {
-            if self.limbs[i - 1] != 0 {
-                return i as u32 * u64::BITS - self.limbs[i - 1].leading_zeros();
+        // Limbs are stored with the most significant one first.
+        let mut i = 0;
+        while i < NUM_LIMBS {
+            if self.limbs[i] != 0 {
+                return (NUM_LIMBS - i) as u32 * u64::BITS - self.limbs[i].leading_zeros();
             }
-            i -= 1;
+            i += 1;
         }
         0
     }
+
+    /// Returns the bit of `self` at `index`, where index 0 is the least significant bit.
+    /// Bits beyond the size of the integer are zero.
+    pub const fn bit(&self, index: usize) -> bool {
+        if index >= NUM_LIMBS * 64 {
+            return false;
+        }
+        (self.limbs[NUM_LIMBS - 1 - index / 64] >> (index % 64)) & 1 == 1
+    }
+
+    /// Returns the width-`window_size` non-adjacent form of `self`, least significant digit first.
+    /// Non-zero digits are odd and smaller than `2^(window_size - 1)` in absolute value,
+    /// and among any `window_size` consecutive digits at most one is non-zero.
+    /// `window_size` must be between 2 and 32.
+    /// For more info, see "Guide to Elliptic Curve Cryptography", Algorithm 3.35.
+    pub fn to_wnaf(&self, window_size: usize) -> Vec<i64> {
+        debug_assert!((2..=32).contains(&window_size));
+        let width = 1i64 << window_size;
+        let bits = self.bits() as usize;
+        let mut digits = vec![0i64; bits + window_size];
+        let mut carry = 0i64;
+        let mut position = 0;
+        while position < bits || carry != 0 {
+            let window =
+                (0..window_size).fold(carry, |acc, j| acc + ((self.bit(position + j) as i64) << j));
+            if window & 1 == 0 {
+                // The current digit is zero, and the carry moves to the next position.
+                position += 1;
+                continue;
+            }
+            if window < width / 2 {
+                carry = 0;
+                digits[position] = window;
+            } else {
+                carry = 1;
+                digits[position] = window - width;
+            }
+            position += window_size;
+        }
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        digits
+    }
 }
 
 impl<const NUM_LIMBS: usize> IsUnsignedInteger for UnsignedInteger<NUM_LIMBS> {}
@@ -1765,6 +1810,20 @@ mod tests_u384 {
         assert_eq!(lo, U384::from_hex_unchecked("1"));
         assert_eq!(hi, U384::from_hex_unchecked("fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe"));
     }
+
+    #[test]
+    fn test_square_with_odd_number_of_limbs_matches_mul() {
+        let a = UnsignedInteger::<7>::from_limbs([
+            u64::MAX,
+            0x0123456789abcdef,
+            u64::MAX,
+            3,
+            0xfedcba9876543210,
+            u64::MAX,
+            1,
+        ]);
+        assert_eq!(UnsignedInteger::square(&a), UnsignedInteger::mul(&a, &a));
+    }
 }
 
 #[cfg(test)]
@@ -2597,4 +2656,65 @@ mod tests_u256 {
         b >>= 222;
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn bits_counts_from_the_most_significant_limb() {
+        assert_eq!(U256::from_u64(0).bits(), 0);
+        assert_eq!(U256::from_u64(1).bits(), 1);
+        assert_eq!(U256::from_hex_unchecked("10000000000000001").bits(), 65);
+        assert_eq!(
+            U256::from_hex_unchecked(
+                "90823e0bd707f000000000000000000000000000000000000000000000000",
+            )
+            .bits(),
+            244
+        );
+    }
+
+    #[test]
+    fn wnaf_of_small_integers() {
+        assert_eq!(U256::from_u64(0).to_wnaf(2), Vec::<i64>::new());
+        assert_eq!(U256::from_u64(7).to_wnaf(2), vec![-1, 0, 0, 1]);
+        assert_eq!(U256::from_u64(7).to_wnaf(3), vec![-1, 0, 0, 1]);
+        assert_eq!(U256::from_u64(7).to_wnaf(4), vec![7]);
+        assert_eq!(
+            U256::from_u64(1122).to_wnaf(3),
+            vec![0, 1, 0, 0, 0, 3, 0, 0, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn wnaf_of_max_integer_carries_past_the_last_bit() {
+        let digits = Uint::from_limbs([u64::MAX; N_LIMBS]).to_wnaf(4);
+        assert_eq!(digits.len(), 257);
+        assert_eq!(digits[0], -1);
+        assert_eq!(digits[256], 1);
+        assert!(digits[1..256].iter().all(|d| *d == 0));
+    }
+
+    proptest! {
+        #[test]
+        fn wnaf_recovers_the_integer(a in any::<[u64; N_LIMBS]>(), window_size in 2usize..9) {
+            let digits = Uint::from_limbs(a).to_wnaf(window_size);
+            // The digits may need one more bit than the integer, so the sum is computed with 5 limbs.
+            let mut positive = UnsignedInteger::<5>::from_u64(0);
+            let mut negative = UnsignedInteger::<5>::from_u64(0);
+            for (i, digit) in digits.iter().enumerate() {
+                let term = UnsignedInteger::<5>::from_u64(digit.unsigned_abs()) << i;
+                if *digit > 0 {
+                    positive = positive + term;
+                } else {
+                    negative = negative + term;
+                }
+                if *digit != 0 {
+                    prop_assert!(digit % 2 != 0);
+                    prop_assert!(digit.unsigned_abs() < 1 << (window_size - 1));
+                    let next = i + 1..(i + window_size).min(digits.len());
+                    prop_assert!(next.into_iter().all(|j| digits[j] == 0));
+                }
+            }
+            let expected = UnsignedInteger::<5>::from_limbs([0, a[0], a[1], a[2], a[3]]);
+            prop_assert_eq!(positive - negative, expected);
+        }
+    }
 }
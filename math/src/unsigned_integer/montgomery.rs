@@ -67,7 +67,9 @@ impl MontgomeryAlgorithms {
         }
         let mut result = UnsignedInteger { limbs: t };
 
-        let overflow = t_extra[0] > 0;
+        // The result is `t[N] * 2^{NUM_LIMBS * 64} + t`, with `t[N]` only
+        // non zero when `q` has no spare bits.
+        let overflow = t_extra[1] > 0;
 
         if overflow || UnsignedInteger::const_le(q, &result) {
             (result, _) = UnsignedInteger::sub(&result, q);
@@ -91,11 +93,11 @@ impl MontgomeryAlgorithms {
         // Step 2: Add terms to `(hi, lo)` until multiple it
         // is a multiple of both `2^{NUM_LIMBS * 64}` and
         // `q`.
-        let mut c: u128 = 0;
+        let mut overflow = false;
         let mut i = NUM_LIMBS;
         while i > 0 {
             i -= 1;
-            c = 0;
+            let mut c: u128 = 0;
             let m = (lo.limbs[i] as u128 * *mu as u128) as u64;
             let mut j = NUM_LIMBS;
             while j > 0 {
@@ -121,6 +123,9 @@ impl MontgomeryAlgorithms {
                 hi.limbs[i - t] = cs as u64;
                 t += 1;
             }
+            // A carry out of the most significant limb can only happen when `q` has no
+            // spare bits, and at most once, since the result is smaller than `2 * q`.
+            overflow |= c > 0;
         }
 
         // Step 3: At this point `overflow * 2^{2 * NUM_LIMBS * 64} + (hi, lo)` is a multiple
@@ -132,7 +137,6 @@ impl MontgomeryAlgorithms {
         // The easy case is when `overflow` is zero. We just use the `sub` function.
         // If `overflow` is 1, then `hi` is smaller than `q`. The function `sub(hi, q)` wraps
         // around `2^{NUM_LIMBS * 64}`. This is the result we need.
-        let overflow = c > 0;
         if overflow || UnsignedInteger::const_le(q, &hi) {
             (hi, _) = UnsignedInteger::sub(&hi, q);
         }
@@ -142,7 +146,10 @@ impl MontgomeryAlgorithms {
 
 #[cfg(test)]
 mod tests {
-    use crate::unsigned_integer::{element::U384, montgomery::MontgomeryAlgorithms};
+    use crate::unsigned_integer::{
+        element::{U256, U384},
+        montgomery::MontgomeryAlgorithms,
+    };
 
     #[test]
     fn montgomery_multiplication_works_0() {
@@ -173,4 +180,41 @@ mod tests {
         let c = U384::from_hex_unchecked("8d65cdee621682815d59f465d2641eea8a1274dc");
         assert_eq!(MontgomeryAlgorithms::cios(&x, &r_mod_m, &m, &mu), c);
     }
+
+    // The modulus of the base field of secp256k1 has no spare bits.
+    const SECP256K1_MODULUS: U256 = U256::from_hex_unchecked(
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+    );
+    const SECP256K1_MU: u64 = 15580212934572586289; // negative of the inverse of `m` modulo 2^{64}
+
+    #[test]
+    fn montgomery_multiplication_works_for_modulus_without_spare_bits() {
+        let x = U256::from_hex_unchecked(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e",
+        );
+        let y = U256::from_hex_unchecked(
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        );
+        let c = U256::from_hex_unchecked(
+            "9e346d39c02cc01a9b5a1ec8c77021290e8a67487f2fd7776c9c886086749452",
+        ); // x * y * (r^{-1}) % m, where r = 2^{64 * 4}
+        assert_eq!(
+            MontgomeryAlgorithms::cios(&x, &y, &SECP256K1_MODULUS, &SECP256K1_MU),
+            c
+        );
+    }
+
+    #[test]
+    fn montgomery_squaring_works_for_modulus_without_spare_bits() {
+        let x = U256::from_hex_unchecked(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e",
+        );
+        let c = U256::from_hex_unchecked(
+            "c9bd1905155383999c46c2c295f2b761bcb223fedc24a059d838091d0868192a",
+        ); // x * x * (r^{-1}) % m, where r = 2^{64 * 4}
+        assert_eq!(
+            MontgomeryAlgorithms::sos_square(&x, &SECP256K1_MODULUS, &SECP256K1_MU),
+            c
+        );
+    }
 }